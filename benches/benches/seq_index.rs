@@ -0,0 +1,45 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Benchmarks of `index::sample` around the algorithm thresholds
+//!
+//! For `amount >= 163`, the inplace method is used for dense samples, bitset
+//! rejection where `length / amount < 256` and hash-set rejection otherwise.
+//! The latter threshold limits the memory of the bitset: bitset rejection is
+//! several times faster for small amounts, but for large amounts the two
+//! methods perform similarly at this threshold, and the bitset becomes slower
+//! beyond it.
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use rand::SeedableRng;
+use rand::seq::index;
+use rand_pcg::Pcg32;
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default();
+    targets = bench
+);
+criterion_main!(benches);
+
+pub fn bench(c: &mut Criterion) {
+    for amount in [200, 20_000, 2_000_000] {
+        let mut g = c.benchmark_group(format!("index_sample_{amount}"));
+        g.throughput(Throughput::Elements(amount as u64));
+        if amount > 20_000 {
+            g.sample_size(10);
+        }
+        for ratio in [2, 4, 16, 64, 255, 256, 1024, 2048] {
+            let length = amount * ratio;
+            g.bench_function(BenchmarkId::from_parameter(ratio), |b| {
+                let mut rng = Pcg32::from_rng(&mut rand::rng());
+                b.iter(|| index::sample(&mut rng, black_box(length), amount))
+            });
+        }
+    }
+}
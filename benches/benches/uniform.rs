@@ -76,6 +76,25 @@ macro_rules! sample {
 
             b.iter(|| dist.sample(&mut rng));
         });
+
+        $g.bench_function(BenchmarkId::new(stringify!($R), "fill"), |b| {
+            let mut rng = <$R>::from_rng(&mut rand::rng());
+            let range = sample!(@range $T, $U, $len, rng);
+            let low = sample!(@MIN $T, $len);
+            let high = sample!(@wrapping_add low, range, $len);
+            let dist = Uniform::new_inclusive(low, high).unwrap();
+            let mut buf = [low; 64];
+
+            // Report the time per sample
+            b.iter_custom(|iters| {
+                let start = std::time::Instant::now();
+                for _ in 0..iters {
+                    dist.sample_fill(&mut rng, &mut buf);
+                    std::hint::black_box(&buf);
+                }
+                start.elapsed() / buf.len() as u32
+            });
+        });
     };
 
     // Entrypoint:
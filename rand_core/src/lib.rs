@@ -534,6 +534,47 @@ pub trait SeedableRng: Sized {
         Ok(Self::from_seed(seed))
     }
 
+    /// Fork this PRNG, creating a child PRNG seeded from its output.
+    ///
+    /// This is equivalent to [`from_rng(self)`][SeedableRng::from_rng]: the
+    /// child is seeded with a whole [`Seed`] of output, advancing `self`, thus
+    /// each subsequent fork yields a different child. It is intended e.g. to
+    /// derive a PRNG for each spawned task from a parent PRNG, deterministically
+    /// if the parent is seeded deterministically.
+    ///
+    /// Prefer this over `seed_from_u64(parent.next_u64())`: that can produce
+    /// at most 2<sup>64</sup> distinct children, which collide with
+    /// non-negligible probability after around 2<sup>32</sup> forks, and
+    /// [`seed_from_u64`] is not designed for statistical independence from
+    /// its input.
+    ///
+    /// With a cryptographically secure parent and child, the child's output
+    /// is computationally independent of the parent's output and that of other
+    /// children. For other PRNGs, children seeded with distinct full seeds are
+    /// usually sufficiently independent for simulations, but the generator's
+    /// documentation should be consulted; some provide dedicated *jump* or
+    /// *stream* functions for this purpose.
+    ///
+    /// [`Seed`]: SeedableRng::Seed
+    /// [`seed_from_u64`]: SeedableRng::seed_from_u64
+    fn fork(&mut self) -> Self
+    where
+        Self: RngCore,
+    {
+        Self::from_rng(self)
+    }
+
+    /// Fork this PRNG, creating a child PRNG seeded from its output, where
+    /// `self` is fallible.
+    ///
+    /// See [`fork`][SeedableRng::fork] docs for more information.
+    fn try_fork(&mut self) -> Result<Self, <Self as TryRngCore>::Error>
+    where
+        Self: TryRngCore,
+    {
+        Self::try_from_rng(self)
+    }
+
     /// Creates a new instance of the RNG seeded via [`getrandom`].
     ///
     /// This method is the recommended way to construct non-deterministic PRNGs
@@ -579,6 +620,13 @@ pub trait SeedableRng: Sized {
 
 /// Adapter that enables reading through a [`io::Read`](std::io::Read) from a [`RngCore`].
 ///
+/// Constructed with [`TryRngCore::read_adapter`], this allows passing random
+/// data to APIs expecting a reader without an intermediate buffer. The reader
+/// is infinite: each call to `read` fills the entire buffer and it never
+/// signals end-of-file, thus use e.g. [`Read::take`](std::io::Read::take) to
+/// limit the length. Errors of a fallible RNG are reported as an
+/// [`io::Error`](std::io::Error) of kind [`Other`](std::io::ErrorKind::Other).
+///
 /// # Examples
 ///
 /// ```no_run
@@ -615,6 +663,30 @@ impl<R: TryRngCore + ?Sized> std::fmt::Debug for RngReadAdapter<'_, R> {
 mod test {
     use super::*;
 
+    /// A counter, yielding consecutive bytes and seeded with its initial value
+    struct Counter(u8);
+    impl RngCore for Counter {
+        fn next_u32(&mut self) -> u32 {
+            impls::next_u32_via_fill(self)
+        }
+        fn next_u64(&mut self) -> u64 {
+            impls::next_u64_via_fill(self)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            for b in dst {
+                *b = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+    }
+    impl SeedableRng for Counter {
+        type Seed = [u8; 2];
+
+        fn from_seed(seed: Self::Seed) -> Self {
+            Counter(seed[0] ^ seed[1].rotate_left(4))
+        }
+    }
+
     #[test]
     fn test_seed_from_u64() {
         struct SeedableNum(u64);
@@ -656,6 +728,36 @@ mod test {
         assert_eq!(results[0], 5029875928683246316);
     }
 
+    #[test]
+    fn test_fork() {
+        let mut parent = Counter(0);
+        // Each child consumes a whole seed of output
+        let child1 = parent.fork();
+        assert_eq!(child1.0, 0x10);
+        assert_eq!(parent.0, 2);
+        let child2 = parent.try_fork().unwrap();
+        assert_eq!(child2.0, 2 ^ 0x30);
+        assert_eq!(parent.0, 4);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_adapter() {
+        use std::io::{self, Read};
+
+        let mut rng = Counter(0);
+        let mut buf = [0u8; 3];
+        rng.read_adapter().read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [0, 1, 2]);
+
+        let mut out = std::vec::Vec::new();
+        let n = io::copy(&mut rng.read_adapter().take(10_000), &mut out).unwrap();
+        assert_eq!(n, 10_000);
+        assert_eq!(out.len(), 10_000);
+        assert_eq!(out[0], 3);
+        assert_eq!(out[9_999], (10_002 % 256) as u8);
+    }
+
     // A stub RNG.
     struct SomeRng;
 
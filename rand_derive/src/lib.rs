@@ -0,0 +1,259 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Derive macros for [`rand`](https://docs.rs/rand).
+//!
+//! This crate should not be used directly; instead enable the `derive`
+//! feature of `rand`, which re-exports [`StandardUniform`] as
+//! `rand::distr::StandardUniform`.
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico",
+    html_root_url = "https://rust-random.github.io/rand/"
+)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Literal, TokenStream as TokenStream2, TokenTree};
+use quote::{ToTokens, quote};
+use syn::{
+    Attribute, Data, DeriveInput, Error, Fields, GenericParam, LitInt, Path, Result, Type,
+    parse_macro_input,
+};
+
+/// Derive `Distribution<Self>` for `StandardUniform`
+///
+/// For a struct, each field is sampled from `StandardUniform`, in order of
+/// declaration.
+///
+/// For an enum, a variant is chosen at random, then its fields (if any) are
+/// sampled as for a struct. By default all variants are equally likely; the
+/// attribute `#[rand(weight = N)]` sets the relative weight of a variant to
+/// the integer `N`, where the default weight is `1` and a weight of `0` means
+/// the variant is never sampled. The variant is chosen by sampling a `u32`
+/// from the range `0..total_weight`, thus the total weight must fit in a
+/// `u32`.
+///
+/// Generic parameters are supported; the generated implementation requires
+/// `StandardUniform: Distribution<T>` for each field type `T` which mentions
+/// a generic type or const parameter.
+///
+/// The generated code refers to `rand` as `::rand`. Where `rand` is renamed or
+/// re-exported from another crate, the attribute `#[rand(crate = path)]` on
+/// the struct or enum sets the path used instead, e.g.
+/// `#[rand(crate = my_crate::rand)]`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::StandardUniform;
+///
+/// #[derive(Debug, StandardUniform)]
+/// enum Weather {
+///     #[rand(weight = 5)]
+///     Sunny,
+///     Cloudy,
+///     Rainy { millimetres: u8 },
+/// }
+///
+/// #[derive(Debug, StandardUniform)]
+/// struct Forecast(Weather, Weather);
+///
+/// let forecast: Forecast = rand::random();
+/// println!("{forecast:?}");
+/// ```
+#[proc_macro_derive(StandardUniform, attributes(rand))]
+pub fn derive_standard_uniform(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let krate = parse_crate(&input.attrs)?;
+    let mut field_types = Vec::new();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            field_types.extend(data.fields.iter().map(|f| &f.ty));
+            let fields = sample_fields(&data.fields, &krate)?;
+            quote! { #name #fields }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            let mut total = 0u32;
+            for variant in &data.variants {
+                let weight = parse_weight(&variant.attrs)?.unwrap_or(1);
+                field_types.extend(variant.fields.iter().map(|f| &f.ty));
+                let fields = sample_fields(&variant.fields, &krate)?;
+                if weight == 0 {
+                    continue;
+                }
+
+                let start = total;
+                total = total.checked_add(weight).ok_or_else(|| {
+                    Error::new_spanned(variant, "the total weight must fit in a `u32`")
+                })?;
+                let (start, end) = (Literal::u32_suffixed(start), Literal::u32_suffixed(total));
+                let ident = &variant.ident;
+                arms.push((quote! { #start..#end }, quote! { #name::#ident #fields }));
+            }
+
+            match arms.len() {
+                0 => {
+                    return Err(Error::new_spanned(
+                        input,
+                        "cannot derive `StandardUniform` for an enum without variants of non-zero weight",
+                    ));
+                }
+                1 => arms.pop().unwrap().1,
+                _ => {
+                    let (_, last) = arms.pop().unwrap();
+                    let (patterns, values): (Vec<_>, Vec<_>) = arms.into_iter().unzip();
+                    let total = Literal::u32_suffixed(total);
+                    quote! {
+                        match #krate::Rng::random_range(rng, 0..#total) {
+                            #(#patterns => #values,)*
+                            _ => #last,
+                        }
+                    }
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "cannot derive `StandardUniform` for a union",
+            ));
+        }
+    };
+
+    // Bounds on field types without generic parameters are either trivially
+    // satisfied or an error best reported at the definition
+    let params: Vec<&Ident> = input
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => Some(&param.ident),
+            GenericParam::Const(param) => Some(&param.ident),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut where_clause = where_clause
+        .cloned()
+        .unwrap_or_else(|| syn::parse_quote!(where));
+    for ty in field_types {
+        if mentions_any(ty, &params) {
+            where_clause.predicates.push(syn::parse_quote! {
+                #krate::distr::StandardUniform: #krate::distr::Distribution<#ty>
+            });
+        }
+    }
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #krate::distr::Distribution<#name #ty_generics>
+            for #krate::distr::StandardUniform
+        #where_clause
+        {
+            #[inline]
+            fn sample<R: #krate::Rng + ?Sized>(&self, rng: &mut R) -> #name #ty_generics {
+                #body
+            }
+        }
+    })
+}
+
+/// Whether `ty` mentions any of the generic parameters `params`
+fn mentions_any(ty: &Type, params: &[&Ident]) -> bool {
+    fn visit(tokens: TokenStream2, params: &[&Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => params.contains(&&ident),
+            TokenTree::Group(group) => visit(group.stream(), params),
+            _ => false,
+        })
+    }
+    !params.is_empty() && visit(ty.to_token_stream(), params)
+}
+
+/// Field initializers (if any), with each field sampled from `StandardUniform`
+fn sample_fields(fields: &Fields, krate: &Path) -> Result<TokenStream2> {
+    for field in fields {
+        if let Some(attr) = field.attrs.iter().find(|attr| attr.path().is_ident("rand")) {
+            return Err(Error::new_spanned(
+                attr,
+                "`rand` attributes are not supported on fields",
+            ));
+        }
+    }
+
+    // The type is explicit since inference would otherwise prefer a bound in
+    // the where clause, e.g. sample `T` for a field of type `bool`
+    let values = fields.iter().map(|f| {
+        let ty = &f.ty;
+        quote! { #krate::Rng::random::<#ty>(rng) }
+    });
+    Ok(match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|f| &f.ident);
+            quote! { { #(#names: #values,)* } }
+        }
+        Fields::Unnamed(_) => quote! { ( #(#values,)* ) },
+        Fields::Unit => quote! {},
+    })
+}
+
+/// Parse `#[rand(weight = N)]`
+fn parse_weight(attrs: &[Attribute]) -> Result<Option<u32>> {
+    let mut weight = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("rand")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("weight") {
+                if weight.is_some() {
+                    return Err(meta.error("duplicate `weight` attribute"));
+                }
+                let lit: LitInt = meta.value()?.parse()?;
+                weight = Some(lit.base10_parse()?);
+                Ok(())
+            } else if meta.path.is_ident("crate") {
+                Err(meta.error("`crate` is only supported on the struct or enum"))
+            } else {
+                Err(meta.error("unsupported `rand` attribute"))
+            }
+        })?;
+    }
+    Ok(weight)
+}
+
+/// Parse `#[rand(crate = path)]`, defaulting to `::rand`
+fn parse_crate(attrs: &[Attribute]) -> Result<Path> {
+    let mut krate = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("rand")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                if krate.is_some() {
+                    return Err(meta.error("duplicate `crate` attribute"));
+                }
+                krate = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("weight") {
+                Err(meta.error("`weight` is only supported on enum variants"))
+            } else {
+                Err(meta.error("unsupported `rand` attribute"))
+            }
+        })?;
+    }
+    Ok(krate.unwrap_or_else(|| syn::parse_quote!(::rand)))
+}
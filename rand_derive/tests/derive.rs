@@ -0,0 +1,146 @@
+use rand::distr::{Distribution, StandardUniform};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+#[derive(Debug, PartialEq, StandardUniform)]
+enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+#[derive(Debug, PartialEq, StandardUniform)]
+enum Weighted {
+    #[rand(weight = 3)]
+    Likely,
+    Unit,
+    Tuple(bool, u8),
+    Named {
+        x: u16,
+        y: bool,
+    },
+    #[rand(weight = 0)]
+    #[allow(dead_code)]
+    Never,
+}
+
+#[derive(Debug, PartialEq, StandardUniform)]
+enum Single {
+    #[rand(weight = 0)]
+    #[allow(dead_code)]
+    Never,
+    Always(u32),
+}
+
+#[derive(Debug, PartialEq, StandardUniform)]
+struct Named {
+    a: u32,
+    b: (bool, Suit),
+}
+
+#[derive(Debug, PartialEq, StandardUniform)]
+struct Tuple(u8, f64);
+
+#[derive(Debug, PartialEq, StandardUniform)]
+struct Unit;
+
+#[derive(Debug, PartialEq, StandardUniform)]
+struct Generic<T, const N: usize>
+where
+    T: Copy,
+{
+    values: [T; N],
+}
+
+#[derive(Debug, PartialEq, StandardUniform)]
+struct Mixed<T> {
+    value: T,
+    flag: bool,
+}
+
+mod reexport {
+    pub use rand::*;
+}
+
+#[derive(Debug, PartialEq, StandardUniform)]
+#[rand(crate = crate::reexport)]
+enum Renamed {
+    #[rand(weight = 2)]
+    A(u8),
+    B,
+}
+
+fn rng() -> SmallRng {
+    SmallRng::seed_from_u64(0x6b1f_0d0e_2e3a_5c47)
+}
+
+#[test]
+fn test_enum() {
+    let mut rng = rng();
+    let mut counts = [0; 4];
+    for _ in 0..4000 {
+        counts[rng.random::<Suit>() as usize] += 1;
+    }
+    for count in counts {
+        assert!((900..=1100).contains(&count), "{:?}", counts);
+    }
+}
+
+#[test]
+fn test_weighted_enum() {
+    let mut rng = rng();
+    let mut counts = [0; 4];
+    for _ in 0..6000 {
+        let i = match rng.random() {
+            Weighted::Likely => 0,
+            Weighted::Unit => 1,
+            Weighted::Tuple(..) => 2,
+            Weighted::Named { .. } => 3,
+            Weighted::Never => panic!("sampled a variant of zero weight"),
+        };
+        counts[i] += 1;
+    }
+    assert!((2800..=3200).contains(&counts[0]), "{:?}", counts);
+    for count in &counts[1..] {
+        assert!((850..=1150).contains(count), "{:?}", counts);
+    }
+
+    assert!(matches!(rng.random::<Single>(), Single::Always(_)));
+}
+
+#[test]
+fn test_struct() {
+    // Fields are sampled in order of declaration
+    let expected = {
+        let mut rng = rng();
+        let a: u32 = rng.random();
+        let b = rng.random();
+        Named { a, b }
+    };
+    assert_eq!(rng().random::<Named>(), expected);
+
+    let mut r1 = rng();
+    let mut r2 = rng();
+    assert_eq!(r1.random::<Tuple>(), Tuple(r2.random(), r2.random()));
+    assert_eq!(r1.random::<Unit>(), Unit);
+
+    let x: Generic<u8, 5> = StandardUniform.sample(&mut r1);
+    assert_eq!(x.values, r2.random::<[u8; 5]>());
+
+    let x: Mixed<u16> = r1.random();
+    assert_eq!((x.value, x.flag), (r2.random(), r2.random()));
+}
+
+#[test]
+fn test_crate_path() {
+    let mut r1 = rng();
+    let mut r2 = rng();
+    for _ in 0..10 {
+        let expected = match r2.random_range(0..3u32) {
+            0..2 => Renamed::A(r2.random()),
+            _ => Renamed::B,
+        };
+        assert_eq!(r1.random::<Renamed>(), expected);
+    }
+}
@@ -43,6 +43,12 @@ use serde::{Deserialize, Serialize};
 /// This `Bernoulli` distribution uses 64 bits from the RNG (a `u64`),
 /// so only probabilities that are multiples of 2<sup>-64</sup> can be
 /// represented.
+///
+/// Sampling uses integer arithmetic only. The same holds for construction via
+/// [`Bernoulli::from_fixed`], [`Bernoulli::from_ratio_u64`],
+/// [`Bernoulli::from_ratio_const`] and [`Bernoulli::from_odds`], thus these
+/// are suitable where floating point is unavailable or results must be
+/// reproducible bit for bit.
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Bernoulli {
@@ -105,8 +111,8 @@ impl Bernoulli {
     /// a multiple of 2<sup>-64</sup>. (Note that not all multiples of
     /// 2<sup>-64</sup> in `[0, 1]` can be represented as a `f64`.)
     #[inline]
-    pub fn new(p: f64) -> Result<Bernoulli, BernoulliError> {
-        if !(0.0..1.0).contains(&p) {
+    pub const fn new(p: f64) -> Result<Bernoulli, BernoulliError> {
+        if !(p >= 0.0 && p < 1.0) {
             if p == 1.0 {
                 return Ok(Bernoulli { p_int: ALWAYS_TRUE });
             }
@@ -121,33 +127,154 @@ impl Bernoulli {
     /// `numerator`-in-`denominator`. I.e. `new_ratio(2, 3)` will return
     /// a `Bernoulli` with a 2-in-3 chance, or about 67%, of returning `true`.
     ///
-    /// return `true`. If `numerator == 0` it will always return `false`.
     /// For `numerator > denominator` and `denominator == 0`, this returns an
     /// error. Otherwise, for `numerator == denominator`, samples are always
     /// true; for `numerator == 0` samples are always false.
     #[inline]
-    pub fn from_ratio(numerator: u32, denominator: u32) -> Result<Bernoulli, BernoulliError> {
+    pub const fn from_ratio(numerator: u32, denominator: u32) -> Result<Bernoulli, BernoulliError> {
         if numerator > denominator || denominator == 0 {
             return Err(BernoulliError::InvalidProbability);
         }
         if numerator == denominator {
             return Ok(Bernoulli { p_int: ALWAYS_TRUE });
         }
-        let p_int = ((f64::from(numerator) / f64::from(denominator)) * SCALE) as u64;
+        let p_int = ((numerator as f64 / denominator as f64) * SCALE) as u64;
         Ok(Bernoulli { p_int })
     }
 
+    /// Construct a new `Bernoulli` with the probability of success of
+    /// `numerator`-in-`denominator`, for 64-bit integers.
+    ///
+    /// Unlike [`Bernoulli::from_ratio`], the probability is converted to
+    /// fixed-point exactly, rounding down to a multiple of 2<sup>-64</sup>.
+    ///
+    /// For `numerator > denominator` and `denominator == 0`, this returns an
+    /// error. Otherwise, for `numerator == denominator`, samples are always
+    /// true; for `numerator == 0` samples are always false.
+    #[inline]
+    pub const fn from_ratio_u64(
+        numerator: u64,
+        denominator: u64,
+    ) -> Result<Bernoulli, BernoulliError> {
+        if numerator > denominator || denominator == 0 {
+            return Err(BernoulliError::InvalidProbability);
+        }
+        Ok(Self::from_fraction(numerator as u128, denominator as u128))
+    }
+
+    /// Construct a new `Bernoulli` with the probability of success of
+    /// `numerator`-in-`denominator`, in const context.
+    ///
+    /// This is equivalent to [`Bernoulli::from_ratio_u64`], but panics on
+    /// invalid input, allowing use in `const` items:
+    ///
+    /// ```
+    /// use rand::distr::{Bernoulli, Distribution};
+    ///
+    /// const ONE_IN_THREE: Bernoulli = Bernoulli::from_ratio_const(1, 3);
+    /// let v = ONE_IN_THREE.sample(&mut rand::rng());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `numerator > denominator` or `denominator == 0`; in const
+    /// context, this is a compile-time error.
+    #[inline]
+    #[track_caller]
+    pub const fn from_ratio_const(numerator: u64, denominator: u64) -> Bernoulli {
+        match Self::from_ratio_u64(numerator, denominator) {
+            Ok(distr) => distr,
+            Err(_) => panic!("numerator > denominator or denominator == 0"),
+        }
+    }
+
+    /// Construct a new `Bernoulli` with odds of success of `success`
+    /// to `failure`.
+    ///
+    /// I.e. `from_odds(3, 1)` will return a `Bernoulli` with a 3-in-4 chance
+    /// of returning `true`. The probability is converted to fixed-point
+    /// exactly, as in [`Bernoulli::from_ratio_u64`].
+    ///
+    /// For `success == 0` and `failure == 0`, this returns an error.
+    /// Otherwise, for `failure == 0` samples are always true; for
+    /// `success == 0` samples are always false.
+    #[inline]
+    pub const fn from_odds(success: u64, failure: u64) -> Result<Bernoulli, BernoulliError> {
+        if success == 0 && failure == 0 {
+            return Err(BernoulliError::InvalidProbability);
+        }
+        let total = success as u128 + failure as u128;
+        Ok(Self::from_fraction(success as u128, total))
+    }
+
+    /// Construct a new `Bernoulli` with the probability of success
+    /// `p = x / 2^64`, given as a 64-bit fixed-point fraction `x`.
+    ///
+    /// This is exact and uses no floating point. Since `p = 1` is not of this
+    /// form, `x = u64::MAX` is interpreted as `p = 1` instead, such that
+    /// samples are always true. For `x = 0` samples are always false.
+    ///
+    /// ```
+    /// use rand::distr::{Bernoulli, Distribution};
+    ///
+    /// // p = 2^-20
+    /// let rare = Bernoulli::from_fixed(1 << 44);
+    /// assert_eq!(rare.p_fixed(), 1 << 44);
+    /// let v = rare.sample(&mut rand::rng());
+    /// # let _ = v;
+    /// ```
+    #[inline]
+    pub const fn from_fixed(x: u64) -> Bernoulli {
+        Bernoulli { p_int: x }
+    }
+
+    /// Construct from `numerator / denominator`, where
+    /// `numerator <= denominator` and `denominator > 0`
+    #[inline]
+    const fn from_fraction(numerator: u128, denominator: u128) -> Bernoulli {
+        if numerator == denominator {
+            return Bernoulli { p_int: ALWAYS_TRUE };
+        }
+        // numerator < denominator, thus the quotient is less than 2^64
+        let p_int = ((numerator << 64) / denominator) as u64;
+        Bernoulli { p_int }
+    }
+
     #[inline]
     /// Returns the probability (`p`) of the distribution.
     ///
     /// This value may differ slightly from the input due to loss of precision.
-    pub fn p(&self) -> f64 {
+    pub const fn p(&self) -> f64 {
         if self.p_int == ALWAYS_TRUE {
             1.0
         } else {
             (self.p_int as f64) / SCALE
         }
     }
+
+    /// Sample in constant time
+    ///
+    /// Unlike [`Distribution::sample`], this always consumes a `u64` from
+    /// `rng` (also for `p = 1`) and uses no branches depending on `p` or the
+    /// output, as far as compiled code follows the source: Rust gives no
+    /// guarantee of constant-time code generation. Results are identical to
+    /// [`Distribution::sample`] except that the RNG is advanced for `p = 1`.
+    #[inline]
+    pub fn sample_ct<R: Rng + ?Sized>(&self, rng: &mut R) -> bool {
+        let v: u64 = rng.random();
+        // Non-short-circuiting `|` evaluates both comparisons
+        (v < self.p_int) | (self.p_int == ALWAYS_TRUE)
+    }
+
+    /// Returns the probability of success as a 64-bit fixed-point fraction
+    /// `x = p * 2^64`, rounded down.
+    ///
+    /// As for [`Bernoulli::from_fixed`], `p = 1` is represented by
+    /// `x = u64::MAX`.
+    #[inline]
+    pub const fn p_fixed(&self) -> u64 {
+        self.p_int
+    }
 }
 
 impl Distribution<bool> for Bernoulli {
@@ -222,6 +349,84 @@ mod test {
         assert!((avg2 - (NUM as f64) / (DENOM as f64)).abs() < 5e-3);
     }
 
+    #[test]
+    fn test_from_ratio_u64() {
+        assert_eq!(Bernoulli::from_ratio_u64(1, 2).unwrap().p_int, 1 << 63);
+        assert_eq!(Bernoulli::from_ratio_u64(1, 3).unwrap().p_int, u64::MAX / 3);
+        assert_eq!(
+            Bernoulli::from_ratio_u64(u64::MAX - 1, u64::MAX)
+                .unwrap()
+                .p_int,
+            u64::MAX - 1
+        );
+        assert_eq!(Bernoulli::from_ratio_u64(0, 7).unwrap().p(), 0.0);
+        assert_eq!(Bernoulli::from_ratio_u64(7, 7).unwrap().p(), 1.0);
+        assert!(Bernoulli::from_ratio_u64(1, 0).is_err());
+        assert!(Bernoulli::from_ratio_u64(2, 1).is_err());
+
+        assert_eq!(Bernoulli::from_odds(1, 1), Bernoulli::from_ratio_u64(1, 2));
+        assert_eq!(Bernoulli::from_odds(3, 1).unwrap().p(), 0.75);
+        assert_eq!(
+            Bernoulli::from_odds(u64::MAX, u64::MAX).unwrap().p_int,
+            1 << 63
+        );
+        assert_eq!(Bernoulli::from_odds(0, 5).unwrap().p(), 0.0);
+        assert_eq!(Bernoulli::from_odds(5, 0).unwrap().p(), 1.0);
+        assert!(Bernoulli::from_odds(0, 0).is_err());
+    }
+
+    #[test]
+    fn test_from_fixed() {
+        assert_eq!(
+            Bernoulli::from_fixed(1 << 63),
+            Bernoulli::from_ratio_u64(1, 2).unwrap()
+        );
+        assert_eq!(Bernoulli::from_fixed(u64::MAX / 3).p_fixed(), u64::MAX / 3);
+        assert_eq!(Bernoulli::new(0.25).unwrap().p_fixed(), 1 << 62);
+        assert_eq!(Bernoulli::new(1.0).unwrap().p_fixed(), u64::MAX);
+
+        let mut rng = crate::test::rng(4);
+        let always = Bernoulli::from_fixed(u64::MAX);
+        let never = Bernoulli::from_fixed(0);
+        for _ in 0..100 {
+            assert!(always.sample(&mut rng));
+            assert!(!never.sample(&mut rng));
+        }
+        // Exact, unlike a conversion from f64 which rounds to 53 bits
+        assert_eq!(Bernoulli::from_fixed(u64::MAX - 1).p_fixed(), u64::MAX - 1);
+    }
+
+    #[test]
+    fn test_sample_ct() {
+        let d = Bernoulli::new(0.3).unwrap();
+        let (mut r1, mut r2) = (crate::test::rng(5), crate::test::rng(5));
+        for _ in 0..100 {
+            assert_eq!(d.sample_ct(&mut r1), d.sample(&mut r2));
+        }
+        let always = Bernoulli::new(1.0).unwrap();
+        let never = Bernoulli::new(0.0).unwrap();
+        assert!(always.sample_ct(&mut crate::test::const_rng(u64::MAX)));
+        assert!(!never.sample_ct(&mut crate::test::const_rng(0)));
+    }
+
+    #[test]
+    fn test_const() {
+        const COIN: Bernoulli = Bernoulli::from_ratio_const(1, 2);
+        const NEVER: Bernoulli = Bernoulli::from_ratio_const(0, 1);
+        const P: Result<Bernoulli, crate::distr::BernoulliError> = Bernoulli::new(0.25);
+        const RATIO: Result<Bernoulli, crate::distr::BernoulliError> = Bernoulli::from_ratio(1, 4);
+        assert_eq!(Ok(COIN), Bernoulli::from_ratio_u64(1, 2));
+        assert_eq!(NEVER.p(), 0.0);
+        assert_eq!(P, RATIO);
+        assert_eq!(P, Bernoulli::from_odds(1, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_ratio_const_invalid() {
+        Bernoulli::from_ratio_const(2, 1);
+    }
+
     #[test]
     fn value_stability() {
         let mut rng = crate::test::rng(3);
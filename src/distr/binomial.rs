@@ -0,0 +1,422 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The binomial distribution `Binomial(n, p)`.
+
+use crate::Rng;
+use crate::distr::Distribution;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [binomial distribution](https://en.wikipedia.org/wiki/Binomial_distribution) `Binomial(n, p)`.
+///
+/// This distribution has density function:
+/// `f(k) = n!/(k! (n-k)!) p^k (1-p)^(n-k)` for `k >= 0`.
+/// It describes the number of successes in `n` independent trials, each of
+/// which succeeds with probability `p`. See also [`Bernoulli`] for `n = 1`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Binomial, Distribution};
+///
+/// let bin = Binomial::new(20, 0.3).unwrap();
+/// let v = bin.sample(&mut rand::rng());
+/// println!("{} is from a binomial distribution", v);
+/// ```
+///
+/// # Implementation
+///
+/// Where `min(p, 1 - p) · n < 10`, the distribution is sampled by inversion,
+/// taking `O(np)` time. Otherwise, the BTPE algorithm of V. Kachitvichyanukul
+/// and B. W. Schmeiser, "Binomial random variate generation", 1988, is used,
+/// taking `O(1)` expected time for any `n`.
+///
+/// Note: this is feature-gated on `std` due to usage of `f64::ln`.
+///
+/// [`Bernoulli`]: crate::distr::Bernoulli
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+    /// Whether samples are computed for `1 - p`, then subtracted from `n`
+    flipped: bool,
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum Method {
+    /// The result is constant
+    Constant,
+    Inversion(Inversion),
+    Btpe(Btpe),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Inversion {
+    /// `p / q`
+    s: f64,
+    /// `(n + 1) · s`
+    a: f64,
+    /// `q^n`, the probability of zero successes
+    r: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Btpe {
+    npq: f64,
+    /// The mode
+    m: f64,
+    x_m: f64,
+    x_l: f64,
+    x_r: f64,
+    c: f64,
+    lambda_l: f64,
+    lambda_r: f64,
+    p1: f64,
+    p2: f64,
+    p3: f64,
+    p4: f64,
+}
+
+/// Error type returned from [`Binomial::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinomialError {
+    /// `p < 0`, `p > 1` or `p` is NaN.
+    InvalidProbability,
+}
+
+impl fmt::Display for BinomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BinomialError::InvalidProbability => "p is outside [0, 1] in binomial distribution",
+        })
+    }
+}
+
+impl std::error::Error for BinomialError {}
+
+impl Binomial {
+    /// Construct a new `Binomial` with the given number of trials `n` and
+    /// probability of success `p`.
+    pub fn new(n: u64, p: f64) -> Result<Binomial, BinomialError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(BinomialError::InvalidProbability);
+        }
+
+        let flipped = p > 0.5;
+        let p_min = if flipped { 1.0 - p } else { p };
+        let q = 1.0 - p_min;
+        let n_f = n as f64;
+        let method = if p_min == 0.0 || n == 0 {
+            Method::Constant
+        } else if n_f * p_min < 10.0 {
+            let s = p_min / q;
+            Method::Inversion(Inversion {
+                s,
+                a: (n_f + 1.0) * s,
+                r: (n_f * (-p_min).ln_1p()).exp(),
+            })
+        } else {
+            let npq = n_f * p_min * q;
+            let f_m = n_f * p_min + p_min;
+            let m = f_m.floor();
+            let p1 = (2.195 * npq.sqrt() - 4.6 * q).floor() + 0.5;
+            let x_m = m + 0.5;
+            let x_l = x_m - p1;
+            let x_r = x_m + p1;
+            let c = 0.134 + 20.5 / (15.3 + m);
+            let lambda_l = {
+                let a = (f_m - x_l) / (f_m - x_l * p_min);
+                a * (1.0 + 0.5 * a)
+            };
+            let lambda_r = {
+                let a = (x_r - f_m) / (x_r * q);
+                a * (1.0 + 0.5 * a)
+            };
+            let p2 = p1 * (1.0 + 2.0 * c);
+            let p3 = p2 + c / lambda_l;
+            let p4 = p3 + c / lambda_r;
+            Method::Btpe(Btpe {
+                npq,
+                m,
+                x_m,
+                x_l,
+                x_r,
+                c,
+                lambda_l,
+                lambda_r,
+                p1,
+                p2,
+                p3,
+                p4,
+            })
+        };
+
+        Ok(Binomial {
+            n,
+            p,
+            flipped,
+            method,
+        })
+    }
+
+    /// Returns the number of trials `n` of the distribution.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the probability of success `p` of the distribution.
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Sample by inversion of the distribution function
+    fn sample_inversion<R: Rng + ?Sized>(&self, params: &Inversion, rng: &mut R) -> u64 {
+        // Beyond this, (rare) rounding errors could make sampling very slow
+        const MAX_X: u64 = 110;
+
+        'outer: loop {
+            let mut u: f64 = rng.random();
+            let mut x = 0;
+            let mut r = params.r;
+            while u > r {
+                u -= r;
+                x += 1;
+                if x > MAX_X {
+                    continue 'outer;
+                }
+                r *= params.a / (x as f64) - params.s;
+            }
+            return x;
+        }
+    }
+
+    /// Sample using the BTPE algorithm
+    fn sample_btpe<R: Rng + ?Sized>(&self, params: &Btpe, rng: &mut R) -> u64 {
+        // Threshold for using explicit evaluation of the density
+        const SQUEEZE_THRESHOLD: f64 = 20.0;
+
+        let n = self.n as f64;
+        let p = if self.flipped { 1.0 - self.p } else { self.p };
+        let q = 1.0 - p;
+        let Btpe {
+            npq,
+            m,
+            x_m,
+            x_l,
+            x_r,
+            c,
+            lambda_l,
+            lambda_r,
+            p1,
+            p2,
+            p3,
+            p4,
+        } = *params;
+
+        loop {
+            let u = rng.random::<f64>() * p4;
+            let mut v: f64 = rng.random();
+            let y;
+            if u <= p1 {
+                // Triangular region: accept immediately
+                return (x_m - p1 * v + u).floor() as u64;
+            } else if u <= p2 {
+                // Parallelogram region
+                let x = x_l + (u - p1) / c;
+                v = v * c + 1.0 - (x - x_m).abs() / p1;
+                if v > 1.0 {
+                    continue;
+                }
+                y = x.floor();
+            } else if u <= p3 {
+                // Left exponential tail
+                y = (x_l + v.ln() / lambda_l).floor();
+                if y < 0.0 {
+                    continue;
+                }
+                v *= (u - p2) * lambda_l;
+            } else {
+                // Right exponential tail
+                y = (x_r - v.ln() / lambda_r).floor();
+                if y > n {
+                    continue;
+                }
+                v *= (u - p3) * lambda_r;
+            }
+
+            let k = (y - m).abs();
+            if k <= SQUEEZE_THRESHOLD || k >= npq / 2.0 - 1.0 {
+                // Evaluate f(y) / f(m) recursively
+                let s = p / q;
+                let a = s * (n + 1.0);
+                let mut f = 1.0;
+                let mut i = m + 1.0;
+                while i <= y {
+                    f *= a / i - s;
+                    i += 1.0;
+                }
+                let mut i = y + 1.0;
+                while i <= m {
+                    f /= a / i - s;
+                    i += 1.0;
+                }
+                if v <= f {
+                    return y as u64;
+                }
+                continue;
+            }
+
+            // Squeeze using upper and lower bounds on ln(f(y) / f(m))
+            let rho = (k / npq) * ((k * (k / 3.0 + 0.625) + 1.0 / 6.0) / npq + 0.5);
+            let t = -0.5 * k * k / npq;
+            let alpha = v.ln();
+            if alpha < t - rho {
+                return y as u64;
+            }
+            if alpha > t + rho {
+                continue;
+            }
+
+            // Final acceptance test using Stirling's formula
+            let x1 = y + 1.0;
+            let f1 = m + 1.0;
+            let z = n + 1.0 - m;
+            let w = n - y + 1.0;
+            // Note that z / w = 1 + (y - m) / w; this avoids cancellation
+            // for large n
+            let bound = x_m * (f1 / x1).ln()
+                + (n - m + 0.5) * ((y - m) / w).ln_1p()
+                + (y - m) * (w * p / (x1 * q)).ln()
+                + stirling(f1)
+                + stirling(z)
+                + stirling(x1)
+                + stirling(w);
+            if alpha <= bound {
+                return y as u64;
+            }
+        }
+    }
+}
+
+/// Correction term of Stirling's approximation to `ln(a!)`
+fn stirling(a: f64) -> f64 {
+    let a2 = a * a;
+    (13860.0 - (462.0 - (132.0 - (99.0 - 140.0 / a2) / a2) / a2) / a2) / a / 166320.0
+}
+
+impl Distribution<u64> for Binomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let x = match &self.method {
+            Method::Constant => 0,
+            Method::Inversion(params) => self.sample_inversion(params, rng),
+            Method::Btpe(params) => self.sample_btpe(params, rng),
+        };
+        if self.flipped { self.n - x } else { x }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Check the sample mean and variance of `Binomial(n, p)`
+    fn test_moments(n: u64, p: f64, seed: u64) {
+        let mut rng = crate::test::rng(seed);
+        let binomial = Binomial::new(n, p).unwrap();
+        let expected_mean = n as f64 * p;
+        let expected_var = expected_mean * (1.0 - p);
+        let samples = 10_000;
+        let (mut sum, mut sum_sq) = (0.0, 0.0);
+        for _ in 0..samples {
+            let x = binomial.sample(&mut rng);
+            assert!(x <= n);
+            let x = x as f64;
+            sum += x;
+            sum_sq += x * x;
+        }
+        let mean = sum / samples as f64;
+        let var = sum_sq / samples as f64 - mean * mean;
+        // Allow for five standard errors of the mean
+        assert!((mean - expected_mean).abs() <= 5.0 * (expected_var / samples as f64).sqrt());
+        if expected_var > 0.0 {
+            assert!((var / expected_var - 1.0).abs() < 0.1);
+        } else {
+            assert_eq!(var, 0.0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_binomial() {
+        test_moments(150, 0.1, 240);
+        test_moments(150, 0.5, 241);
+        test_moments(150, 0.9, 242);
+        test_moments(20, 0.3, 243);
+        test_moments(1, 0.5, 244);
+        test_moments(1_000_000_000, 0.4, 245);
+        test_moments(1_000_000_000, 1e-8, 246);
+        test_moments(u64::MAX, 1e-17, 247);
+        test_moments(0, 0.5, 248);
+        test_moments(20, 0.0, 249);
+        test_moments(20, 1.0, 250);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_binomial_probabilities() {
+        // Compare frequencies to the probability mass function, for a case
+        // using BTPE including its squeeze and final acceptance tests
+        let mut rng = crate::test::rng(251);
+        let (n, p) = (1000, 0.3);
+        let binomial = Binomial::new(n, p).unwrap();
+        let samples = 100_000;
+        let mut counts = [0u32; 1001];
+        for _ in 0..samples {
+            counts[binomial.sample(&mut rng) as usize] += 1;
+        }
+
+        // Recursively compute the probabilities starting from the mode
+        let mut pmf = [0.0; 1001];
+        pmf[300] = 1.0;
+        for k in 300..1000 {
+            pmf[k + 1] = pmf[k] * (n - k as u64) as f64 / (k + 1) as f64 * p / (1.0 - p);
+        }
+        for k in (0..300).rev() {
+            pmf[k] = pmf[k + 1] * (k + 1) as f64 / (n - k as u64) as f64 * (1.0 - p) / p;
+        }
+        let total: f64 = pmf.iter().sum();
+        for (count, p) in counts.iter().zip(pmf) {
+            let expected = p / total * samples as f64;
+            assert!((*count as f64 - expected).abs() < 5.0 * expected.sqrt() + 1.0);
+        }
+    }
+
+    #[test]
+    fn test_binomial_invalid() {
+        assert_eq!(
+            Binomial::new(20, -0.1),
+            Err(BinomialError::InvalidProbability)
+        );
+        assert_eq!(
+            Binomial::new(20, 1.1),
+            Err(BinomialError::InvalidProbability)
+        );
+        assert_eq!(
+            Binomial::new(20, f64::NAN),
+            Err(BinomialError::InvalidProbability)
+        );
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A distribution over a custom alphabet of `char`s
+
+use crate::Rng;
+use crate::distr::uniform::{UniformSampler, UniformUsize};
+use crate::distr::{Distribution, SampleString};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Sample a `char`, uniformly distributed over a custom alphabet.
+///
+/// A `Charset` is constructed from a set of `char`s, e.g. hexadecimal digits
+/// or the Base58 alphabet. Each distinct `char` is sampled with equal
+/// probability, for any alphabet size, without the bias of taking a random
+/// value modulo the alphabet size. Duplicate `char`s are ignored.
+///
+/// See also [`slice::Choose`], which samples references to elements of a
+/// borrowed slice.
+///
+/// With the `serde` feature, only the `char`s are serialized.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Charset, SampleString};
+///
+/// let base58 = Charset::new("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz").unwrap();
+/// let id = base58.sample_string(&mut rand::rng(), 22);
+/// println!("Random ID: {}", id);
+/// ```
+///
+/// [`slice::Choose`]: crate::distr::slice::Choose
+#[derive(Debug, Clone, PartialEq)]
+pub struct Charset {
+    /// Distinct chars in ascending order
+    chars: Box<[char]>,
+    range: UniformUsize,
+    /// The maximum UTF-8 length of `chars`
+    max_len_utf8: usize,
+}
+
+/// Error type returned from [`Charset::new`] and [`Charset::from_chars`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CharsetError {
+    /// The set of `char`s is empty.
+    Empty,
+}
+
+impl fmt::Display for CharsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CharsetError::Empty => "the set of chars is empty",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CharsetError {}
+
+impl Charset {
+    /// Construct a new `Charset` from the `char`s of `alphabet`
+    pub fn new(alphabet: &str) -> Result<Charset, CharsetError> {
+        Self::from_chars(alphabet.chars())
+    }
+
+    /// Construct a new `Charset` from a sequence of `char`s
+    pub fn from_chars<I>(chars: I) -> Result<Charset, CharsetError>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let mut chars: Vec<char> = chars.into_iter().collect();
+        chars.sort_unstable();
+        chars.dedup();
+        if chars.is_empty() {
+            return Err(CharsetError::Empty);
+        }
+        let max_len_utf8 = chars.iter().map(|c| c.len_utf8()).max().unwrap();
+        Ok(Charset {
+            range: UniformUsize::new(0, chars.len()).unwrap(),
+            chars: chars.into_boxed_slice(),
+            max_len_utf8,
+        })
+    }
+
+    /// Returns the distinct `char`s of the alphabet, in ascending order
+    pub fn chars(&self) -> &[char] {
+        &self.chars
+    }
+}
+
+impl Distribution<char> for Charset {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        self.chars[self.range.sample(rng)]
+    }
+}
+
+impl SampleString for Charset {
+    fn append_string<R: Rng + ?Sized>(&self, rng: &mut R, string: &mut String, len: usize) {
+        string.reserve(self.max_len_utf8 * len);
+        string.extend(self.sample_iter(rng).take(len));
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Charset {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let chars: String = self.chars.iter().collect();
+        let mut state = serializer.serialize_struct("Charset", 1)?;
+        state.serialize_field("chars", &chars)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Charset {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Charset")]
+        struct Repr {
+            chars: String,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        Charset::new(&repr.chars).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_charset() {
+        let mut rng = crate::test::rng(812);
+        // An alphabet of 56 distinct chars, including multi-byte and duplicate chars
+        let charset =
+            Charset::new("0123456789abcdefghijklmnopqrstuvwxyzäöüßαβγδεζηθικλμνξο€€").unwrap();
+        assert_eq!(charset.chars().len(), 56);
+        assert!(charset.chars().windows(2).all(|w| w[0] < w[1]));
+
+        let n = 56_000;
+        let s = charset.sample_string(&mut rng, n);
+        assert_eq!(s.chars().count(), n);
+        for &c in charset.chars() {
+            let count = s.chars().filter(|&x| x == c).count() as f64;
+            // Expected 1000, with standard deviation below 32
+            assert!((count - 1000.0).abs() < 160.0);
+        }
+
+        let one = Charset::from_chars(['x', 'x']).unwrap();
+        assert_eq!(one.sample_string(&mut rng, 3), "xxx");
+        assert_eq!(Charset::new(""), Err(CharsetError::Empty));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialization() {
+        let charset = Charset::new("fedcba").unwrap();
+        let json = serde_json::to_string(&charset).unwrap();
+        assert_eq!(json, r#"{"chars":"abcdef"}"#);
+        let de: Charset = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, charset);
+        assert!(serde_json::from_str::<Charset>(r#"{"chars":""}"#).is_err());
+    }
+}
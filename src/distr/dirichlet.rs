@@ -0,0 +1,180 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Dirichlet distribution `Dirichlet(α₁, α₂, ..., αₖ)`.
+
+use crate::Rng;
+use crate::distr::Distribution;
+use crate::distr::gamma::sample_ln_gamma;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [Dirichlet distribution](https://en.wikipedia.org/wiki/Dirichlet_distribution) `Dirichlet(α₁, α₂, ..., αₖ)`.
+///
+/// The Dirichlet distribution is a family of continuous multivariate
+/// probability distributions parameterized by a vector of positive
+/// concentration parameters `α`. Samples are vectors of `k` non-negative
+/// values which sum to one, i.e. probability vectors; it is the conjugate
+/// prior of the [`Multinomial`](crate::distr::Multinomial) distribution.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Dirichlet, Distribution};
+///
+/// let dirichlet = Dirichlet::new(&[1.0, 2.0, 3.0]).unwrap();
+/// let samples = dirichlet.sample(&mut rand::rng());
+/// println!("{:?} is from a Dirichlet([1.0, 2.0, 3.0]) distribution", samples);
+/// ```
+///
+/// # Implementation
+///
+/// Each sample normalizes a vector of independent
+/// [`Gamma(αᵢ, 1)`](crate::distr::Gamma) variates. These are computed in log
+/// space, such that small `αᵢ` (where most variates would underflow to zero)
+/// are supported.
+///
+/// Note: this is feature-gated on `distr_math` as it samples `Gamma`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Dirichlet {
+    alpha: Vec<f64>,
+}
+
+/// Error type returned from [`Dirichlet::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DirichletError {
+    /// `alpha.len() < 2`.
+    AlphaTooShort,
+    /// `alpha <= 0.0` or `nan`.
+    AlphaTooSmall,
+    /// `alpha` is infinite.
+    AlphaInfinite,
+}
+
+impl fmt::Display for DirichletError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DirichletError::AlphaTooShort => "less than 2 dimensions in Dirichlet distribution",
+            DirichletError::AlphaTooSmall => "alpha is not positive in Dirichlet distribution",
+            DirichletError::AlphaInfinite => "alpha is infinite in Dirichlet distribution",
+        })
+    }
+}
+
+impl std::error::Error for DirichletError {}
+
+impl Dirichlet {
+    /// Construct a new `Dirichlet` with the given concentration parameters
+    /// `alpha`.
+    ///
+    /// Requires `alpha.len() >= 2`, and each `alpha[i]` to be positive and
+    /// finite.
+    #[inline]
+    pub fn new(alpha: &[f64]) -> Result<Dirichlet, DirichletError> {
+        if alpha.len() < 2 {
+            return Err(DirichletError::AlphaTooShort);
+        }
+        for &a in alpha {
+            if !(a > 0.0) {
+                return Err(DirichletError::AlphaTooSmall);
+            }
+            if !a.is_finite() {
+                return Err(DirichletError::AlphaInfinite);
+            }
+        }
+        Ok(Dirichlet {
+            alpha: alpha.to_vec(),
+        })
+    }
+
+    /// Returns the concentration parameters `α` of the distribution.
+    pub fn alpha(&self) -> &[f64] {
+        &self.alpha
+    }
+}
+
+impl Distribution<Vec<f64>> for Dirichlet {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let mut samples: Vec<f64> = self
+            .alpha
+            .iter()
+            .map(|&a| sample_ln_gamma(rng, a))
+            .collect();
+        // Scale by the largest variate; at least one value is then 1
+        let max = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let mut sum = 0.0;
+        for x in samples.iter_mut() {
+            *x = (*x - max).exp();
+            sum += *x;
+        }
+        for x in samples.iter_mut() {
+            *x /= sum;
+        }
+        samples
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_dirichlet() {
+        let mut rng = crate::test::rng(405);
+        for alpha in [&[1.0, 2.0, 3.0][..], &[0.5; 4], &[100.0, 1e-3]] {
+            let distr = Dirichlet::new(alpha).unwrap();
+            assert_eq!(distr.alpha(), alpha);
+            let alpha_sum: f64 = alpha.iter().sum();
+
+            let n = 10_000;
+            let mut sums = alloc::vec![0.0; alpha.len()];
+            for _ in 0..n {
+                let x = distr.sample(&mut rng);
+                assert_eq!(x.len(), alpha.len());
+                assert!(x.iter().all(|&x| (0.0..=1.0).contains(&x)));
+                assert!((x.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+                for (s, x) in sums.iter_mut().zip(x) {
+                    *s += x;
+                }
+            }
+            for (&s, &a) in sums.iter().zip(alpha) {
+                let mean = a / alpha_sum;
+                let var = mean * (1.0 - mean) / (alpha_sum + 1.0);
+                let std_err = (var / n as f64).sqrt();
+                assert!((s / n as f64 - mean).abs() < 5.0 * std_err + 1e-12);
+            }
+        }
+
+        // Most Gamma variates underflow at such small alpha
+        let distr = Dirichlet::new(&[1e-300, 1e-300]).unwrap();
+        let x = distr.sample(&mut rng);
+        assert!((x[0] + x[1] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Dirichlet::new(&[1.0]), Err(DirichletError::AlphaTooShort));
+        assert_eq!(
+            Dirichlet::new(&[1.0, 0.0]),
+            Err(DirichletError::AlphaTooSmall)
+        );
+        assert_eq!(
+            Dirichlet::new(&[f64::NAN, 1.0]),
+            Err(DirichletError::AlphaTooSmall)
+        );
+        assert_eq!(
+            Dirichlet::new(&[1.0, f64::INFINITY]),
+            Err(DirichletError::AlphaInfinite)
+        );
+    }
+}
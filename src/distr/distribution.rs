@@ -12,7 +12,7 @@
 use crate::Rng;
 #[cfg(feature = "alloc")]
 use alloc::string::String;
-use core::iter;
+use core::{fmt, iter};
 
 /// Types (distributions) that can be used to create a random instance of `T`.
 ///
@@ -31,11 +31,46 @@ use core::iter;
 /// of The Rust Rand Book. In some cases this does not apply, e.g. the `usize`
 /// type requires different sampling on 32-bit and 64-bit machines.
 ///
+/// Tuples of up to 12 distributions are themselves distributions over tuples,
+/// sampling each component independently and in order:
+///
+/// ```
+/// use rand::distr::{Bernoulli, Distribution, Uniform};
+///
+/// let joint = (Uniform::new(0.0, 1.0).unwrap(), Bernoulli::new(0.5).unwrap());
+/// let (x, b): (f64, bool) = joint.sample(&mut rand::rng());
+/// # let _ = (x, b);
+/// ```
+///
 /// [`sample_iter`]: Distribution::sample_iter
 pub trait Distribution<T> {
     /// Generate a random value of `T`, using `rng` as the source of randomness.
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T;
 
+    /// Fill `out` with random values of `T`, using `rng` as the source of
+    /// randomness.
+    ///
+    /// The default implementation calls [`Distribution::sample`] for each
+    /// element in order. Implementations may override this to generate values
+    /// in bulk; such implementations sample from the same distribution but may
+    /// consume `rng` differently, thus the values are not guaranteed to equal
+    /// those of repeated calls to `sample`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distr::{Distribution, Uniform};
+    ///
+    /// let mut buf = [0u32; 256];
+    /// Uniform::new(0, 1000).unwrap().sample_fill(&mut rand::rng(), &mut buf);
+    /// assert!(buf.iter().all(|&x| x < 1000));
+    /// ```
+    fn sample_fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [T]) {
+        for x in out {
+            *x = self.sample(rng);
+        }
+    }
+
     /// Create an iterator that generates random values of `T`, using `rng` as
     /// the source of randomness.
     ///
@@ -94,6 +129,18 @@ pub trait Distribution<T> {
     ///     println!("Still odd; rolling again!");
     /// }
     /// ```
+    ///
+    /// The result is itself a [`Distribution`], thus may for example be
+    /// sampled as an iterator:
+    ///
+    /// ```
+    /// use rand::distr::{Distribution, StandardUniform};
+    ///
+    /// let sqrt = StandardUniform.map(|x: f64| x.sqrt());
+    /// for x in sqrt.sample_iter(rand::rng()).take(10) {
+    ///     assert!((0.0..1.0).contains(&x));
+    /// }
+    /// ```
     fn map<F, S>(self, func: F) -> Map<Self, F, T, S>
     where
         F: Fn(T) -> S,
@@ -105,14 +152,152 @@ pub trait Distribution<T> {
             phantom: core::marker::PhantomData,
         }
     }
+
+    /// Restrict sampled values to those satisfying `predicate`
+    ///
+    /// The result samples from this distribution truncated to values for
+    /// which `predicate` returns `true`, by rejection: each sample draws
+    /// values until one is accepted, up to `max_tries` times. The expected
+    /// number of tries is the inverse of the probability of acceptance.
+    /// With `max_tries == 0`, sampling always fails.
+    ///
+    /// Use [`Filter::try_sample`] to handle exhaustion of the tries as an
+    /// error; sampling via [`Distribution::sample`] panics instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distr::{Distribution, Uniform};
+    ///
+    /// // Floor numbers of a building without a 13th floor
+    /// let floor = Uniform::new_inclusive(1, 20).unwrap().filter(|&x| x != 13, 100);
+    /// for x in floor.sample_iter(rand::rng()).take(10) {
+    ///     assert!(x != 13);
+    /// }
+    /// ```
+    fn filter<P>(self, predicate: P, max_tries: u32) -> Filter<Self, P, T>
+    where
+        P: Fn(&T) -> bool,
+        Self: Sized,
+    {
+        Filter {
+            distr: self,
+            predicate,
+            max_tries,
+            phantom: core::marker::PhantomData,
+        }
+    }
 }
 
 impl<T, D: Distribution<T> + ?Sized> Distribution<T> for &D {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
         (*self).sample(rng)
     }
+
+    fn sample_fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [T]) {
+        (*self).sample_fill(rng, out)
+    }
+}
+
+/// Types (distributions) that can be used to create a random instance of `T`,
+/// where sampling may fail.
+///
+/// This is the fallible counterpart of [`Distribution`], for distributions
+/// which cannot guarantee to produce a value, e.g. due to a cap on rejection
+/// sampling ([`Filter`]) or a state which is invalid for sampling
+/// ([`WeightedTreeIndex`]). Such types typically also implement
+/// [`Distribution`], panicking where [`TryDistribution::try_sample`] would
+/// return an error.
+///
+/// There is no blanket implementation for infallible distributions (with
+/// `Error = Infallible`): it would overlap with the implementations for
+/// types such as [`Filter`] which implement both traits with a
+/// meaningful error type.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, TryDistribution, Uniform};
+///
+/// let odd = Uniform::new(0, 100).unwrap().filter(|x| x % 2 == 1, 32);
+/// let values: Result<Vec<i32>, _> = odd.try_sample_iter(rand::rng()).take(10).collect();
+/// assert!(values.unwrap().iter().all(|x| x % 2 == 1));
+/// ```
+///
+/// [`WeightedTreeIndex`]: crate::distr::weighted::WeightedTreeIndex
+pub trait TryDistribution<T> {
+    /// The type returned in the event of a sampling error.
+    type Error;
+
+    /// Generate a random value of `T`, using `rng` as the source of
+    /// randomness, or return an error.
+    fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<T, Self::Error>;
+
+    /// Create an iterator that tries to generate random values of `T`, using
+    /// `rng` as the source of randomness.
+    ///
+    /// Each item is the result of [`TryDistribution::try_sample`]; the
+    /// iterator does not stop at errors. Collecting into a `Result` stops at
+    /// the first error.
+    ///
+    /// As with [`Distribution::sample_iter`], this function takes `self` by
+    /// value; use `(&distr).try_sample_iter(...)` to borrow.
+    fn try_sample_iter<R>(self, rng: R) -> TryIter<Self, R, T>
+    where
+        R: Rng,
+        Self: Sized,
+    {
+        TryIter {
+            distr: self,
+            rng,
+            phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, D: TryDistribution<T> + ?Sized> TryDistribution<T> for &D {
+    type Error = D::Error;
+
+    fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<T, Self::Error> {
+        (*self).try_sample(rng)
+    }
 }
 
+/// Implement `Distribution` for a tuple of distributions; takes a list of
+/// pairs of sampled type and distribution type identifiers
+macro_rules! tuple_impl {
+    ($($ty:ident $distr:ident)*) => {
+        /// Samples each component independently and in order
+        impl<$($ty, $distr,)*> Distribution<($($ty,)*)> for ($($distr,)*)
+        where $(
+            $distr: Distribution<$ty>,
+        )*
+        {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ($($ty,)*) {
+                let ($($distr,)*) = self;
+                ($($distr.sample(rng),)*)
+            }
+        }
+    }
+}
+
+/// Looping wrapper for `tuple_impl`. Given (A DA, B DB), it also generates
+/// the implementation for (A DA)
+macro_rules! tuple_impls {
+    ($($ty:ident $distr:ident)*) => {tuple_impls!{[] $($ty $distr)*}};
+
+    ([$($prefix:ident)*] $ty:ident $distr:ident $($tail:ident)*) => {
+        tuple_impl!{$($prefix)* $ty $distr}
+        tuple_impls!{[$($prefix)* $ty $distr] $($tail)*}
+    };
+
+    ([$($prefix:ident)*]) => {};
+}
+
+tuple_impls! {A DA B DB C DC D DD E DE F DF G DG H DH I DI J DJ K DK L DL}
+
 /// An iterator over a [`Distribution`]
 ///
 /// This iterator yields random values of type `T` with distribution `D`
@@ -155,6 +340,43 @@ where
 {
 }
 
+/// An iterator over a [`TryDistribution`]
+///
+/// This iterator yields results of trying to sample values of type `T` with
+/// distribution `D` from a random generator of type `R`.
+///
+/// Construct this `struct` using [`TryDistribution::try_sample_iter`].
+#[derive(Debug)]
+pub struct TryIter<D, R, T> {
+    distr: D,
+    rng: R,
+    phantom: core::marker::PhantomData<T>,
+}
+
+impl<D, R, T> Iterator for TryIter<D, R, T>
+where
+    D: TryDistribution<T>,
+    R: Rng,
+{
+    type Item = Result<T, D::Error>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.distr.try_sample(&mut self.rng))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<D, R, T> iter::FusedIterator for TryIter<D, R, T>
+where
+    D: TryDistribution<T>,
+    R: Rng,
+{
+}
+
 /// A [`Distribution`] which maps sampled values to type `S`
 ///
 /// This `struct` is created by the [`Distribution::map`] method.
@@ -166,6 +388,19 @@ pub struct Map<D, F, T, S> {
     phantom: core::marker::PhantomData<fn(T) -> S>,
 }
 
+// Manual impls avoid bounds on `T` and `S`, which are not stored
+impl<D: Clone, F: Clone, T, S> Clone for Map<D, F, T, S> {
+    fn clone(&self) -> Self {
+        Map {
+            distr: self.distr.clone(),
+            func: self.func.clone(),
+            phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<D: Copy, F: Copy, T, S> Copy for Map<D, F, T, S> {}
+
 impl<D, F, T, S> Distribution<S> for Map<D, F, T, S>
 where
     D: Distribution<T>,
@@ -176,6 +411,101 @@ where
     }
 }
 
+/// A [`Distribution`] restricted to values satisfying a predicate
+///
+/// This `struct` is created by the [`Distribution::filter`] method.
+/// See its documentation for more.
+#[derive(Debug)]
+pub struct Filter<D, P, T> {
+    distr: D,
+    predicate: P,
+    max_tries: u32,
+    phantom: core::marker::PhantomData<fn() -> T>,
+}
+
+/// Error type returned from [`Filter::try_sample`] and its
+/// [`TryDistribution`] implementation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterError {
+    /// No sampled value satisfied the predicate within the maximum number of
+    /// tries.
+    Exhausted,
+}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FilterError::Exhausted => "no sample satisfied the predicate within the maximum tries",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FilterError {}
+
+impl<D, P, T> Filter<D, P, T>
+where
+    D: Distribution<T>,
+    P: Fn(&T) -> bool,
+{
+    /// Sample a value satisfying the predicate, or fail with
+    /// [`FilterError::Exhausted`] if none did within the maximum tries
+    pub fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<T, FilterError> {
+        for _ in 0..self.max_tries {
+            let x = self.distr.sample(rng);
+            if (self.predicate)(&x) {
+                return Ok(x);
+            }
+        }
+        Err(FilterError::Exhausted)
+    }
+}
+
+// Manual impls avoid bounds on `T`, which is not stored
+impl<D: Clone, P: Clone, T> Clone for Filter<D, P, T> {
+    fn clone(&self) -> Self {
+        Filter {
+            distr: self.distr.clone(),
+            predicate: self.predicate.clone(),
+            max_tries: self.max_tries,
+            phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<D: Copy, P: Copy, T> Copy for Filter<D, P, T> {}
+
+impl<D, P, T> TryDistribution<T> for Filter<D, P, T>
+where
+    D: Distribution<T>,
+    P: Fn(&T) -> bool,
+{
+    type Error = FilterError;
+
+    fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<T, FilterError> {
+        Filter::try_sample(self, rng)
+    }
+}
+
+impl<D, P, T> Distribution<T> for Filter<D, P, T>
+where
+    D: Distribution<T>,
+    P: Fn(&T) -> bool,
+{
+    /// Sample a value satisfying the predicate
+    ///
+    /// # Panics
+    ///
+    /// If no sampled value satisfied the predicate within the maximum tries.
+    #[track_caller]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        match self.try_sample(rng) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
 /// Sample or extend a [`String`]
 ///
 /// Helper methods to extend a [`String`] or sample a new [`String`].
@@ -226,6 +556,85 @@ mod tests {
         let mut rng = crate::test::rng(212);
         let val = dist.sample(&mut rng);
         assert!((15..=20).contains(&val));
+
+        // Copy is implemented independently of the sampled type
+        let copy = dist;
+        assert!(
+            copy.sample_iter(&mut rng)
+                .take(10)
+                .all(|val| (15..=20).contains(&val))
+        );
+        assert!((15..=20).contains(&dist.sample(&mut rng)));
+    }
+
+    #[test]
+    fn test_distributions_filter() {
+        use super::FilterError;
+
+        let mut rng = crate::test::rng(215);
+        let dist = Uniform::new(0, 100).unwrap().filter(|x| x % 10 == 0, 1000);
+        for x in (&dist).sample_iter(&mut rng).take(100) {
+            assert!((0..100).contains(&x) && x % 10 == 0);
+        }
+        assert_eq!(dist.try_sample(&mut rng).map(|x| x % 10), Ok(0));
+
+        let never = Uniform::new(0, 100).unwrap().filter(|&x| x >= 100, 10);
+        assert_eq!(never.try_sample(&mut rng), Err(FilterError::Exhausted));
+        let zero_tries = dist.filter(|_| true, 0);
+        assert_eq!(zero_tries.try_sample(&mut rng), Err(FilterError::Exhausted));
+    }
+
+    #[test]
+    fn test_try_sample_iter() {
+        use super::{FilterError, TryDistribution};
+
+        let mut rng = crate::test::rng(217);
+        let dist = Uniform::new(0, 10).unwrap().filter(|&x| x < 5, 1);
+        let mut oks = 0;
+        for result in (&dist).try_sample_iter(&mut rng).take(1000) {
+            match result {
+                Ok(x) => {
+                    assert!(x < 5);
+                    oks += 1;
+                }
+                Err(e) => assert_eq!(e, FilterError::Exhausted),
+            }
+        }
+        assert!((400..=600).contains(&oks));
+
+        let never = Uniform::new(0, 10).unwrap().filter(|_| false, 3);
+        let error = never.try_sample_iter(&mut rng).find_map(Result::err);
+        assert_eq!(error, Some(FilterError::Exhausted));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_distributions_filter_exhausted() {
+        let never = Uniform::new(0, 100).unwrap().filter(|&x| x >= 100, 10);
+        never.sample(&mut crate::test::rng(216));
+    }
+
+    #[test]
+    fn test_distributions_tuple() {
+        use crate::distr::{Bernoulli, StandardUniform};
+
+        let dist = (
+            Uniform::new(10.0, 20.0).unwrap(),
+            Uniform::new_inclusive(1u8, 6).unwrap(),
+            Bernoulli::new(1.0).unwrap(),
+        );
+        let mut rng = crate::test::rng(213);
+        for (x, y, z) in dist.sample_iter(&mut rng).take(100) {
+            assert!((10.0..=20.0).contains(&x));
+            assert!((1..=6).contains(&y));
+            assert!(z);
+        }
+
+        // Equivalent to sampling components in order
+        let (a, b): (u32, u64) =
+            (StandardUniform, StandardUniform).sample(&mut crate::test::rng(214));
+        let mut rng = crate::test::rng(214);
+        assert_eq!((a, b), (rng.random(), rng.random()));
     }
 
     #[test]
@@ -0,0 +1,258 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The empirical distribution of observed samples.
+
+use crate::Rng;
+use crate::distr::uniform::{UniformSampler, UniformUsize};
+use crate::distr::{Distribution, StandardUniform};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [empirical distribution](https://en.wikipedia.org/wiki/Empirical_distribution_function)
+/// of a set of observed samples.
+///
+/// Constructed with [`Empirical::new`], this distribution resamples the
+/// observations uniformly with replacement, as used by the bootstrap: each
+/// observation is sampled with probability `1 / n`, and duplicate
+/// observations are thus proportionally more likely.
+///
+/// Constructed with [`Empirical::new_interpolated`], this distribution is
+/// continuous over `[min, max)` of the observations: its CDF interpolates
+/// linearly between the sorted observations, which are at evenly spaced
+/// quantiles. This is suitable to drive simulations by measured data, such as
+/// traces of latencies.
+///
+/// See also [`slice::Choose`] to resample observations of types other than
+/// `f64`.
+///
+/// With the `serde` feature, the sorted observations are serialized.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Empirical};
+///
+/// let latencies = [12.0, 15.5, 11.2, 30.1, 13.7];
+/// let mut rng = rand::rng();
+///
+/// let resample = Empirical::new(&latencies).unwrap();
+/// let x = resample.sample(&mut rng);
+/// assert!(latencies.contains(&x));
+///
+/// let continuous = Empirical::new_interpolated(&latencies).unwrap();
+/// let y = continuous.sample(&mut rng);
+/// assert!((11.2..30.1).contains(&y));
+/// ```
+///
+/// # Implementation
+///
+/// Sampling takes constant time: continuous samples are generated by
+/// inversion of the interpolated CDF, locating the interval of evenly spaced
+/// quantiles directly.
+///
+/// [`slice::Choose`]: crate::distr::slice::Choose
+#[derive(Clone, Debug, PartialEq)]
+pub struct Empirical {
+    /// Observations in ascending order
+    data: Box<[f64]>,
+    index: UniformUsize,
+    interpolated: bool,
+}
+
+/// Error type returned from [`Empirical::new`] and
+/// [`Empirical::new_interpolated`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmpiricalError {
+    /// There are no observations.
+    Empty,
+    /// An observation is infinite or NaN.
+    NonFinite,
+}
+
+impl fmt::Display for EmpiricalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            EmpiricalError::Empty => "there are no observations in empirical distribution",
+            EmpiricalError::NonFinite => "an observation is not finite in empirical distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EmpiricalError {}
+
+impl Empirical {
+    /// Construct, resampling the observations in `data`
+    ///
+    /// Requires at least one observation, with all observations finite.
+    pub fn new(data: &[f64]) -> Result<Empirical, EmpiricalError> {
+        Self::with_mode(data, false)
+    }
+
+    /// Construct, sampling continuously by linear interpolation between the
+    /// sorted observations in `data`
+    ///
+    /// Requires at least one observation, with all observations finite. With a
+    /// single distinct observation, this always samples that observation.
+    pub fn new_interpolated(data: &[f64]) -> Result<Empirical, EmpiricalError> {
+        Self::with_mode(data, true)
+    }
+
+    fn with_mode(data: &[f64], interpolated: bool) -> Result<Empirical, EmpiricalError> {
+        if data.is_empty() {
+            return Err(EmpiricalError::Empty);
+        }
+        if !data.iter().all(|x| x.is_finite()) {
+            return Err(EmpiricalError::NonFinite);
+        }
+        let mut data: Vec<f64> = data.to_vec();
+        data.sort_unstable_by(f64::total_cmp);
+        // For interpolation, choose among the `len - 1` intervals
+        let len = if interpolated && data.len() > 1 {
+            data.len() - 1
+        } else {
+            data.len()
+        };
+        Ok(Empirical {
+            index: UniformUsize::new(0, len).unwrap(),
+            data: data.into_boxed_slice(),
+            interpolated,
+        })
+    }
+
+    /// Returns the observations, in ascending order
+    pub fn data(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Returns whether samples are interpolated between observations
+    pub fn is_interpolated(&self) -> bool {
+        self.interpolated
+    }
+}
+
+impl Distribution<f64> for Empirical {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let i = self.index.sample(rng);
+        if !self.interpolated || self.data.len() == 1 {
+            return self.data[i];
+        }
+        // Each interval between consecutive observations has equal
+        // probability; interpolate uniformly within the chosen interval
+        let (lo, hi) = (self.data[i], self.data[i + 1]);
+        let f: f64 = rng.sample(StandardUniform);
+        let x = lo + f * (hi - lo);
+        // Rounding may yield `hi`, which belongs to the next interval
+        if x < hi { x } else { lo }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Empirical {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Empirical", 2)?;
+        state.serialize_field("data", &self.data)?;
+        state.serialize_field("interpolated", &self.interpolated)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Empirical {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Empirical")]
+        struct Repr {
+            data: Vec<f64>,
+            interpolated: bool,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        Empirical::with_mode(&repr.data, repr.interpolated).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_empirical() {
+        let mut rng = crate::test::rng(751);
+        let data = [3.0, 1.0, 2.0, 1.0];
+        let d = Empirical::new(&data).unwrap();
+        assert_eq!(d.data(), &[1.0, 1.0, 2.0, 3.0]);
+        assert!(!d.is_interpolated());
+
+        let mut counts = [0; 3];
+        for _ in 0..4000 {
+            let x = d.sample(&mut rng);
+            counts[x as usize - 1] += 1;
+            assert_eq!(x.fract(), 0.0);
+        }
+        assert!((1850..=2150).contains(&counts[0]));
+        assert!((900..=1100).contains(&counts[1]));
+        assert!((900..=1100).contains(&counts[2]));
+
+        assert_eq!(Empirical::new(&[]), Err(EmpiricalError::Empty));
+        assert_eq!(
+            Empirical::new(&[1.0, f64::NAN]),
+            Err(EmpiricalError::NonFinite)
+        );
+        assert_eq!(
+            Empirical::new_interpolated(&[f64::INFINITY]),
+            Err(EmpiricalError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn test_empirical_interpolated() {
+        let mut rng = crate::test::rng(752);
+        // Quantiles 0, 1/3, 2/3 and 1 at 0, 1, 4 and 10
+        let d = Empirical::new_interpolated(&[10.0, 0.0, 4.0, 1.0]).unwrap();
+        assert!(d.is_interpolated());
+
+        let mut counts = [0; 3];
+        for _ in 0..3000 {
+            let x = d.sample(&mut rng);
+            assert!((0.0..10.0).contains(&x));
+            counts[(x >= 1.0) as usize + (x >= 4.0) as usize] += 1;
+        }
+        for count in counts {
+            assert!((900..=1100).contains(&count));
+        }
+
+        let single = Empirical::new_interpolated(&[5.0]).unwrap();
+        assert_eq!(single.sample(&mut rng), 5.0);
+        let same = Empirical::new_interpolated(&[5.0, 5.0]).unwrap();
+        assert_eq!(same.sample(&mut rng), 5.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialization() {
+        let d = Empirical::new_interpolated(&[2.0, 1.0]).unwrap();
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(json, r#"{"data":[1.0,2.0],"interpolated":true}"#);
+        let de: Empirical = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, d);
+        let unsorted = r#"{"data":[2.0,1.0],"interpolated":false}"#;
+        assert_eq!(
+            serde_json::from_str::<Empirical>(unsorted).unwrap(),
+            Empirical::new(&[1.0, 2.0]).unwrap()
+        );
+        assert!(serde_json::from_str::<Empirical>(r#"{"data":[],"interpolated":false}"#).is_err());
+    }
+}
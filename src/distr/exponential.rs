@@ -0,0 +1,484 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The exponential distribution `Exp(λ)` and the related `Pareto` and
+//! `Weibull` distributions.
+
+use crate::Rng;
+#[cfg(feature = "distr_math")]
+use crate::distr::OpenClosed01;
+use crate::distr::utils::ziggurat;
+use crate::distr::ziggurat_tables;
+use crate::distr::{Distribution, Open01};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The standard exponential distribution `Exp(1)`.
+///
+/// This is equivalent to `Exp::new(1.0)`, but faster.
+///
+/// See [`Exp`] for the general exponential distribution.
+///
+/// # Example
+///
+/// ```
+/// use rand::prelude::*;
+/// use rand::distr::Exp1;
+///
+/// let val: f64 = rand::rng().sample(Exp1);
+/// println!("{}", val);
+/// ```
+///
+/// # Implementation
+///
+/// This uses the ziggurat method of G. Marsaglia and W. W. Tsang, "The
+/// Ziggurat Method for Generating Random Variables", 2000, with 256 layers.
+/// Samples of type `f32` are converted from `f64`.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Exp1;
+
+impl Distribution<f64> for Exp1 {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        #[inline]
+        fn pdf(x: f64) -> f64 {
+            (-x).exp()
+        }
+        #[inline]
+        fn zero_case<R: Rng + ?Sized>(rng: &mut R, _u: f64) -> f64 {
+            // The tail beyond R is itself exponential
+            let u: f64 = rng.sample(Open01);
+            ziggurat_tables::ZIG_EXP_R - u.ln()
+        }
+
+        ziggurat(
+            rng,
+            false,
+            ziggurat_tables::ZIG_EXP_X,
+            ziggurat_tables::ZIG_EXP_F,
+            pdf,
+            zero_case,
+        )
+    }
+}
+
+impl Distribution<f32> for Exp1 {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f32 {
+        let x: f64 = self.sample(rng);
+        x as f32
+    }
+}
+
+/// The [exponential distribution](https://en.wikipedia.org/wiki/Exponential_distribution) `Exp(λ)`.
+///
+/// The exponential distribution is a continuous probability distribution
+/// with rate parameter `λ` (`lambda`). It describes the time between events
+/// in a [`Poisson`](crate::distr::Poisson) process, i.e. a process in which
+/// events occur continuously and independently at a constant average rate.
+///
+/// See [`Exp1`] for an optimised implementation for `λ = 1`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Exp};
+///
+/// // Time between requests arriving at 20 per second, on average
+/// let exp = Exp::new(20.0).unwrap();
+/// let v = exp.sample(&mut rand::rng());
+/// println!("{} s until the next request", v);
+/// ```
+///
+/// Note: this is feature-gated on `std` due to usage of `f64::exp`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Exp {
+    /// `1 / λ`
+    lambda_inverse: f64,
+}
+
+/// Error type returned from [`Exp::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExpError {
+    /// `lambda < 0` or `lambda` is NaN.
+    LambdaTooSmall,
+}
+
+impl fmt::Display for ExpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ExpError::LambdaTooSmall => "lambda is negative or NaN in exponential distribution",
+        })
+    }
+}
+
+impl std::error::Error for ExpError {}
+
+impl Exp {
+    /// Construct a new `Exp` with the given rate parameter `lambda`.
+    ///
+    /// # Remarks
+    ///
+    /// For `lambda = 0`, all samples are positive infinity; for
+    /// `lambda = inf`, all samples are zero.
+    #[inline]
+    pub fn new(lambda: f64) -> Result<Exp, ExpError> {
+        if !(lambda >= 0.0) {
+            return Err(ExpError::LambdaTooSmall);
+        }
+        Ok(Exp {
+            lambda_inverse: 1.0 / lambda,
+        })
+    }
+
+    /// Returns the rate parameter `λ` of the distribution.
+    pub fn lambda(&self) -> f64 {
+        1.0 / self.lambda_inverse
+    }
+}
+
+impl Distribution<f64> for Exp {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let x: f64 = rng.sample(Exp1);
+        x * self.lambda_inverse
+    }
+}
+
+#[cfg(feature = "distr_math")]
+/// The [Pareto distribution](https://en.wikipedia.org/wiki/Pareto_distribution) `Pareto(x_m, α)`.
+///
+/// This is a heavy-tailed distribution over `[x_m, ∞)` with scale `x_m` (the
+/// minimum value) and shape `α` (the tail index): `P(X > x) = (x_m / x)^α`.
+/// The mean is infinite for `α <= 1` and the variance is infinite for
+/// `α <= 2`. It models e.g. file sizes or the sizes of requests.
+///
+/// If `X` is [`Exp`]`(α)`, then `x_m · exp(X)` is `Pareto(x_m, α)`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Pareto};
+///
+/// // Response sizes of at least 1 KiB, with the classic "80-20 rule" tail
+/// let size = Pareto::new(1024.0, 1.16).unwrap();
+/// let bytes = size.sample(&mut rand::rng());
+/// assert!(bytes >= 1024.0);
+/// ```
+///
+/// # Implementation
+///
+/// Samples are generated by inversion of the CDF: `x_m · u^(-1/α)`, where
+/// `u` is sampled from [`OpenClosed01`].
+///
+/// Note: this is feature-gated on `distr_math`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Pareto {
+    scale: f64,
+    shape: f64,
+}
+
+#[cfg(feature = "distr_math")]
+/// Error type returned from [`Pareto::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParetoError {
+    /// `scale <= 0` or `scale` is NaN.
+    ScaleTooSmall,
+    /// `scale` is infinite.
+    ScaleInfinite,
+    /// `shape <= 0` or `shape` is NaN.
+    ShapeTooSmall,
+    /// `shape` is infinite.
+    ShapeInfinite,
+}
+
+#[cfg(feature = "distr_math")]
+impl fmt::Display for ParetoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParetoError::ScaleTooSmall => "scale is not positive in Pareto distribution",
+            ParetoError::ScaleInfinite => "scale is infinite in Pareto distribution",
+            ParetoError::ShapeTooSmall => "shape is not positive in Pareto distribution",
+            ParetoError::ShapeInfinite => "shape is infinite in Pareto distribution",
+        })
+    }
+}
+
+#[cfg(feature = "distr_math")]
+impl std::error::Error for ParetoError {}
+
+#[cfg(feature = "distr_math")]
+impl Pareto {
+    /// Construct a new `Pareto` with the given scale parameter `scale` (the
+    /// minimum value) and shape parameter `shape`.
+    ///
+    /// Requires `scale > 0` and `shape > 0`, both finite.
+    #[inline]
+    pub fn new(scale: f64, shape: f64) -> Result<Pareto, ParetoError> {
+        if !(scale > 0.0) {
+            return Err(ParetoError::ScaleTooSmall);
+        }
+        if scale.is_infinite() {
+            return Err(ParetoError::ScaleInfinite);
+        }
+        if !(shape > 0.0) {
+            return Err(ParetoError::ShapeTooSmall);
+        }
+        if shape.is_infinite() {
+            return Err(ParetoError::ShapeInfinite);
+        }
+        Ok(Pareto { scale, shape })
+    }
+
+    /// Returns the scale parameter `x_m` of the distribution.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape parameter `α` of the distribution.
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+}
+
+#[cfg(feature = "distr_math")]
+impl Distribution<f64> for Pareto {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let u: f64 = rng.sample(OpenClosed01);
+        self.scale * u.powf(-1.0 / self.shape)
+    }
+}
+
+#[cfg(feature = "distr_math")]
+/// The [Weibull distribution](https://en.wikipedia.org/wiki/Weibull_distribution) `Weibull(λ, k)`.
+///
+/// This is a distribution over `[0, ∞)` with scale `λ` and shape `k`:
+/// `P(X > x) = exp(-(x / λ)^k)`. It models failure times, where the failure
+/// rate decreases over time for `k < 1`, is constant for `k = 1` (when this is
+/// [`Exp`]`(1 / λ)`) and increases over time for `k > 1`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Weibull};
+///
+/// // Time to failure of a component subject to wear, in hours
+/// let lifetime = Weibull::new(10_000.0, 1.5).unwrap();
+/// let hours = lifetime.sample(&mut rand::rng());
+/// assert!(hours >= 0.0);
+/// ```
+///
+/// # Implementation
+///
+/// Samples are generated by inversion of the CDF: `λ · (-ln u)^(1/k)`, where
+/// `u` is sampled from [`OpenClosed01`].
+///
+/// Note: this is feature-gated on `distr_math`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Weibull {
+    scale: f64,
+    shape: f64,
+}
+
+#[cfg(feature = "distr_math")]
+/// Error type returned from [`Weibull::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeibullError {
+    /// `scale <= 0` or `scale` is NaN.
+    ScaleTooSmall,
+    /// `scale` is infinite.
+    ScaleInfinite,
+    /// `shape <= 0` or `shape` is NaN.
+    ShapeTooSmall,
+    /// `shape` is infinite.
+    ShapeInfinite,
+}
+
+#[cfg(feature = "distr_math")]
+impl fmt::Display for WeibullError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            WeibullError::ScaleTooSmall => "scale is not positive in Weibull distribution",
+            WeibullError::ScaleInfinite => "scale is infinite in Weibull distribution",
+            WeibullError::ShapeTooSmall => "shape is not positive in Weibull distribution",
+            WeibullError::ShapeInfinite => "shape is infinite in Weibull distribution",
+        })
+    }
+}
+
+#[cfg(feature = "distr_math")]
+impl std::error::Error for WeibullError {}
+
+#[cfg(feature = "distr_math")]
+impl Weibull {
+    /// Construct a new `Weibull` with the given scale parameter `scale` and
+    /// shape parameter `shape`.
+    ///
+    /// Requires `scale > 0` and `shape > 0`, both finite.
+    #[inline]
+    pub fn new(scale: f64, shape: f64) -> Result<Weibull, WeibullError> {
+        if !(scale > 0.0) {
+            return Err(WeibullError::ScaleTooSmall);
+        }
+        if scale.is_infinite() {
+            return Err(WeibullError::ScaleInfinite);
+        }
+        if !(shape > 0.0) {
+            return Err(WeibullError::ShapeTooSmall);
+        }
+        if shape.is_infinite() {
+            return Err(WeibullError::ShapeInfinite);
+        }
+        Ok(Weibull { scale, shape })
+    }
+
+    /// Returns the scale parameter `λ` of the distribution.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape parameter `k` of the distribution.
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+}
+
+#[cfg(feature = "distr_math")]
+impl Distribution<f64> for Weibull {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let u: f64 = rng.sample(OpenClosed01);
+        self.scale * (-u.ln()).powf(1.0 / self.shape)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_exp() {
+        let mut rng = crate::test::rng(390);
+        for &lambda in &[0.1, 1.0, 20.0] {
+            let exp = Exp::new(lambda).unwrap();
+            assert_eq!(exp.lambda(), lambda);
+            let n = 10_000;
+            let mut sum = 0.0;
+            let mut sum_sq = 0.0;
+            for _ in 0..n {
+                let x = exp.sample(&mut rng);
+                assert!(x >= 0.0);
+                sum += x;
+                sum_sq += x * x;
+            }
+            let mean = sum / n as f64;
+            let var = sum_sq / n as f64 - mean * mean;
+            let std_err = 1.0 / lambda / (n as f64).sqrt();
+            assert!((mean - 1.0 / lambda).abs() < 5.0 * std_err);
+            assert!((0.9..=1.1).contains(&(var * lambda * lambda)));
+        }
+
+        // P(X > R) = exp(-R) for the tail
+        let n = 1_000_000;
+        let tail = (0..n)
+            .filter(|_| {
+                let x: f64 = rng.sample(Exp1);
+                x > ziggurat_tables::ZIG_EXP_R
+            })
+            .count();
+        let expected = (-ziggurat_tables::ZIG_EXP_R).exp() * n as f64;
+        assert!((tail as f64 - expected).abs() < 5.0 * expected.sqrt());
+
+        let x: f32 = rng.sample(Exp1);
+        assert!(x >= 0.0);
+    }
+
+    #[test]
+    fn test_exp_edge_cases() {
+        let mut rng = crate::test::rng(391);
+        assert_eq!(Exp::new(0.0).unwrap().sample(&mut rng), f64::INFINITY);
+        assert_eq!(Exp::new(f64::INFINITY).unwrap().sample(&mut rng), 0.0);
+        assert_eq!(Exp::new(-1.0), Err(ExpError::LambdaTooSmall));
+        assert_eq!(Exp::new(f64::NAN), Err(ExpError::LambdaTooSmall));
+    }
+
+    #[cfg(feature = "distr_math")]
+    /// Check the fraction of 10000 samples of `distr` above each of
+    /// `thresholds` against the survival function `sf`
+    fn test_tail<D: Distribution<f64>>(distr: D, sf: impl Fn(f64) -> f64, thresholds: &[f64]) {
+        let mut rng = crate::test::rng(392);
+        let n = 10_000;
+        let samples: alloc::vec::Vec<f64> = distr.sample_iter(&mut rng).take(n).collect();
+        for &x in thresholds {
+            let p = sf(x);
+            let count = samples.iter().filter(|&&s| s > x).count();
+            let std_dev = (n as f64 * p * (1.0 - p)).sqrt();
+            assert!((count as f64 - n as f64 * p).abs() <= 5.0 * std_dev + 1.0);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "distr_math")]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_pareto() {
+        for (scale, shape) in [(1.0, 1.0), (1024.0, 1.16), (0.5, 10.0)] {
+            let distr = Pareto::new(scale, shape).unwrap();
+            assert_eq!((distr.scale(), distr.shape()), (scale, shape));
+            let sf = |x: f64| (scale / x).powf(shape);
+            test_tail(distr, sf, &[scale, scale * 1.1, scale * 2.0, scale * 10.0]);
+            assert!(distr.sample(&mut crate::test::rng(393)) >= scale);
+        }
+
+        // The shape round-trips exactly, though its reciprocal is inexact
+        assert_eq!(Pareto::new(1.0, 49.0).unwrap().shape(), 49.0);
+
+        assert_eq!(Pareto::new(0.0, 1.0), Err(ParetoError::ScaleTooSmall));
+        assert_eq!(
+            Pareto::new(f64::INFINITY, 1.0),
+            Err(ParetoError::ScaleInfinite)
+        );
+        assert_eq!(Pareto::new(1.0, f64::NAN), Err(ParetoError::ShapeTooSmall));
+        assert_eq!(
+            Pareto::new(1.0, f64::INFINITY),
+            Err(ParetoError::ShapeInfinite)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "distr_math")]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_weibull() {
+        for (scale, shape) in [(1.0, 1.0), (10_000.0, 1.5), (2.0, 0.5)] {
+            let distr = Weibull::new(scale, shape).unwrap();
+            assert_eq!((distr.scale(), distr.shape()), (scale, shape));
+            let sf = |x: f64| (-(x / scale).powf(shape)).exp();
+            test_tail(distr, sf, &[scale * 0.1, scale * 0.5, scale, scale * 3.0]);
+        }
+
+        assert_eq!(Weibull::new(1.0, 49.0).unwrap().shape(), 49.0);
+
+        assert_eq!(Weibull::new(-1.0, 1.0), Err(WeibullError::ScaleTooSmall));
+        assert_eq!(
+            Weibull::new(f64::INFINITY, 1.0),
+            Err(WeibullError::ScaleInfinite)
+        );
+        assert_eq!(Weibull::new(1.0, 0.0), Err(WeibullError::ShapeTooSmall));
+        assert_eq!(
+            Weibull::new(1.0, f64::INFINITY),
+            Err(WeibullError::ShapeInfinite)
+        );
+    }
+}
@@ -8,10 +8,10 @@
 
 //! Basic floating-point number distributions
 
-use crate::Rng;
 use crate::distr::utils::{FloatAsSIMD, FloatSIMDUtils, IntAsSIMD};
 use crate::distr::{Distribution, StandardUniform};
-use core::mem;
+use crate::{Fill, Rng};
+use core::mem::{self, MaybeUninit};
 #[cfg(feature = "simd_support")]
 use core::simd::prelude::*;
 
@@ -39,7 +39,7 @@ use serde::{Deserialize, Serialize};
 /// println!("f32 from (0, 1): {}", val);
 /// ```
 ///
-/// [`StandardUniform`]: crate::distr::StandardUniform
+/// [`StandardUniform`]: struct@crate::distr::StandardUniform
 /// [`Open01`]: crate::distr::Open01
 /// [`Uniform`]: crate::distr::uniform::Uniform
 #[derive(Clone, Copy, Debug, Default)]
@@ -66,13 +66,95 @@ pub struct OpenClosed01;
 /// println!("f32 from (0, 1): {}", val);
 /// ```
 ///
-/// [`StandardUniform`]: crate::distr::StandardUniform
+/// [`StandardUniform`]: struct@crate::distr::StandardUniform
 /// [`OpenClosed01`]: crate::distr::OpenClosed01
 /// [`Uniform`]: crate::distr::uniform::Uniform
 #[derive(Clone, Copy, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Open01;
 
+/// A distribution to sample floating point numbers uniformly in the half-open
+/// interval `[0, 1)` with full precision.
+///
+/// Unlike [`StandardUniform`], which only generates values of the form
+/// `n * ε/2`, every representable value in `[0, 1)`, including subnormal
+/// numbers, may be generated: the probability of each value `x` is that of a
+/// real number sampled uniformly from `[0, 1)` being rounded down to `x`. This
+/// is useful e.g. to test numerical code near zero, where the values of
+/// [`StandardUniform`] are sparse.
+///
+/// The exponent is sampled geometrically by counting leading zero bits, then
+/// the fraction is sampled uniformly. For `f32` this usually consumes a single
+/// `u32` and for `f64` a single `u64`, rarely (with probability `2^-9` and
+/// `2^-12` respectively) more.
+///
+/// # Example
+/// ```
+/// use rand::Rng;
+/// use rand::distr::StandardUniformFull;
+///
+/// let val: f64 = rand::rng().sample(StandardUniformFull);
+/// assert!((0.0..1.0).contains(&val));
+/// ```
+///
+/// [`StandardUniform`]: struct@crate::distr::StandardUniform
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StandardUniformFull;
+
+/// A choice of unit interval, to sample floating point numbers uniformly
+/// from
+///
+/// Each variant samples exactly as the corresponding distribution:
+/// [`StandardUniform`] for [`ClosedOpen`](Self::ClosedOpen), [`OpenClosed01`]
+/// for [`OpenClosed`](Self::OpenClosed) and [`Open01`] for
+/// [`Open`](Self::Open). This allows selecting the interval by a value, e.g.
+/// via [`Rng::random_float_in`], or at run-time.
+///
+/// # Example
+/// ```
+/// use rand::Rng;
+/// use rand::distr::UnitInterval;
+///
+/// let mut rng = rand::rng();
+/// // Suitable as input to `ln`
+/// let x: f64 = rng.random_float_in(UnitInterval::OpenClosed);
+/// assert!(x > 0.0 && x <= 1.0);
+/// let y: f32 = rng.sample(UnitInterval::Open);
+/// assert!(y > 0.0 && y < 1.0);
+/// ```
+///
+/// [`StandardUniform`]: struct@crate::distr::StandardUniform
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnitInterval {
+    /// The interval `[0, 1)`, as sampled by [`StandardUniform`]
+    ///
+    /// [`StandardUniform`]: struct@crate::distr::StandardUniform
+    #[default]
+    ClosedOpen,
+    /// The interval `(0, 1]`, as sampled by [`OpenClosed01`]
+    OpenClosed,
+    /// The interval `(0, 1)`, as sampled by [`Open01`]
+    Open,
+}
+
+impl<T> Distribution<T> for UnitInterval
+where
+    StandardUniform: Distribution<T>,
+    OpenClosed01: Distribution<T>,
+    Open01: Distribution<T>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        match self {
+            UnitInterval::ClosedOpen => StandardUniform.sample(rng),
+            UnitInterval::OpenClosed => OpenClosed01.sample(rng),
+            UnitInterval::Open => Open01.sample(rng),
+        }
+    }
+}
+
 // This trait is needed by both this lib and rand_distr hence is a hidden export
 #[doc(hidden)]
 pub trait IntoFloat {
@@ -153,9 +235,119 @@ macro_rules! float_impls {
     }
 }
 
+#[cfg(feature = "f16")]
+float_impls! { feature = "f16", f16, u16, f16, u16, 10, 15 }
 float_impls! { , f32, u32, f32, u32, 23, 127 }
 float_impls! { , f64, u64, f64, u64, 52, 1023 }
 
+/// Implement `Fill` for a float type, converting integers generated in bulk
+/// exactly as `StandardUniform` converts a single integer
+macro_rules! impl_fill {
+    ($ty:ident, $uty:ident, $fraction_bits:expr) => {
+        impl Fill for $ty {
+            fn fill_slice<R: Rng + ?Sized>(this: &mut [Self], rng: &mut R) {
+                let precision = $fraction_bits + 1;
+                let scale = 1.0 / (((1 as $uty) << precision) as $ty);
+
+                let mut buf = [0 as $uty; 32];
+                for chunk in this.chunks_mut(buf.len()) {
+                    let buf = &mut buf[..chunk.len()];
+                    rng.fill(buf);
+                    for (x, &v) in chunk.iter_mut().zip(buf.iter()) {
+                        *x = scale * (v >> ($uty::BITS - precision)) as $ty;
+                    }
+                }
+            }
+
+            fn fill_uninit_slice<'a, R: Rng + ?Sized>(
+                this: &'a mut [MaybeUninit<Self>],
+                rng: &mut R,
+            ) -> &'a mut [Self] {
+                let precision = $fraction_bits + 1;
+                let scale = 1.0 / (((1 as $uty) << precision) as $ty);
+
+                let mut buf = [0 as $uty; 32];
+                for chunk in this.chunks_mut(buf.len()) {
+                    let buf = &mut buf[..chunk.len()];
+                    rng.fill(buf);
+                    for (x, &v) in chunk.iter_mut().zip(buf.iter()) {
+                        x.write(scale * (v >> ($uty::BITS - precision)) as $ty);
+                    }
+                }
+                // SAFETY: all elements of `this` are initialized above, and
+                // `MaybeUninit<$ty>` has the same layout as `$ty`.
+                unsafe {
+                    core::slice::from_raw_parts_mut(this.as_mut_ptr() as *mut $ty, this.len())
+                }
+            }
+        }
+    };
+}
+
+impl_fill!(f32, u32, 23);
+impl_fill!(f64, u64, 52);
+
+macro_rules! full_impl {
+    ($ty:ident, $uty:ident, $fraction_bits:expr, $exponent_bias:expr) => {
+        impl Distribution<$ty> for StandardUniformFull {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                // The biased exponent of [0.5, 1), decremented for each
+                // leading zero bit; at or below 0 the value is subnormal
+                let mut exponent: i32 = $exponent_bias - 1;
+                let mut bits: $uty = rng.random();
+                let mut zeros = bits.leading_zeros();
+                while zeros == $uty::BITS {
+                    exponent -= $uty::BITS as i32;
+                    if exponent <= 0 {
+                        break;
+                    }
+                    bits = rng.random();
+                    zeros = bits.leading_zeros();
+                }
+
+                // Bits following the leading one are independent of the
+                // exponent; use these if sufficient
+                let fraction_shift = $uty::BITS - $fraction_bits;
+                let (exponent, fraction) = if zeros == $uty::BITS {
+                    // Without a leading one the value is uniform on
+                    // [0, 2^(exponent - bias + 1)), i.e. a subnormal with a
+                    // fraction shifted right by the zeros past exponent 0
+                    let fraction = rng.random::<$uty>() >> fraction_shift;
+                    (
+                        0,
+                        fraction.checked_shr(exponent.unsigned_abs()).unwrap_or(0),
+                    )
+                } else {
+                    let exponent = exponent - zeros as i32;
+                    let fraction = if zeros < fraction_shift {
+                        (bits << (zeros + 1)) >> fraction_shift
+                    } else {
+                        rng.random::<$uty>() >> fraction_shift
+                    };
+                    if exponent > 0 {
+                        (exponent as $uty, fraction)
+                    } else {
+                        // Subnormal: the leading one becomes part of the
+                        // fraction, shifted right by one plus the zeros past
+                        // exponent 0
+                        let fraction = ((1 as $uty) << $fraction_bits) | fraction;
+                        (
+                            0,
+                            fraction
+                                .checked_shr(1 + exponent.unsigned_abs())
+                                .unwrap_or(0),
+                        )
+                    }
+                };
+                $ty::from_bits((exponent << $fraction_bits) | fraction)
+            }
+        }
+    };
+}
+
+full_impl!(f32, u32, 23, 127);
+full_impl!(f64, u64, 52, 1023);
+
 #[cfg(feature = "simd_support")]
 float_impls! { feature = "simd_support", f32x2, u32x2, f32, u32, 23, 127 }
 #[cfg(feature = "simd_support")]
@@ -274,6 +466,132 @@ mod tests {
     #[cfg(feature = "simd_support")]
     test_f64! { f64x8_edge_cases, f64x8, f64x8::splat(0.0), f64x8::splat(EPSILON64) }
 
+    #[test]
+    #[cfg(feature = "f16")]
+    fn f16_edge_cases() {
+        let eps = f16::EPSILON;
+
+        // StandardUniform
+        assert_eq!(const_rng(0).random::<f16>(), 0.0);
+        assert_eq!(const_rng(!0).random::<f16>(), 1.0 - eps / 2.0);
+
+        // OpenClosed01
+        assert_eq!(const_rng(0).sample::<f16, _>(OpenClosed01), eps / 2.0);
+        assert_eq!(const_rng(!0).sample::<f16, _>(OpenClosed01), 1.0);
+
+        // Open01
+        assert_eq!(const_rng(0).sample::<f16, _>(Open01), eps / 2.0);
+        assert_eq!(const_rng(!0).sample::<f16, _>(Open01), 1.0 - eps / 2.0);
+    }
+
+    #[test]
+    fn test_fill() {
+        // Integers of the same size are converted as by `StandardUniform`
+        let mut rng = crate::test::step_rng(1 << 63, 1 << 11);
+        let mut expected = [0.0f64; 100];
+        for x in &mut expected {
+            *x = rng.random();
+        }
+        let mut rng = crate::test::step_rng(1 << 63, 1 << 11);
+        let mut buf = [0.0f64; 100];
+        rng.fill(&mut buf[..]);
+        assert_eq!(buf, expected);
+
+        // `f32` values are converted from the `u32`s filled in LE order
+        let x = (1 << 63) | (1 << 8);
+        let mut buf = [0.0f32; 2];
+        const_rng(x).fill(&mut buf[..]);
+        assert_eq!(buf, [EPSILON32 / 2.0, 0.5]);
+
+        let mut buf = [-1.0f32; 1000];
+        crate::test::rng(212).fill(&mut buf[..]);
+        assert!(buf.iter().all(|x| (0.0..1.0).contains(x)));
+        assert!(buf.iter().any(|&x| x < 0.01) && buf.iter().any(|&x| x > 0.99));
+    }
+
+    #[test]
+    fn test_unit_interval() {
+        for (interval, zero, max) in [
+            (UnitInterval::ClosedOpen, 0.0, 1.0 - EPSILON64 / 2.0),
+            (UnitInterval::OpenClosed, EPSILON64 / 2.0, 1.0),
+            (UnitInterval::Open, EPSILON64 / 2.0, 1.0 - EPSILON64 / 2.0),
+        ] {
+            assert_eq!(const_rng(0).random_float_in::<f64>(interval), zero);
+            assert_eq!(const_rng(!0).sample::<f64, _>(interval), max);
+        }
+        assert_eq!(UnitInterval::default(), UnitInterval::ClosedOpen);
+        assert_eq!(
+            const_rng(!0).random_float_in::<f32>(UnitInterval::OpenClosed),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_full() {
+        // All-zero words reach the subnormal range, with a zero fraction
+        assert_eq!(const_rng(0).sample::<f64, _>(StandardUniformFull), 0.0);
+        assert_eq!(const_rng(0).sample::<f32, _>(StandardUniformFull), 0.0);
+        assert_eq!(
+            const_rng(!0).sample::<f64, _>(StandardUniformFull),
+            1.0 - EPSILON64 / 2.0
+        );
+        assert_eq!(
+            const_rng(!0).sample::<f32, _>(StandardUniformFull),
+            1.0 - EPSILON32 / 2.0
+        );
+        // The fraction is taken from the same `u64`
+        let x = const_rng(0x0123_4567_89ab_cdef).sample::<f64, _>(StandardUniformFull);
+        assert_eq!(x.to_bits(), (1015 << 52) | 0x2_3456_789a_bcde);
+        // ... or from another `u64`, here the same value, after 19 zeros
+        let x = const_rng(0x0000_1234_5678_9abc).sample::<f64, _>(StandardUniformFull);
+        assert_eq!(x.to_bits(), (1003 << 52) | 0x1_2345_6789);
+        assert!((x * 2.0f64.powi(53)).fract() != 0.0);
+
+        // Subnormal values, only reached with crafted input
+        struct SeqRng<'a>(core::slice::Iter<'a, u64>);
+        impl crate::RngCore for SeqRng<'_> {
+            fn next_u32(&mut self) -> u32 {
+                self.next_u64() as u32
+            }
+            fn next_u64(&mut self) -> u64 {
+                *self.0.next().unwrap()
+            }
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                rand_core::impls::fill_bytes_via_next(self, dst)
+            }
+        }
+        let sample_f32 = |words: &[u64]| {
+            let mut rng = SeqRng(words.iter());
+            let x: f32 = rng.sample(StandardUniformFull);
+            assert!(rng.0.next().is_none());
+            x.to_bits()
+        };
+        // 127 zeros give `2^-128 · 1.11...`, with the leading one
+        assert_eq!(sample_f32(&[0, 0, 0, 1, !0]), 0x3f_ffff);
+        // 128 zeros without a leading one give a value below `2^-128`
+        assert_eq!(sample_f32(&[0, 0, 0, 0, !0]), 0x1f_ffff);
+        // 126 zeros give exactly `2^-127`
+        assert_eq!(sample_f32(&[0, 0, 0, 2, 0]), 0x40_0000);
+        let mut words = [0; 17];
+        words[16] = !0;
+        let x: f64 = SeqRng(words.iter()).sample(StandardUniformFull);
+        assert_eq!(x.to_bits(), 0x3_ffff_ffff_ffff);
+
+        let mut rng = crate::test::rng(991);
+        let mut small = 0;
+        for _ in 0..10_000 {
+            let x: f64 = rng.sample(StandardUniformFull);
+            assert!((0.0..1.0).contains(&x));
+            if x < 1.0 / 16.0 {
+                small += 1;
+                assert!(x > 0.0);
+            }
+            let y: f32 = rng.sample(StandardUniformFull);
+            assert!((0.0..1.0).contains(&y));
+        }
+        assert!((500..=750).contains(&small));
+    }
+
     #[test]
     fn value_stability() {
         fn test_samples<T: Copy + core::fmt::Debug + PartialEq, D: Distribution<T>>(
@@ -0,0 +1,340 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The gamma distribution `Gamma(k, θ)` and beta distribution `Beta(α, β)`.
+
+use crate::Rng;
+use crate::distr::{Distribution, Open01, StandardNormal};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [gamma distribution](https://en.wikipedia.org/wiki/Gamma_distribution) `Gamma(k, θ)`.
+///
+/// The gamma distribution is a continuous probability distribution over
+/// `[0, ∞)` with shape parameter `k` (`shape`) and scale parameter `θ`
+/// (`scale`). For integer `k`, it describes the sum of `k` independent
+/// [`Exp`](crate::distr::Exp) variates of rate `1 / θ`; smaller `k` yields a
+/// more skewed distribution. Its mean is `k θ` and its variance is `k θ²`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Gamma};
+///
+/// // Retry backoff with mean 2 s, less variable than an exponential delay
+/// let gamma = Gamma::new(4.0, 0.5).unwrap();
+/// let v = gamma.sample(&mut rand::rng());
+/// println!("retrying in {} s", v);
+/// ```
+///
+/// # Implementation
+///
+/// This uses the method of G. Marsaglia and W. W. Tsang, "A Simple Method for
+/// Generating Gamma Variables", 2000. For `k < 1`, a `Gamma(k + 1, θ)` variate
+/// is scaled by `U^(1 / k)`, thus for very small `k` most samples underflow to
+/// zero.
+///
+/// Note: this is feature-gated on `distr_math` as it samples [`StandardNormal`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Gamma {
+    shape: f64,
+    scale: f64,
+}
+
+/// Error type returned from [`Gamma::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GammaError {
+    /// `shape <= 0` or `shape` is NaN.
+    ShapeTooSmall,
+    /// `shape` is infinite.
+    ShapeInfinite,
+    /// `scale <= 0` or `scale` is NaN.
+    ScaleTooSmall,
+    /// `scale` is infinite.
+    ScaleInfinite,
+}
+
+impl fmt::Display for GammaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            GammaError::ShapeTooSmall => "shape is not positive in gamma distribution",
+            GammaError::ShapeInfinite => "shape is infinite in gamma distribution",
+            GammaError::ScaleTooSmall => "scale is not positive in gamma distribution",
+            GammaError::ScaleInfinite => "scale is infinite in gamma distribution",
+        })
+    }
+}
+
+impl std::error::Error for GammaError {}
+
+impl Gamma {
+    /// Construct a new `Gamma` with the given shape parameter `shape` and
+    /// scale parameter `scale`.
+    ///
+    /// Requires `shape > 0` and `scale > 0`, both finite.
+    #[inline]
+    pub fn new(shape: f64, scale: f64) -> Result<Gamma, GammaError> {
+        if !(shape > 0.0) {
+            return Err(GammaError::ShapeTooSmall);
+        }
+        if shape.is_infinite() {
+            return Err(GammaError::ShapeInfinite);
+        }
+        if !(scale > 0.0) {
+            return Err(GammaError::ScaleTooSmall);
+        }
+        if scale.is_infinite() {
+            return Err(GammaError::ScaleInfinite);
+        }
+        Ok(Gamma { shape, scale })
+    }
+
+    /// Returns the shape parameter `k` of the distribution.
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns the scale parameter `θ` of the distribution.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl Distribution<f64> for Gamma {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let x = if self.shape < 1.0 {
+            let u: f64 = rng.sample(Open01);
+            sample_gamma_large(rng, self.shape + 1.0) * u.powf(1.0 / self.shape)
+        } else {
+            sample_gamma_large(rng, self.shape)
+        };
+        x * self.scale
+    }
+}
+
+/// The [beta distribution](https://en.wikipedia.org/wiki/Beta_distribution) `Beta(α, β)`.
+///
+/// The beta distribution is a continuous probability distribution over
+/// `[0, 1]` with shape parameters `α` (`alpha`) and `β` (`beta`). It is the
+/// conjugate prior of the [`Bernoulli`](crate::distr::Bernoulli) and
+/// [`Binomial`](crate::distr::Binomial) distributions, and the
+/// two-dimensional case of the [`Dirichlet`](crate::distr::Dirichlet)
+/// distribution. Its mean is `α / (α + β)`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Beta, Distribution};
+///
+/// // Posterior of a success rate after 8 successes and 2 failures
+/// let beta = Beta::new(9.0, 3.0).unwrap();
+/// let v = beta.sample(&mut rand::rng());
+/// println!("{} is from a Beta(9, 3) distribution", v);
+/// ```
+///
+/// # Implementation
+///
+/// Each sample is `X / (X + Y)` for independent variates `X ~ Gamma(α, 1)`
+/// and `Y ~ Gamma(β, 1)`. These are computed in log space, such that small
+/// `α` and `β` (where both variates might underflow to zero) are supported.
+///
+/// Note: this is feature-gated on `distr_math` as it samples [`StandardNormal`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Beta {
+    alpha: f64,
+    beta: f64,
+}
+
+/// Error type returned from [`Beta::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BetaError {
+    /// `alpha <= 0` or `alpha` is NaN.
+    AlphaTooSmall,
+    /// `alpha` is infinite.
+    AlphaInfinite,
+    /// `beta <= 0` or `beta` is NaN.
+    BetaTooSmall,
+    /// `beta` is infinite.
+    BetaInfinite,
+}
+
+impl fmt::Display for BetaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BetaError::AlphaTooSmall => "alpha is not positive in beta distribution",
+            BetaError::AlphaInfinite => "alpha is infinite in beta distribution",
+            BetaError::BetaTooSmall => "beta is not positive in beta distribution",
+            BetaError::BetaInfinite => "beta is infinite in beta distribution",
+        })
+    }
+}
+
+impl std::error::Error for BetaError {}
+
+impl Beta {
+    /// Construct a new `Beta` with the given shape parameters `alpha` and
+    /// `beta`.
+    ///
+    /// Requires `alpha > 0` and `beta > 0`, both finite.
+    #[inline]
+    pub fn new(alpha: f64, beta: f64) -> Result<Beta, BetaError> {
+        if !(alpha > 0.0) {
+            return Err(BetaError::AlphaTooSmall);
+        }
+        if alpha.is_infinite() {
+            return Err(BetaError::AlphaInfinite);
+        }
+        if !(beta > 0.0) {
+            return Err(BetaError::BetaTooSmall);
+        }
+        if beta.is_infinite() {
+            return Err(BetaError::BetaInfinite);
+        }
+        Ok(Beta { alpha, beta })
+    }
+
+    /// Returns the shape parameter `α` of the distribution.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the shape parameter `β` of the distribution.
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+}
+
+impl Distribution<f64> for Beta {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let ln_x = sample_ln_gamma(rng, self.alpha);
+        let ln_y = sample_ln_gamma(rng, self.beta);
+        // X / (X + Y) = 1 / (1 + Y / X)
+        1.0 / (1.0 + (ln_y - ln_x).exp())
+    }
+}
+
+/// Sample a `Gamma(shape, 1)` variate, for finite `shape >= 1`
+fn sample_gamma_large<R: Rng + ?Sized>(rng: &mut R, shape: f64) -> f64 {
+    debug_assert!(shape >= 1.0 && shape.is_finite());
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+    loop {
+        let x: f64 = rng.sample(StandardNormal);
+        let v_cbrt = 1.0 + c * x;
+        if v_cbrt <= 0.0 {
+            continue;
+        }
+        let v = v_cbrt * v_cbrt * v_cbrt;
+        let u: f64 = rng.sample(Open01);
+        let x_sqr = x * x;
+        if u < 1.0 - 0.0331 * x_sqr * x_sqr || u.ln() < 0.5 * x_sqr + d * (1.0 - v + v.ln()) {
+            return d * v;
+        }
+    }
+}
+
+/// Sample the logarithm of a `Gamma(shape, 1)` variate, for finite `shape > 0`
+///
+/// This uses the method of G. Marsaglia and W. W. Tsang, "A Simple Method for
+/// Generating Gamma Variables", 2000. For `shape < 1`, a `Gamma(shape + 1)`
+/// variate is scaled by `U^(1 / shape)`, which is added in log space.
+pub(crate) fn sample_ln_gamma<R: Rng + ?Sized>(rng: &mut R, shape: f64) -> f64 {
+    debug_assert!(shape > 0.0 && shape.is_finite());
+    if shape < 1.0 {
+        let u: f64 = rng.sample(Open01);
+        return sample_ln_gamma(rng, shape + 1.0) + u.ln() / shape;
+    }
+    sample_gamma_large(rng, shape).ln()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_ln_gamma() {
+        let mut rng = crate::test::rng(406);
+        for &shape in &[0.1, 1.0, 2.5, 100.0] {
+            let n = 10_000;
+            let mean = (0..n)
+                .map(|_| sample_ln_gamma(&mut rng, shape).exp())
+                .sum::<f64>()
+                / n as f64;
+            // Gamma(shape, 1) has mean and variance shape
+            assert!((mean - shape).abs() < 5.0 * (shape / n as f64).sqrt());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_gamma() {
+        let mut rng = crate::test::rng(760);
+        for (shape, scale) in [(0.1, 1.0), (1.0, 2.0), (2.5, 0.5), (100.0, 3.0)] {
+            let gamma = Gamma::new(shape, scale).unwrap();
+            assert_eq!((gamma.shape(), gamma.scale()), (shape, scale));
+            let n = 10_000;
+            let mut sum = 0.0;
+            for _ in 0..n {
+                let x = gamma.sample(&mut rng);
+                assert!(x >= 0.0 && x.is_finite());
+                sum += x;
+            }
+            let (mean, var) = (shape * scale, shape * scale * scale);
+            assert!((sum / n as f64 - mean).abs() < 5.0 * (var / n as f64).sqrt());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_beta() {
+        let mut rng = crate::test::rng(761);
+        for (alpha, beta) in [(0.5, 0.5), (1.0, 1.0), (9.0, 3.0), (1e-3, 100.0)] {
+            let distr = Beta::new(alpha, beta).unwrap();
+            assert_eq!((distr.alpha(), distr.beta()), (alpha, beta));
+            let n = 10_000;
+            let mut sum = 0.0;
+            for _ in 0..n {
+                let x = distr.sample(&mut rng);
+                assert!((0.0..=1.0).contains(&x));
+                sum += x;
+            }
+            let mean = alpha / (alpha + beta);
+            let var = mean * (1.0 - mean) / (alpha + beta + 1.0);
+            assert!((sum / n as f64 - mean).abs() < 5.0 * (var / n as f64).sqrt() + 1e-12);
+        }
+
+        // Both Gamma variates would underflow at such small parameters
+        let distr = Beta::new(1e-300, 1e-300).unwrap();
+        assert!((0.0..=1.0).contains(&distr.sample(&mut rng)));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Gamma::new(0.0, 1.0), Err(GammaError::ShapeTooSmall));
+        assert_eq!(Gamma::new(f64::NAN, 1.0), Err(GammaError::ShapeTooSmall));
+        assert_eq!(
+            Gamma::new(f64::INFINITY, 1.0),
+            Err(GammaError::ShapeInfinite)
+        );
+        assert_eq!(Gamma::new(1.0, -1.0), Err(GammaError::ScaleTooSmall));
+        assert_eq!(
+            Gamma::new(1.0, f64::INFINITY),
+            Err(GammaError::ScaleInfinite)
+        );
+
+        assert_eq!(Beta::new(-1.0, 1.0), Err(BetaError::AlphaTooSmall));
+        assert_eq!(Beta::new(f64::INFINITY, 1.0), Err(BetaError::AlphaInfinite));
+        assert_eq!(Beta::new(1.0, f64::NAN), Err(BetaError::BetaTooSmall));
+        assert_eq!(Beta::new(1.0, f64::INFINITY), Err(BetaError::BetaInfinite));
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The geometric distribution `Geometric(p)`.
+
+use crate::Rng;
+use crate::distr::{Bernoulli, Distribution, Open01};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [geometric distribution](https://en.wikipedia.org/wiki/Geometric_distribution) `Geometric(p)`.
+///
+/// This is the distribution of the number of failures before the first
+/// success in a series of independent trials, each of which succeeds with
+/// probability `p`. The density function is `f(k) = (1 - p)^k p` for `k >= 0`.
+///
+/// For `p = 0`, success never occurs and samples are always `u64::MAX`.
+/// Large samples are otherwise also saturated to `u64::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Geometric};
+///
+/// let geo = Geometric::new(0.25).unwrap();
+/// let v = geo.sample(&mut rand::rng());
+/// println!("{} is from a Geometric(0.25) distribution", v);
+/// ```
+///
+/// # Implementation
+///
+/// For `p > 1/3`, trials are sampled directly using [`Bernoulli`], requiring
+/// at most three samples on average. Otherwise, the distribution is sampled
+/// by inversion, as `floor(ln(u) / ln(1 - p))` for `u` uniform on `(0, 1)`;
+/// `ln(1 - p)` is computed without rounding `1 - p`, such that this is
+/// accurate even for very small `p`.
+///
+/// Note: this is feature-gated on `std` due to usage of `f64::ln`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Geometric {
+    p: f64,
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum Method {
+    Constant(u64),
+    Trials(Bernoulli),
+    Inversion {
+        /// `ln(1 - p)`
+        ln_q: f64,
+    },
+}
+
+/// Error type returned from [`Geometric::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeometricError {
+    /// `p < 0`, `p > 1` or `p` is NaN.
+    InvalidProbability,
+}
+
+impl fmt::Display for GeometricError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            GeometricError::InvalidProbability => "p is outside [0, 1] in geometric distribution",
+        })
+    }
+}
+
+impl std::error::Error for GeometricError {}
+
+impl Geometric {
+    /// Construct a new `Geometric` with the given probability of success `p`.
+    pub fn new(p: f64) -> Result<Geometric, GeometricError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(GeometricError::InvalidProbability);
+        }
+        let method = if p == 1.0 {
+            Method::Constant(0)
+        } else if p > 1.0 / 3.0 {
+            Method::Trials(Bernoulli::new(p).unwrap())
+        } else {
+            let ln_q = (-p).ln_1p();
+            if ln_q == 0.0 {
+                // p == 0, or too small for any sample to fit
+                Method::Constant(u64::MAX)
+            } else {
+                Method::Inversion { ln_q }
+            }
+        };
+        Ok(Geometric { p, method })
+    }
+
+    /// Returns the probability of success `p` of the distribution.
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+}
+
+impl Distribution<u64> for Geometric {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        match self.method {
+            Method::Constant(k) => k,
+            Method::Trials(trial) => {
+                let mut failures = 0;
+                while !trial.sample(rng) {
+                    failures += 1;
+                }
+                failures
+            }
+            Method::Inversion { ln_q } => {
+                let u: f64 = rng.sample(Open01);
+                // The cast saturates to u64::MAX for very large values
+                (u.ln() / ln_q) as u64
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Check the sample mean and variance of `Geometric(p)`
+    fn test_moments(p: f64, seed: u64) {
+        let mut rng = crate::test::rng(seed);
+        let geometric = Geometric::new(p).unwrap();
+        let expected_mean = (1.0 - p) / p;
+        let expected_var = expected_mean / p;
+        let n = 10_000;
+        let (mut sum, mut sum_sq) = (0.0, 0.0);
+        for _ in 0..n {
+            let x = geometric.sample(&mut rng) as f64;
+            sum += x;
+            sum_sq += x * x;
+        }
+        let mean = sum / n as f64;
+        let var = sum_sq / n as f64 - mean * mean;
+        // Allow for five standard errors of the mean
+        assert!((mean - expected_mean).abs() <= 5.0 * (expected_var / n as f64).sqrt());
+        if expected_var > 0.0 {
+            assert!((var / expected_var - 1.0).abs() < 0.2);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_geometric() {
+        test_moments(1.0, 360);
+        test_moments(0.9, 361);
+        test_moments(0.5, 362);
+        test_moments(0.334, 363);
+        test_moments(0.333, 364);
+        test_moments(0.1, 365);
+        test_moments(1e-6, 366);
+        test_moments(1e-15, 367);
+    }
+
+    #[test]
+    fn test_geometric_extremes() {
+        let mut rng = crate::test::rng(368);
+        assert_eq!(Geometric::new(0.0).unwrap().sample(&mut rng), u64::MAX);
+        assert_eq!(Geometric::new(1e-300).unwrap().sample(&mut rng), u64::MAX);
+        assert_eq!(Geometric::new(1.0).unwrap().sample(&mut rng), 0);
+        assert_eq!(
+            Geometric::new(-0.1),
+            Err(GeometricError::InvalidProbability)
+        );
+        assert_eq!(
+            Geometric::new(f64::NAN),
+            Err(GeometricError::InvalidProbability)
+        );
+    }
+}
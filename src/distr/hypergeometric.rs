@@ -0,0 +1,433 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The hypergeometric distribution `Hypergeometric(N, K, n)`.
+
+use crate::Rng;
+use crate::distr::utils::log_factorial;
+use crate::distr::{Distribution, Open01, StandardUniform};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [hypergeometric distribution](https://en.wikipedia.org/wiki/Hypergeometric_distribution) `Hypergeometric(N, K, n)`.
+///
+/// This is the distribution of successes in samples of size `n` drawn without
+/// replacement from a population of size `N` containing `K` successes.
+/// For example, the number of aces in a hand of 5 cards drawn from a deck
+/// of 52 cards is distributed as `Hypergeometric(52, 4, 5)`.
+///
+/// The density function is
+/// `f(k) = (K choose k) (N - K choose n - k) / (N choose n)`,
+/// for `max(0, n + K - N) <= k <= min(n, K)`. See also [`Binomial`] for
+/// sampling with replacement.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Hypergeometric};
+///
+/// let aces = Hypergeometric::new(52, 4, 5).unwrap();
+/// let v = aces.sample(&mut rand::rng());
+/// println!("{} aces in a hand of 5 cards", v);
+/// ```
+///
+/// # Implementation
+///
+/// By symmetry, samples are computed for `K <= N / 2` and `n <= N / 2`. Where
+/// the mean is less than 10, the distribution is then sampled by inversion.
+/// Otherwise, the ratio-of-uniforms method of E. Stadlober, "The ratio of
+/// uniforms approach for generating discrete random variates", 1990, is used,
+/// taking `O(1)` expected time for any `N`.
+///
+/// Note: this is feature-gated on `std` due to usage of `f64::ln`.
+///
+/// [`Binomial`]: crate::distr::Binomial
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hypergeometric {
+    total: u64,
+    successes: u64,
+    draws: u64,
+    /// Whether samples are computed for `N - K` successes, then subtracted
+    /// from the number of draws
+    flip_successes: bool,
+    /// Whether samples are computed for `N - n` draws, then subtracted from
+    /// `K`
+    flip_draws: bool,
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum Method {
+    Inversion(Inversion),
+    RatioOfUniforms(RatioOfUniforms),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Inversion {
+    /// The probability of zero successes
+    p0: f64,
+    /// The largest possible sample
+    max: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct RatioOfUniforms {
+    /// The mode
+    m: u64,
+    /// The center of the hat, `mean + 1/2`, relative to the mode
+    a: f64,
+    /// The width of the hat
+    h: f64,
+    /// Samples are rejected from `m + b` upwards
+    b: f64,
+}
+
+/// Error type returned from [`Hypergeometric::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HypergeometricError {
+    /// `successes > total`.
+    TooManySuccesses,
+    /// `draws > total`.
+    TooManyDraws,
+}
+
+impl fmt::Display for HypergeometricError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HypergeometricError::TooManySuccesses => {
+                "number of successes exceeds the population size in hypergeometric distribution"
+            }
+            HypergeometricError::TooManyDraws => {
+                "number of draws exceeds the population size in hypergeometric distribution"
+            }
+        })
+    }
+}
+
+impl std::error::Error for HypergeometricError {}
+
+/// Compute `ln((b + d)!) - ln(b!)`, without cancellation for large `b`
+///
+/// Offsets `d` may be negative, with `b + d >= 0`.
+fn ln_factorial_ratio(b: f64, d: f64) -> f64 {
+    if b.min(b + d) < 10.0 {
+        return log_factorial(b + d) - log_factorial(b);
+    }
+    // Using Stirling's series for ln Γ(x) - ln Γ(y), with
+    // (x - 1/2) ln x - (y - 1/2) ln y = (x - y) ln y + (x - 1/2) ln(x / y)
+    let y = b + 1.0;
+    let x = y + d;
+    let series = |x: f64| {
+        let x2 = x * x;
+        (1.0 / 12.0 - (1.0 / 360.0 - (1.0 / 1260.0 - 1.0 / (1680.0 * x2)) / x2) / x2) / x
+    };
+    d * (y.ln() - 1.0) + (x - 0.5) * (d / y).ln_1p() + (series(x) - series(y))
+}
+
+impl Hypergeometric {
+    /// Construct a new `Hypergeometric` with population size `total`,
+    /// `successes` successes within the population and `draws` draws.
+    pub fn new(
+        total: u64,
+        successes: u64,
+        draws: u64,
+    ) -> Result<Hypergeometric, HypergeometricError> {
+        if successes > total {
+            return Err(HypergeometricError::TooManySuccesses);
+        }
+        if draws > total {
+            return Err(HypergeometricError::TooManyDraws);
+        }
+
+        let flip_successes = successes > total - successes;
+        let flip_draws = draws > total - draws;
+        let k = if flip_successes {
+            total - successes
+        } else {
+            successes
+        };
+        let n = if flip_draws { total - draws } else { draws };
+        // Note that k, n <= total / 2, thus k + n <= total
+        let (small, large) = (k.min(n), k.max(n));
+
+        let mean = n as f64 * k as f64 / total as f64;
+        let method = if !(mean >= 10.0) {
+            // P(X = 0) = (N - n)! (N - K)! / ((N - K - n)! N!), which is
+            // symmetric in K and n; the smaller is used as an offset.
+            let ln_p0 = ln_factorial_ratio((total - large - small) as f64, small as f64)
+                - ln_factorial_ratio((total - small) as f64, small as f64);
+            Method::Inversion(Inversion {
+                p0: ln_p0.exp(),
+                max: small,
+            })
+        } else {
+            const D1: f64 = 1.7155277699214135; // 2 sqrt(2 / e)
+            const D2: f64 = 0.8989161620588988; // 3 - 2 sqrt(3 / e)
+            let (big_n, kf, nf) = (total as f64, k as f64, n as f64);
+            let p = kf / big_n;
+            let var = (big_n - nf) * nf * p * (1.0 - p) / (big_n - 1.0);
+            let c = (var + 0.5).sqrt();
+
+            // The mode, and mean relative to the mode, are computed exactly
+            // since these may exceed the precision of f64.
+            let (k, n, total) = (k as u128, n as u128, total as u128);
+            let m = ((n + 1) * (k + 1) / (total + 2)) as u64;
+            let offset = (n * k) as i128 - (m as u128 * total) as i128;
+            let a = offset as f64 / big_n + 0.5;
+
+            Method::RatioOfUniforms(RatioOfUniforms {
+                m,
+                a,
+                h: D1 * c + D2,
+                b: ((small - m + 1) as f64).min((a + 16.0 * c).floor()),
+            })
+        };
+
+        Ok(Hypergeometric {
+            total,
+            successes,
+            draws,
+            flip_successes,
+            flip_draws,
+            method,
+        })
+    }
+
+    /// Returns the population size `N` of the distribution.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the number of successes `K` in the population.
+    pub fn successes(&self) -> u64 {
+        self.successes
+    }
+
+    /// Returns the number of draws `n` of the distribution.
+    pub fn draws(&self) -> u64 {
+        self.draws
+    }
+
+    /// Returns `(N, K, n)` as used for sampling, with `K, n <= N / 2`
+    fn reduced(&self) -> (u64, u64, u64) {
+        let k = if self.flip_successes {
+            self.total - self.successes
+        } else {
+            self.successes
+        };
+        let n = if self.flip_draws {
+            self.total - self.draws
+        } else {
+            self.draws
+        };
+        (self.total, k, n)
+    }
+
+    fn sample_inversion<R: Rng + ?Sized>(&self, params: &Inversion, rng: &mut R) -> u64 {
+        let (big_n, k, n) = self.reduced();
+        let (k, n, rest) = (k as f64, n as f64, (big_n - k - n) as f64);
+        loop {
+            let mut u: f64 = rng.sample(StandardUniform);
+            let mut p = params.p0;
+            let mut x = 0;
+            loop {
+                if u <= p {
+                    return x;
+                }
+                if x == params.max {
+                    // Rounding has exhausted the support; retry
+                    break;
+                }
+                u -= p;
+                let xf = x as f64;
+                p *= (k - xf) * (n - xf) / ((xf + 1.0) * (rest + xf + 1.0));
+                x += 1;
+            }
+        }
+    }
+
+    fn sample_ratio_of_uniforms<R: Rng + ?Sized>(
+        &self,
+        params: &RatioOfUniforms,
+        rng: &mut R,
+    ) -> u64 {
+        let (big_n, k, n) = self.reduced();
+        let m = params.m;
+        let (m_f, k_m, n_m, rest_m) = (
+            m as f64,
+            (k - m) as f64,
+            (n - m) as f64,
+            (big_n - k - n + m) as f64,
+        );
+        // ln f(m + d) - ln f(m), where f is the density
+        let ln_ratio = |d: f64| {
+            -(ln_factorial_ratio(m_f, d)
+                + ln_factorial_ratio(k_m, -d)
+                + ln_factorial_ratio(n_m, -d)
+                + ln_factorial_ratio(rest_m, d))
+        };
+        loop {
+            let u: f64 = rng.sample(Open01);
+            let v: f64 = rng.sample(StandardUniform);
+            // The sample is x = m + d
+            let d = params.a + params.h * (v - 0.5) / u;
+            if !(d >= -m_f && d < params.b) {
+                continue;
+            }
+            let d = d.floor();
+            let t = ln_ratio(d);
+            // Fast acceptance and rejection using bounds of ln(u²)
+            if u * (4.0 - u) - 3.0 <= t || (u * (u - t) < 1.0 && 2.0 * u.ln() <= t) {
+                return m.checked_add_signed(d as i64).unwrap();
+            }
+        }
+    }
+}
+
+impl Distribution<u64> for Hypergeometric {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let mut x = match self.method {
+            Method::Inversion(ref params) => self.sample_inversion(params, rng),
+            Method::RatioOfUniforms(ref params) => self.sample_ratio_of_uniforms(params, rng),
+        };
+        let n = if self.flip_draws {
+            self.total - self.draws
+        } else {
+            self.draws
+        };
+        if self.flip_successes {
+            x = n - x;
+        }
+        if self.flip_draws {
+            x = self.successes - x;
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_moments(total: u64, successes: u64, draws: u64, seed: u64) {
+        let distr = Hypergeometric::new(total, successes, draws).unwrap();
+        let mut rng = crate::test::rng(seed);
+        let (big_n, k, n) = (total as f64, successes as f64, draws as f64);
+        let lower = draws.saturating_sub(total - successes);
+        let upper = draws.min(successes);
+
+        let expected_mean = if total > 0 { n * k / big_n } else { 0.0 };
+        let count = 10_000;
+        let mut sum = 0.0;
+        let mut sum_sq = 0.0;
+        for _ in 0..count {
+            let x = distr.sample(&mut rng);
+            assert!((lower..=upper).contains(&x));
+            // Subtract the expected mean to reduce rounding error
+            let x = x as f64 - expected_mean;
+            sum += x;
+            sum_sq += x * x;
+        }
+        let mean = sum / count as f64;
+        let var = sum_sq / count as f64 - mean * mean;
+        let expected_var = if total > 1 {
+            n * k / big_n * (big_n - k) / big_n * (big_n - n) / (big_n - 1.0)
+        } else {
+            0.0
+        };
+        let std_err = (expected_var / count as f64).sqrt();
+        assert!(mean.abs() <= 5.0 * std_err + 1e-9 * n);
+        if expected_var > 0.0 {
+            assert!((0.9..=1.1).contains(&(var / expected_var)));
+        } else {
+            assert!(var.abs() <= 1e-9 * n);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_hypergeometric() {
+        test_moments(52, 4, 5, 420);
+        test_moments(100, 60, 80, 421);
+        test_moments(1000, 500, 500, 422);
+        test_moments(5_000, 20, 4_990, 423);
+        test_moments(1_000_000, 1_000, 20_000, 424);
+        test_moments(u64::MAX, u64::MAX / 3, u64::MAX / 7, 425);
+        test_moments(1 << 60, 5, 1 << 59, 426);
+        test_moments(0, 0, 0, 427);
+        test_moments(10, 0, 5, 428);
+        test_moments(10, 10, 5, 429);
+        test_moments(10, 3, 10, 430);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_hypergeometric_pmf() {
+        // Compare frequencies to the density for both methods
+        for &(total, successes, draws) in &[(40u64, 15u64, 25u64), (200, 100, 60)] {
+            let distr = Hypergeometric::new(total, successes, draws).unwrap();
+            let mut rng = crate::test::rng(431);
+            let mut counts = [0u32; 61];
+            let n = 100_000;
+            for _ in 0..n {
+                counts[distr.sample(&mut rng) as usize] += 1;
+            }
+
+            let (big_n, k, d) = (total as f64, successes as f64, draws as f64);
+            let ln_choose =
+                |a: f64, b: f64| log_factorial(a) - log_factorial(b) - log_factorial(a - b);
+            for (x, &count) in counts.iter().enumerate() {
+                let x = x as f64;
+                let p = if x <= k && d - x <= big_n - k && x <= d {
+                    (ln_choose(k, x) + ln_choose(big_n - k, d - x) - ln_choose(big_n, d)).exp()
+                } else {
+                    0.0
+                };
+                let expected = p * n as f64;
+                assert!((count as f64 - expected).abs() <= 5.0 * expected.sqrt() + 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ln_factorial_ratio() {
+        for &(b, d) in &[
+            (3.0f64, 2.0f64),
+            (12.0, 8.0),
+            (5.0, -5.0),
+            (1e6 - 3.0, 3.0),
+            (1e15 + 7.0, -7.0),
+            (4e17, 1000.0),
+        ] {
+            // ln((b + d)! / b!) = ± Σ ln i over i in (min, max]
+            let lo = if d >= 0.0 { b } else { b + d };
+            let sum: f64 = (1..=d.abs() as u64).map(|i| (lo + i as f64).ln()).sum();
+            let expected = if d >= 0.0 { sum } else { -sum };
+            let ratio = ln_factorial_ratio(b, d);
+            assert!((ratio - expected).abs() <= 1e-9 * expected.abs());
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            Hypergeometric::new(10, 11, 5),
+            Err(HypergeometricError::TooManySuccesses)
+        );
+        assert_eq!(
+            Hypergeometric::new(10, 5, 11),
+            Err(HypergeometricError::TooManyDraws)
+        );
+    }
+}
@@ -25,11 +25,20 @@ use core::num::{
 #[cfg(feature = "simd_support")]
 use core::simd::*;
 
+// `sample_fill` is implemented via `Rng::fill`, which uses `fill_bytes` and
+// thus does not discard the high bits of each `u32`, as `sample` does for the
+// smaller types.
+
 impl Distribution<u8> for StandardUniform {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u8 {
         rng.next_u32() as u8
     }
+
+    #[inline]
+    fn sample_fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u8]) {
+        rng.fill(out)
+    }
 }
 
 impl Distribution<u16> for StandardUniform {
@@ -37,6 +46,11 @@ impl Distribution<u16> for StandardUniform {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u16 {
         rng.next_u32() as u16
     }
+
+    #[inline]
+    fn sample_fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u16]) {
+        rng.fill(out)
+    }
 }
 
 impl Distribution<u32> for StandardUniform {
@@ -44,6 +58,11 @@ impl Distribution<u32> for StandardUniform {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u32 {
         rng.next_u32()
     }
+
+    #[inline]
+    fn sample_fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u32]) {
+        rng.fill(out)
+    }
 }
 
 impl Distribution<u64> for StandardUniform {
@@ -51,6 +70,11 @@ impl Distribution<u64> for StandardUniform {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
         rng.next_u64()
     }
+
+    #[inline]
+    fn sample_fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u64]) {
+        rng.fill(out)
+    }
 }
 
 impl Distribution<u128> for StandardUniform {
@@ -61,6 +85,11 @@ impl Distribution<u128> for StandardUniform {
         let y = u128::from(rng.next_u64());
         (y << 64) | x
     }
+
+    #[inline]
+    fn sample_fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u128]) {
+        rng.fill(out)
+    }
 }
 
 macro_rules! impl_int_from_uint {
@@ -70,6 +99,11 @@ macro_rules! impl_int_from_uint {
             fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
                 rng.random::<$uty>() as $ty
             }
+
+            #[inline]
+            fn sample_fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [$ty]) {
+                rng.fill(out)
+            }
         }
     };
 }
@@ -160,10 +194,7 @@ macro_rules! simd_impl {
         ///
         /// [`simd_support`]: https://github.com/rust-random/rand#crate-features
         #[cfg(feature = "simd_support")]
-        impl<const LANES: usize> Distribution<Simd<$ty, LANES>> for StandardUniform
-        where
-            LaneCount<LANES>: SupportedLaneCount,
-        {
+        impl<const LANES: usize> Distribution<Simd<$ty, LANES>> for StandardUniform {
             #[inline]
             fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Simd<$ty, LANES> {
                 let mut vec = Simd::default();
@@ -198,6 +229,26 @@ mod tests {
         rng.sample::<u128, _>(StandardUniform);
     }
 
+    #[test]
+    fn test_sample_fill() {
+        fn check<T: Copy + Default + core::fmt::Debug + PartialEq + crate::Fill>()
+        where
+            StandardUniform: Distribution<T>,
+        {
+            let mut buf = [T::default(); 7];
+            StandardUniform.sample_fill(&mut crate::test::rng(808), &mut buf);
+            let mut expected = [T::default(); 7];
+            crate::test::rng(808).fill(&mut expected[..]);
+            assert_eq!(buf, expected);
+        }
+
+        check::<u8>();
+        check::<i16>();
+        check::<u32>();
+        check::<i64>();
+        check::<u128>();
+    }
+
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     #[test]
     fn x86_integers() {
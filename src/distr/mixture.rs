@@ -0,0 +1,220 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `Mixture` distribution.
+
+use crate::Rng;
+use crate::distr::Distribution;
+use crate::distr::uniform::SampleUniform;
+use crate::distr::weighted::{Error, Weight, WeightedIndex};
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A weighted mixture of component distributions
+///
+/// Sampling a `Mixture` first chooses one of its components with probability
+/// proportional to its weight, as with [`WeightedIndex`], then samples a
+/// value from that component. Each sample thus requires exactly one sample
+/// from a single component.
+///
+/// All components have the same type `D`; to mix distributions of different
+/// types, use e.g. an `enum` or `Box<dyn Distribution<T>>` as `D`.
+///
+/// With the `serde` feature, the components and their weights are serialized.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Mixture, Uniform};
+///
+/// // Bimodal latencies: 90% fast responses, 10% slow ones (in milliseconds)
+/// let latency = Mixture::new([
+///     (Uniform::new(5.0, 15.0).unwrap(), 9.0),
+///     (Uniform::new(80.0, 200.0).unwrap(), 1.0),
+/// ])
+/// .unwrap();
+/// let ms: f64 = latency.sample(&mut rand::rng());
+/// println!("Simulated latency: {ms:.1} ms");
+/// ```
+pub struct Mixture<D, X: SampleUniform + PartialOrd = f64> {
+    components: Vec<D>,
+    index: WeightedIndex<X>,
+}
+
+// Derived impls would lack bounds on `X::Sampler`, required by `WeightedIndex`
+impl<D: fmt::Debug, X: SampleUniform + PartialOrd> fmt::Debug for Mixture<D, X>
+where
+    WeightedIndex<X>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mixture")
+            .field("components", &self.components)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<D: Clone, X: SampleUniform + PartialOrd> Clone for Mixture<D, X>
+where
+    WeightedIndex<X>: Clone,
+{
+    fn clone(&self) -> Self {
+        Mixture {
+            components: self.components.clone(),
+            index: self.index.clone(),
+        }
+    }
+}
+
+impl<D: PartialEq, X: SampleUniform + PartialOrd> PartialEq for Mixture<D, X>
+where
+    WeightedIndex<X>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.components == other.components && self.index == other.index
+    }
+}
+
+impl<D, X: SampleUniform + PartialOrd + Weight> Mixture<D, X> {
+    /// Construct from pairs of a component and its weight
+    ///
+    /// Fails with the same errors as [`WeightedIndex::new`] on the weights.
+    pub fn new<I>(components: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (D, X)>,
+    {
+        let (components, weights): (Vec<D>, Vec<X>) = components.into_iter().unzip();
+        Ok(Mixture {
+            index: WeightedIndex::new(weights)?,
+            components,
+        })
+    }
+}
+
+impl<D, X: SampleUniform + PartialOrd> Mixture<D, X> {
+    /// Returns the components, in order of construction
+    #[inline]
+    pub fn components(&self) -> &[D] {
+        &self.components
+    }
+
+    /// Returns the distribution over indices of [`Self::components`], which
+    /// provides access to the weights
+    #[inline]
+    pub fn weight_index(&self) -> &WeightedIndex<X> {
+        &self.index
+    }
+}
+
+impl<T, D, X> Distribution<T> for Mixture<D, X>
+where
+    D: Distribution<T>,
+    X: SampleUniform + PartialOrd + Weight,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        self.components[self.index.sample(rng)].sample(rng)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<D, X> Serialize for Mixture<D, X>
+where
+    D: Serialize,
+    X: SampleUniform + PartialOrd + Clone + Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let weights: Vec<X> = self.index.weights().collect();
+        let mut state = serializer.serialize_struct("Mixture", 2)?;
+        state.serialize_field("components", &self.components)?;
+        state.serialize_field("weights", &weights)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D, X> Deserialize<'de> for Mixture<D, X>
+where
+    D: Deserialize<'de>,
+    X: SampleUniform + PartialOrd + Weight + Deserialize<'de>,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Mixture")]
+        struct Repr<D, X> {
+            components: Vec<D>,
+            weights: Vec<X>,
+        }
+
+        let repr = Repr::<D, X>::deserialize(deserializer)?;
+        if repr.components.len() != repr.weights.len() {
+            return Err(serde::de::Error::custom(
+                "the numbers of components and weights differ",
+            ));
+        }
+        Mixture::new(repr.components.into_iter().zip(repr.weights))
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distr::Uniform;
+
+    #[test]
+    fn test_mixture() {
+        let mut rng = crate::test::rng(721);
+        let d = Mixture::new([
+            (Uniform::new(0, 10).unwrap(), 3),
+            (Uniform::new(0, 10).unwrap(), 0),
+            (Uniform::new(100, 110).unwrap(), 1),
+        ])
+        .unwrap();
+        assert_eq!(d.components().len(), 3);
+        assert_eq!(d.weight_index().total_weight(), 4);
+
+        let mut low = 0;
+        for _ in 0..1000 {
+            match d.sample(&mut rng) {
+                0..10 => low += 1,
+                x => assert!((100..110).contains(&x)),
+            }
+        }
+        assert!((700..=800).contains(&low));
+
+        // Components of any distribution type producing the same output type
+        let d = Mixture::new([(Uniform::new(0.0, 1.0).unwrap(), 1.0f32)]).unwrap();
+        let x: f64 = d.sample(&mut rng);
+        assert!((0.0..1.0).contains(&x));
+
+        let empty: [(Uniform<u8>, f64); 0] = [];
+        assert_eq!(Mixture::new(empty), Err(Error::InvalidInput));
+        let zero = Mixture::new([(Uniform::new(0.0, 1.0).unwrap(), 0.0)]);
+        assert_eq!(zero, Err(Error::InsufficientNonZero));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialization() {
+        let d = Mixture::new([("a", 1u32), ("b", 2)]).unwrap();
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(json, r#"{"components":["a","b"],"weights":[1,2]}"#);
+        let de: Mixture<&str, u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, d);
+
+        let mismatched = r#"{"components":["a","b"],"weights":[1]}"#;
+        assert!(serde_json::from_str::<Mixture<&str, u32>>(mismatched).is_err());
+        let zero = r#"{"components":["a"],"weights":[0]}"#;
+        assert!(serde_json::from_str::<Mixture<&str, u32>>(zero).is_err());
+    }
+}
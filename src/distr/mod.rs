@@ -49,12 +49,26 @@
 //! There's also an [`Alphabetic`] distribution which acts similarly to [`Alphanumeric`] but
 //! doesn't include digits.
 //!
+//! Similarly, [`Digit`], [`HexDigit`], [`UpperHexDigit`] and
+//! [`AsciiPrintable`] sample from other classes of ASCII characters, as either
+//! `u8` or `char`.
+//!
+//! [`Charset`] samples `char`s uniformly from a custom alphabet, such as
+//! hexadecimal digits or Base58.
+//!
+//! [`UnicodeAssigned`] and [`UnicodePrintable`] sample `char`s from the
+//! assigned and printable subsets of Unicode, respectively; these are useful
+//! to generate test input exercising non-ASCII text.
+//!
 //! For floats (`f32`, `f64`), [`StandardUniform`] samples from `[0, 1)`. Also
 //! provided are [`Open01`] (samples from `(0, 1)`) and [`OpenClosed01`]
 //! (samples from `(0, 1]`). No option is provided to sample from `[0, 1]`; it
 //! is suggested to use one of the above half-open ranges since the failure to
 //! sample a value which would have a low chance of being sampled anyway is
 //! rarely an issue in practice.
+//! [`StandardUniformFull`] samples from `[0, 1)` with full precision, i.e.
+//! all representable values in the interval.
+//! [`UnitInterval`] selects between these intervals by value.
 //!
 //! # Parameterized Uniform distributions
 //!
@@ -71,11 +85,41 @@
 //!
 //! Sampling a simple true/false outcome with a given probability has a name:
 //! the [`Bernoulli`] distribution (this is used by [`Rng::random_bool`]).
+//! [`Optional`] uses such an outcome to sample either `None` or `Some` value
+//! of another distribution. [`Poisson`] samples the number of events
+//! occurring at a constant rate.
 //!
 //! For weighted sampling of discrete values see the [`weighted`] module.
+//! With the `alloc` feature, [`Mixture`] samples from one of several
+//! component distributions, chosen by weight, and [`Empirical`] samples from
+//! the empirical distribution of observed data.
+//!
+//! With the `std` feature, the following distributions are also provided:
 //!
-//! This crate no longer includes other non-uniform distributions; instead
-//! it is recommended that you use either [`rand_distr`] or [`statrs`].
+//! - [`Binomial`] samples the number of successes of independent trials
+//! - [`Exp`] and [`Exp1`] sample the time between events occurring at a
+//!   constant rate
+//! - [`Geometric`] samples the number of failures before the first success
+//! - [`Hypergeometric`] samples the number of successes drawn without
+//!   replacement
+//! - [`Multinomial`] samples the counts of outcomes of independent trials
+//! - [`Triangular`] samples from a three-point estimate
+//! - [`Zipf`] and [`Zeta`] sample ranks following Zipf's law
+//!
+//! With the `distr_math` feature, the following distributions are also
+//! provided:
+//!
+//! - [`Beta`] samples probabilities, e.g. the unknown success rate of trials
+//! - [`Dirichlet`] samples probability vectors
+//! - [`Gamma`] samples waiting times of multiple events, and positive
+//!   quantities of skewed distribution
+//! - [`Normal`], [`StandardNormal`] and [`LogNormal`] sample from the
+//!   (log-)normal or Gaussian distribution
+//! - [`Pareto`] samples heavy-tailed quantities, e.g. file sizes
+//! - [`Weibull`] samples failure times
+//!
+//! For other non-uniform distributions, it is recommended that you use
+//! either [`rand_distr`] or [`statrs`].
 //!
 //!
 //! [probability distribution]: https://en.wikipedia.org/wiki/Probability_distribution
@@ -83,15 +127,47 @@
 //! [`statrs`]: https://crates.io/crates/statrs
 
 //! [`random`]: crate::random
+//! [`StandardUniform`]: struct@StandardUniform
 //! [`rand_distr`]: https://crates.io/crates/rand_distr
 //! [`statrs`]: https://crates.io/crates/statrs
 
 mod bernoulli;
+#[cfg(feature = "std")]
+mod binomial;
+#[cfg(feature = "alloc")]
+mod charset;
+#[cfg(feature = "distr_math")]
+mod dirichlet;
 mod distribution;
+#[cfg(feature = "alloc")]
+mod empirical;
+#[cfg(feature = "std")]
+mod exponential;
 mod float;
+#[cfg(feature = "distr_math")]
+mod gamma;
+#[cfg(feature = "std")]
+mod geometric;
+#[cfg(feature = "std")]
+mod hypergeometric;
 mod integer;
+#[cfg(feature = "alloc")]
+mod mixture;
+#[cfg(feature = "std")]
+mod multinomial;
+#[cfg(feature = "distr_math")]
+mod normal;
+mod optional;
 mod other;
+mod poisson;
+#[cfg(feature = "std")]
+mod triangular;
+mod unicode_tables;
 mod utils;
+#[cfg(feature = "std")]
+mod ziggurat_tables;
+#[cfg(feature = "std")]
+mod zipf;
 
 #[doc(hidden)]
 pub mod hidden_export {
@@ -99,17 +175,53 @@ pub mod hidden_export {
 }
 pub mod slice;
 pub mod uniform;
-#[cfg(feature = "alloc")]
 pub mod weighted;
 
 pub use self::bernoulli::{Bernoulli, BernoulliError};
+#[cfg(feature = "std")]
+pub use self::binomial::{Binomial, BinomialError};
+#[cfg(feature = "alloc")]
+pub use self::charset::{Charset, CharsetError};
+#[cfg(feature = "distr_math")]
+pub use self::dirichlet::{Dirichlet, DirichletError};
 #[cfg(feature = "alloc")]
 pub use self::distribution::SampleString;
-pub use self::distribution::{Distribution, Iter, Map};
-pub use self::float::{Open01, OpenClosed01};
-pub use self::other::{Alphabetic, Alphanumeric};
+pub use self::distribution::{
+    Distribution, Filter, FilterError, Iter, Map, TryDistribution, TryIter,
+};
+#[cfg(feature = "alloc")]
+pub use self::empirical::{Empirical, EmpiricalError};
+#[cfg(feature = "std")]
+pub use self::exponential::{Exp, Exp1, ExpError};
+#[cfg(feature = "distr_math")]
+pub use self::exponential::{Pareto, ParetoError, Weibull, WeibullError};
+pub use self::float::{Open01, OpenClosed01, StandardUniformFull, UnitInterval};
+#[cfg(feature = "distr_math")]
+pub use self::gamma::{Beta, BetaError, Gamma, GammaError};
+#[cfg(feature = "std")]
+pub use self::geometric::{Geometric, GeometricError};
+#[cfg(feature = "std")]
+pub use self::hypergeometric::{Hypergeometric, HypergeometricError};
+#[cfg(feature = "alloc")]
+pub use self::mixture::Mixture;
+#[cfg(feature = "std")]
+pub use self::multinomial::{Multinomial, MultinomialError};
+#[cfg(feature = "distr_math")]
+pub use self::normal::{LogNormal, Normal, NormalError, StandardNormal};
+pub use self::optional::Optional;
+pub use self::other::{
+    Alphabetic, Alphanumeric, AsciiPrintable, Digit, HexDigit, UnicodeAssigned, UnicodePrintable,
+    UpperHexDigit,
+};
+pub use self::poisson::{Poisson, PoissonError};
+#[cfg(feature = "std")]
+pub use self::triangular::{Triangular, TriangularError};
 #[doc(inline)]
 pub use self::uniform::Uniform;
+#[cfg(feature = "std")]
+pub use self::zipf::{Zeta, ZetaError, Zipf, ZipfError};
+#[cfg(feature = "derive")]
+pub use rand_derive::StandardUniform;
 
 #[allow(unused)]
 use crate::Rng;
@@ -138,6 +250,8 @@ use crate::Rng;
 ///   normal integer variants.
 /// * Non-zero integers ([`NonZeroU8`]), which are like their normal integer
 ///   variants but cannot sample zero.
+/// * IP addresses ([`Ipv4Addr`], [`Ipv6Addr`]) are uniformly distributed over
+///   all addresses, including reserved addresses.
 ///
 /// The `StandardUniform` distribution also supports generation of the following
 /// compound types where all component types are supported:
@@ -145,11 +259,11 @@ use crate::Rng;
 /// * Tuples (up to 12 elements): each element is sampled sequentially and
 ///   independently (thus, assuming a well-behaved RNG, there is no correlation
 ///   between elements).
-/// * Arrays `[T; n]` where `T` is supported. Each element is sampled
-///   sequentially and independently. Note that for small `T` this usually
-///   results in the RNG discarding random bits; see also [`Rng::fill`] which
-///   offers a more efficient approach to filling an array of integer types
-///   with random data.
+/// * Arrays `[T; n]` of any length `n` where `T` is supported. Each element
+///   is sampled sequentially and independently. Note that for small `T` this
+///   usually results in the RNG discarding random bits; see also [`Rng::fill`]
+///   and [`Rng::fill_uninit`] which offer a more efficient approach to filling
+///   an array of integer types with random data.
 /// * SIMD types (requires [`simd_support`] feature) like x86's [`__m128i`]
 ///   and `std::simd`'s [`u32x4`], [`f32x4`] and [`mask32x4`] types are
 ///   effectively arrays of integer or floating-point types. Each lane is
@@ -177,6 +291,11 @@ use crate::Rng;
 /// }
 /// ```
 ///
+/// With the `derive` feature, such implementations may instead be derived for
+/// structs and enums via `#[derive(StandardUniform)]`, which samples each
+/// field from `StandardUniform` and chooses enum variants with (optionally
+/// weighted) uniform probability; see the macro for details.
+///
 /// ## Example usage
 /// ```
 /// use rand::prelude::*;
@@ -201,9 +320,12 @@ use crate::Rng;
 /// less precision but may perform faster on some architectures (on modern Intel
 /// CPUs all methods have approximately equal performance).
 ///
+/// [`StandardUniform`]: struct@StandardUniform
 /// [`Uniform`]: uniform::Uniform
 /// [`Wrapping<T>`]: std::num::Wrapping
 /// [`NonZeroU8`]: std::num::NonZeroU8
+/// [`Ipv4Addr`]: core::net::Ipv4Addr
+/// [`Ipv6Addr`]: core::net::Ipv6Addr
 /// [`__m128i`]: https://doc.rust-lang.org/core/arch/x86/struct.__m128i.html
 /// [`u32x4`]: std::simd::u32x4
 /// [`f32x4`]: std::simd::f32x4
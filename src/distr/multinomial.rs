@@ -0,0 +1,291 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multinomial distribution `Multinomial(n, p)`.
+
+use crate::Rng;
+use crate::distr::uniform::SampleUniform;
+use crate::distr::weighted::WeightedIndex;
+use crate::distr::{Binomial, Distribution};
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [multinomial distribution](https://en.wikipedia.org/wiki/Multinomial_distribution) `Multinomial(n, p)`.
+///
+/// This is the distribution of the counts of each of `k` outcomes in `n`
+/// independent trials, where each trial has outcome `i` with probability
+/// `p[i]`. Samples are vectors of `k` counts summing to `n`.
+///
+/// Probabilities are given as non-negative weights, which need not sum to
+/// one. For `k = 2` this is equivalent to the [`Binomial`] distribution.
+///
+/// Sampling counts the outcomes of `n` samples of a [`WeightedIndex`] with the
+/// same weights (see [`Multinomial::from_weighted_index`]), but without
+/// sampling each trial: the cost depends on `k` but not on `n`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Multinomial};
+///
+/// // Roll a die 100 times
+/// let die = Multinomial::new(100, &[1.0; 6]).unwrap();
+/// let counts = die.sample(&mut rand::rng());
+/// assert_eq!(counts.iter().sum::<u64>(), 100);
+/// ```
+///
+/// # Implementation
+///
+/// Counts are sampled successively from the conditional binomial
+/// distributions of each outcome given the counts of preceding outcomes,
+/// requiring `k - 1` [`Binomial`] samples.
+///
+/// Note: this is feature-gated on `std` due to usage of `f64::ln`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Multinomial {
+    n: u64,
+    /// Normalized probabilities
+    probs: Vec<f64>,
+    /// Probability of each outcome given that preceding outcomes did not
+    /// occur
+    conditional: Vec<f64>,
+}
+
+/// Error type returned from [`Multinomial::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MultinomialError {
+    /// The sequence of weights is empty.
+    WeightsEmpty,
+    /// A weight is negative, infinite or NaN.
+    InvalidWeight,
+    /// All weights are zero.
+    WeightsZero,
+}
+
+impl fmt::Display for MultinomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MultinomialError::WeightsEmpty => "no weights given in multinomial distribution",
+            MultinomialError::InvalidWeight => {
+                "a weight is negative or not finite in multinomial distribution"
+            }
+            MultinomialError::WeightsZero => "all weights are zero in multinomial distribution",
+        })
+    }
+}
+
+impl std::error::Error for MultinomialError {}
+
+impl Multinomial {
+    /// Construct a new `Multinomial` with `n` trials and outcomes chosen with
+    /// probability proportional to `weights`.
+    pub fn new(n: u64, weights: &[f64]) -> Result<Multinomial, MultinomialError> {
+        if weights.is_empty() {
+            return Err(MultinomialError::WeightsEmpty);
+        }
+        if !weights.iter().all(|w| *w >= 0.0 && w.is_finite()) {
+            return Err(MultinomialError::InvalidWeight);
+        }
+
+        let mut conditional = Vec::with_capacity(weights.len());
+        // The sum of weights from index i onwards, accumulated from the end
+        let mut tail = 0.0;
+        for &w in weights.iter().rev() {
+            tail += w;
+            // w <= tail even with rounding
+            conditional.push(if tail > 0.0 { w / tail } else { 0.0 });
+        }
+        conditional.reverse();
+
+        if !(tail > 0.0 && tail.is_finite()) {
+            return Err(if tail > 0.0 {
+                MultinomialError::InvalidWeight
+            } else {
+                MultinomialError::WeightsZero
+            });
+        }
+        let probs = weights.iter().map(|w| w / tail).collect();
+
+        Ok(Multinomial {
+            n,
+            probs,
+            conditional,
+        })
+    }
+
+    /// Construct a new `Multinomial` with `n` trials and outcomes chosen with
+    /// the weights of `index`.
+    ///
+    /// Weights of any integer or floating-point type are supported. These are
+    /// converted to `f64`, rounding large integers, thus this fails with
+    /// [`MultinomialError::InvalidWeight`] if their sum overflows `f64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distr::weighted::WeightedIndex;
+    /// use rand::distr::{Distribution, Multinomial};
+    ///
+    /// let index = WeightedIndex::new([2u32, 1, 1]).unwrap();
+    /// // Count the outcomes of a million samples of `index`
+    /// let counts = Multinomial::from_weighted_index(1_000_000, &index)
+    ///     .unwrap()
+    ///     .sample(&mut rand::rng());
+    /// assert_eq!(counts.len(), 3);
+    /// assert_eq!(counts.iter().sum::<u64>(), 1_000_000);
+    /// ```
+    pub fn from_weighted_index<X>(
+        n: u64,
+        index: &WeightedIndex<X>,
+    ) -> Result<Multinomial, MultinomialError>
+    where
+        X: SampleUniform + PartialOrd + Clone + sealed::ToF64,
+    {
+        let weights: Vec<f64> = index.weights().map(|w| w.to_f64()).collect();
+        Multinomial::new(n, &weights)
+    }
+
+    /// Returns the number of trials `n`.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the (normalized) probability of each outcome.
+    pub fn probs(&self) -> &[f64] {
+        &self.probs
+    }
+}
+
+mod sealed {
+    /// Conversion of weights to `f64`, rounding to nearest
+    pub trait ToF64 {
+        fn to_f64(self) -> f64;
+    }
+
+    macro_rules! impl_to_f64 {
+        ($($t:ty),*) => {$(
+            impl ToF64 for $t {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*};
+    }
+    impl_to_f64!(i8, i16, i32, i64, i128, isize);
+    impl_to_f64!(u8, u16, u32, u64, u128, usize);
+    impl_to_f64!(f32, f64);
+}
+
+impl Distribution<Vec<u64>> for Multinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u64> {
+        let mut remaining = self.n;
+        self.conditional
+            .iter()
+            .map(|&p| {
+                if remaining == 0 {
+                    return 0;
+                }
+                // Probabilities are in [0, 1] by construction
+                let k = Binomial::new(remaining, p).unwrap().sample(rng);
+                remaining -= k;
+                k
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_multinomial() {
+        let mut rng = crate::test::rng(410);
+        for (n, weights) in [
+            (100, &[1.0, 2.0, 3.0, 4.0][..]),
+            (1_000_000, &[0.0, 1.0, 0.0, 1e-3]),
+            (u64::MAX, &[1.0; 3]),
+            (5, &[7.0]),
+        ] {
+            let distr = Multinomial::new(n, weights).unwrap();
+            assert_eq!(distr.n(), n);
+            let total: f64 = weights.iter().sum();
+
+            let trials = 2_000;
+            let mut sums = alloc::vec![0.0; weights.len()];
+            for _ in 0..trials {
+                let counts = distr.sample(&mut rng);
+                assert_eq!(counts.len(), weights.len());
+                assert_eq!(counts.iter().sum::<u64>(), n);
+                for ((s, c), w) in sums.iter_mut().zip(counts).zip(weights) {
+                    if *w == 0.0 {
+                        assert_eq!(c, 0);
+                    }
+                    *s += c as f64;
+                }
+            }
+            for (&s, &w) in sums.iter().zip(weights) {
+                let p = w / total;
+                let mean = n as f64 * p;
+                let std_err = (n as f64 * p * (1.0 - p) / trials as f64).sqrt();
+                assert!((s / trials as f64 - mean).abs() <= 5.0 * std_err + mean * 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_weighted_index() {
+        let index = WeightedIndex::new([3u8, 0, 1]).unwrap();
+        let distr = Multinomial::from_weighted_index(1000, &index).unwrap();
+        assert_eq!(distr, Multinomial::new(1000, &[3.0, 0.0, 1.0]).unwrap());
+        assert_eq!(distr.probs(), &[0.75, 0.0, 0.25]);
+
+        let counts = distr.sample(&mut crate::test::rng(411));
+        assert_eq!(counts[1], 0);
+        assert_eq!(counts.iter().sum::<u64>(), 1000);
+
+        let index = WeightedIndex::new([u64::MAX / 2, 0, u64::MAX / 2]).unwrap();
+        let distr = Multinomial::from_weighted_index(1000, &index).unwrap();
+        assert_eq!(distr.probs(), &[0.5, 0.0, 0.5]);
+        let index = WeightedIndex::new([1usize, 3]).unwrap();
+        let distr = Multinomial::from_weighted_index(1000, &index).unwrap();
+        assert_eq!(distr.probs(), &[0.25, 0.75]);
+        let index = WeightedIndex::new([0i64, 1]).unwrap();
+        assert!(Multinomial::from_weighted_index(1000, &index).is_ok());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            Multinomial::new(1, &[]),
+            Err(MultinomialError::WeightsEmpty)
+        );
+        assert_eq!(
+            Multinomial::new(1, &[1.0, -1.0]),
+            Err(MultinomialError::InvalidWeight)
+        );
+        assert_eq!(
+            Multinomial::new(1, &[1.0, f64::NAN]),
+            Err(MultinomialError::InvalidWeight)
+        );
+        assert_eq!(
+            Multinomial::new(1, &[f64::MAX, f64::MAX]),
+            Err(MultinomialError::InvalidWeight)
+        );
+        assert_eq!(
+            Multinomial::new(1, &[0.0, 0.0]),
+            Err(MultinomialError::WeightsZero)
+        );
+    }
+}
@@ -0,0 +1,370 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The normal and derived distributions.
+
+use crate::Rng;
+use crate::distr::utils::ziggurat;
+use crate::distr::ziggurat_tables;
+use crate::distr::{Distribution, Open01};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The standard normal distribution `N(0, 1)`.
+///
+/// This is equivalent to `Normal::new(0.0, 1.0)`, but faster.
+///
+/// See [`Normal`] for the general normal distribution.
+///
+/// # Example
+///
+/// ```
+/// use rand::prelude::*;
+/// use rand::distr::StandardNormal;
+///
+/// let val: f64 = rand::rng().sample(StandardNormal);
+/// println!("{}", val);
+/// ```
+///
+/// # Implementation
+///
+/// This uses the ziggurat method of G. Marsaglia and W. W. Tsang, "The
+/// Ziggurat Method for Generating Random Variables", 2000, with 256 layers.
+/// Samples of type `f32` are converted from `f64`.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StandardNormal;
+
+impl Distribution<f64> for StandardNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        #[inline]
+        fn pdf(x: f64) -> f64 {
+            (-x * x / 2.0).exp()
+        }
+        #[inline]
+        fn zero_case<R: Rng + ?Sized>(rng: &mut R, u: f64) -> f64 {
+            // Sample from the tail beyond R, using the method of Marsaglia
+            // (1964), with rejection from an exponential envelope.
+            let mut x = 1.0f64;
+            let mut y = 0.0f64;
+
+            while -2.0 * y < x * x {
+                let x_: f64 = rng.sample(Open01);
+                let y_: f64 = rng.sample(Open01);
+
+                x = x_.ln() / ziggurat_tables::ZIG_NORM_R;
+                y = y_.ln();
+            }
+
+            if u < 0.0 {
+                x - ziggurat_tables::ZIG_NORM_R
+            } else {
+                ziggurat_tables::ZIG_NORM_R - x
+            }
+        }
+
+        ziggurat(
+            rng,
+            true, // this is symmetric
+            ziggurat_tables::ZIG_NORM_X,
+            ziggurat_tables::ZIG_NORM_F,
+            pdf,
+            zero_case,
+        )
+    }
+}
+
+impl Distribution<f32> for StandardNormal {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f32 {
+        let x: f64 = self.sample(rng);
+        x as f32
+    }
+}
+
+/// The [normal distribution](https://en.wikipedia.org/wiki/Normal_distribution) `N(μ, σ)`.
+///
+/// The normal distribution, also known as the Gaussian distribution, is a
+/// continuous distribution with mean `μ` (`mean`) and standard deviation `σ`
+/// (`std_dev`).
+///
+/// See [`StandardNormal`] for an optimised implementation for `μ = 0` and
+/// `σ = 1`, and [`LogNormal`] for the distribution of `exp(X)`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Normal};
+///
+/// // mean 2, standard deviation 3
+/// let normal = Normal::new(2.0, 3.0).unwrap();
+/// let v = normal.sample(&mut rand::rng());
+/// println!("{} is from a N(2, 9) distribution", v)
+/// ```
+///
+/// Note: this is feature-gated on `distr_math`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+/// Error type returned from [`Normal::new`] and [`LogNormal::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NormalError {
+    /// The mean value is too small (log-normal samples must be positive).
+    MeanTooSmall,
+    /// The standard deviation or other dispersion parameter is not finite
+    /// and non-negative.
+    BadVariance,
+}
+
+impl fmt::Display for NormalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NormalError::MeanTooSmall => "mean <= 0 or is NaN in log-normal distribution",
+            NormalError::BadVariance => {
+                "variation parameter is non-finite in (log)normal distribution"
+            }
+        })
+    }
+}
+
+impl std::error::Error for NormalError {}
+
+impl Normal {
+    /// Construct, from mean and standard deviation
+    ///
+    /// Parameters:
+    ///
+    /// -   mean (`μ`, unrestricted)
+    /// -   standard deviation (`σ`, must be finite and non-negative)
+    #[inline]
+    pub fn new(mean: f64, std_dev: f64) -> Result<Normal, NormalError> {
+        if !(std_dev >= 0.0 && std_dev.is_finite()) {
+            return Err(NormalError::BadVariance);
+        }
+        Ok(Normal { mean, std_dev })
+    }
+
+    /// Sample from a z-score
+    ///
+    /// This may be useful for generating correlated samples `x1` and `x2`
+    /// from two different distributions, as follows.
+    /// ```
+    /// # use rand::prelude::*;
+    /// # use rand::distr::{Normal, StandardNormal};
+    /// let mut rng = rand::rng();
+    /// let z = StandardNormal.sample(&mut rng);
+    /// let x1 = Normal::new(0.0, 1.0).unwrap().from_zscore(z);
+    /// let x2 = Normal::new(2.0, 3.0).unwrap().from_zscore(z);
+    /// ```
+    #[inline]
+    pub fn from_zscore(&self, zscore: f64) -> f64 {
+        self.mean + self.std_dev * zscore
+    }
+
+    /// Returns the mean (`μ`) of the distribution.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the standard deviation (`σ`) of the distribution.
+    pub fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+}
+
+impl Distribution<f64> for Normal {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.from_zscore(rng.sample(StandardNormal))
+    }
+}
+
+/// The [log-normal distribution](https://en.wikipedia.org/wiki/Log-normal_distribution) `ln N(μ, σ)`.
+///
+/// This is the distribution of the random variable `X = exp(Y)` where `Y` is
+/// normally distributed with mean `μ` and standard deviation `σ`, i.e.
+/// `ln X` has the distribution `N(μ, σ)`. Samples are positive.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, LogNormal};
+///
+/// // mean 2, standard deviation 3
+/// let log_normal = LogNormal::new(2.0, 3.0).unwrap();
+/// let v = log_normal.sample(&mut rand::rng());
+/// println!("{} is from an ln N(2, 9) distribution", v)
+/// ```
+///
+/// Note: this is feature-gated on `distr_math`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LogNormal {
+    norm: Normal,
+}
+
+impl LogNormal {
+    /// Construct, from (log-space) mean and standard deviation
+    ///
+    /// Parameters are the "standard" log-space measures (these are the mean
+    /// and standard deviation of the logarithm of samples):
+    ///
+    /// -   `mu` (`μ`, unrestricted) is the mean of the underlying distribution
+    /// -   `sigma` (`σ`, must be finite and non-negative) is the standard
+    ///     deviation of the underlying normal distribution
+    #[inline]
+    pub fn new(mu: f64, sigma: f64) -> Result<LogNormal, NormalError> {
+        let norm = Normal::new(mu, sigma)?;
+        Ok(LogNormal { norm })
+    }
+
+    /// Construct, from (linear-space) mean and coefficient of variation
+    ///
+    /// Parameters are linear-space measures:
+    ///
+    /// -   mean (`μ > 0`) is the (real) mean of the distribution
+    /// -   coefficient of variation (`cv = σ / μ`, requiring `cv ≥ 0`) is a
+    ///     standardized measure of dispersion
+    ///
+    /// As a special exception, `μ = 0, cv = 0` is allowed (samples are `-inf`).
+    #[inline]
+    pub fn from_mean_cv(mean: f64, cv: f64) -> Result<LogNormal, NormalError> {
+        if cv == 0.0 {
+            let mu = mean.ln();
+            let norm = Normal::new(mu, 0.0).unwrap();
+            return Ok(LogNormal { norm });
+        }
+        if !(mean > 0.0) {
+            return Err(NormalError::MeanTooSmall);
+        }
+        if !(cv >= 0.0) {
+            return Err(NormalError::BadVariance);
+        }
+
+        let a = 1.0 + cv * cv;
+        let mu = 0.5 * (mean * mean / a).ln();
+        let sigma = a.ln().sqrt();
+        let norm = Normal::new(mu, sigma)?;
+        Ok(LogNormal { norm })
+    }
+
+    /// Sample from a z-score
+    ///
+    /// This may be useful for generating correlated samples `x1` and `x2`
+    /// from two different distributions, as in [`Normal::from_zscore`].
+    #[inline]
+    pub fn from_zscore(&self, zscore: f64) -> f64 {
+        self.norm.from_zscore(zscore).exp()
+    }
+
+    /// Returns the log-space mean (`μ`) of the distribution.
+    pub fn mu(&self) -> f64 {
+        self.norm.mean
+    }
+
+    /// Returns the log-space standard deviation (`σ`) of the distribution.
+    pub fn sigma(&self) -> f64 {
+        self.norm.std_dev
+    }
+}
+
+impl Distribution<f64> for LogNormal {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.norm.sample(rng).exp()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Check the sample mean and variance of `distr` from 10000 samples
+    fn test_moments<D: Distribution<f64>>(distr: D, mean: f64, var: f64, seed: u64) {
+        let mut rng = crate::test::rng(seed);
+        let n = 10_000;
+        let samples: alloc::vec::Vec<f64> = (0..n).map(|_| distr.sample(&mut rng)).collect();
+        let sample_mean = samples.iter().sum::<f64>() / n as f64;
+        let sample_var = samples
+            .iter()
+            .map(|x| (x - sample_mean).powi(2))
+            .sum::<f64>()
+            / (n - 1) as f64;
+        let std_err = (var / n as f64).sqrt();
+        assert!((sample_mean - mean).abs() < 5.0 * std_err + 1e-12);
+        if var > 0.0 {
+            assert!((0.9..=1.1).contains(&(sample_var / var)));
+        } else {
+            assert_eq!(sample_var, 0.0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_normal() {
+        test_moments(StandardNormal, 0.0, 1.0, 380);
+        test_moments(Normal::new(10.0, 10.0).unwrap(), 10.0, 100.0, 381);
+        test_moments(Normal::new(-1.0, 0.1).unwrap(), -1.0, 0.01, 382);
+        test_moments(Normal::new(3.0, 0.0).unwrap(), 3.0, 0.0, 383);
+
+        // The tail beyond ZIG_NORM_R should be sampled at the right rate
+        let mut rng = crate::test::rng(384);
+        let n = 1_000_000;
+        let tail = (0..n)
+            .filter(|_| {
+                let x: f64 = rng.sample(StandardNormal);
+                x.abs() > ziggurat_tables::ZIG_NORM_R
+            })
+            .count();
+        // P(|X| > 3.6542) ≈ 2.5806e-4
+        let expected = 2.5806e-4 * n as f64;
+        assert!((tail as f64 - expected).abs() < 5.0 * expected.sqrt());
+
+        let x: f32 = rng.sample(StandardNormal);
+        assert!(x.is_finite());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_log_normal() {
+        let (mu, sigma) = (0.5f64, 0.5f64);
+        let mean = (mu + sigma * sigma / 2.0).exp();
+        let var = ((sigma * sigma).exp() - 1.0) * (2.0 * mu + sigma * sigma).exp();
+        test_moments(LogNormal::new(mu, sigma).unwrap(), mean, var, 385);
+
+        let ln = LogNormal::from_mean_cv(3.0, 0.5).unwrap();
+        test_moments(ln, 3.0, (3.0 * 0.5f64).powi(2), 386);
+        let ln = LogNormal::from_mean_cv(3.0, 0.0).unwrap();
+        assert!((ln.sample(&mut crate::test::rng(387)) - 3.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Normal::new(0.0, -1.0), Err(NormalError::BadVariance));
+        assert_eq!(Normal::new(0.0, f64::NAN), Err(NormalError::BadVariance));
+        assert_eq!(
+            Normal::new(0.0, f64::INFINITY),
+            Err(NormalError::BadVariance)
+        );
+        assert_eq!(LogNormal::new(0.0, -1.0), Err(NormalError::BadVariance));
+        assert_eq!(
+            LogNormal::from_mean_cv(-1.0, 1.0),
+            Err(NormalError::MeanTooSmall)
+        );
+        assert_eq!(
+            LogNormal::from_mean_cv(1.0, -1.0),
+            Err(NormalError::BadVariance)
+        );
+    }
+}
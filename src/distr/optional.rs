@@ -0,0 +1,116 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `Optional` distribution.
+
+use crate::Rng;
+use crate::distr::{Bernoulli, BernoulliError, Distribution};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Sample `Some` value of another distribution with probability `p_some`,
+/// otherwise `None`
+///
+/// This distribution is [`Bernoulli`] combined with an `inner` distribution,
+/// which is only sampled in the `Some` case.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Alphanumeric, Distribution, Optional};
+///
+/// // A field which is missing half the time
+/// let d = Optional::new(0.5, Alphanumeric).unwrap();
+/// let v: Option<u8> = d.sample(&mut rand::rng());
+/// println!("{:?} is from an Optional distribution", v);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Optional<D> {
+    some: Bernoulli,
+    inner: D,
+}
+
+impl<D> Optional<D> {
+    /// Construct, sampling `Some` value of `inner` with probability `p_some`
+    ///
+    /// Fails if `p_some < 0` or `p_some > 1`, like [`Bernoulli::new`].
+    #[inline]
+    pub fn new(p_some: f64, inner: D) -> Result<Self, BernoulliError> {
+        Ok(Optional {
+            some: Bernoulli::new(p_some)?,
+            inner,
+        })
+    }
+
+    /// Construct, given the probability of `Some` as a [`Bernoulli`]
+    /// distribution
+    #[inline]
+    pub const fn from_bernoulli(some: Bernoulli, inner: D) -> Self {
+        Optional { some, inner }
+    }
+
+    /// Returns the probability of sampling `Some`
+    #[inline]
+    pub fn p_some(&self) -> f64 {
+        self.some.p()
+    }
+
+    /// Returns a reference to the inner distribution
+    #[inline]
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+
+    /// Returns the inner distribution
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+}
+
+impl<T, D: Distribution<T>> Distribution<Option<T>> for Optional<D> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<T> {
+        if self.some.sample(rng) {
+            Some(self.inner.sample(rng))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distr::Uniform;
+
+    #[test]
+    fn test_optional() {
+        let mut rng = crate::test::rng(180);
+        let d = Optional::new(0.25, Uniform::new(10, 20).unwrap()).unwrap();
+        assert_eq!(d.p_some(), 0.25);
+
+        let mut somes = 0;
+        for _ in 0..1000 {
+            if let Some(x) = d.sample(&mut rng) {
+                assert!((10..20).contains(&x));
+                somes += 1;
+            }
+        }
+        assert!((200..=300).contains(&somes));
+
+        let never = Optional::new(0.0, Uniform::new(10, 20).unwrap()).unwrap();
+        assert_eq!(never.sample(&mut rng), None);
+        let always = Optional::from_bernoulli(Bernoulli::new(1.0).unwrap(), *d.inner());
+        assert!(always.sample(&mut rng).is_some());
+
+        assert!(Optional::new(1.5, d.into_inner()).is_err());
+    }
+}
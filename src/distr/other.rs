@@ -9,20 +9,22 @@
 //! The implementations of the `StandardUniform` distribution for other built-in types.
 
 #[cfg(feature = "alloc")]
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use core::array;
 use core::char;
+use core::net::{Ipv4Addr, Ipv6Addr};
 use core::num::Wrapping;
 
 use crate::Rng;
 #[cfg(feature = "alloc")]
 use crate::distr::SampleString;
+use crate::distr::unicode_tables;
 use crate::distr::{Distribution, StandardUniform, Uniform};
 
 #[cfg(feature = "simd_support")]
-use core::simd::prelude::*;
+use core::simd::MaskElement;
 #[cfg(feature = "simd_support")]
-use core::simd::{LaneCount, MaskElement, SupportedLaneCount};
+use core::simd::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -99,6 +101,125 @@ pub struct Alphanumeric;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Alphabetic;
 
+/// Sample a [`u8`] or [`prim@char`], uniformly distributed over ASCII digits:
+/// 0-9.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use rand::distr::{Digit, SampleString};
+///
+/// let mut rng = rand::rng();
+/// let digit: char = rng.sample(Digit);
+/// assert!(digit.is_ascii_digit());
+///
+/// let pin = Digit.sample_string(&mut rng, 6);
+/// println!("PIN: {}", pin);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Digit;
+
+/// Sample a [`u8`] or [`prim@char`], uniformly distributed over lowercase
+/// hexadecimal digits: 0-9 and a-f.
+///
+/// See [`UpperHexDigit`] for uppercase digits.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{HexDigit, SampleString};
+///
+/// let token = HexDigit.sample_string(&mut rand::rng(), 32);
+/// assert!(token.bytes().all(|b| b.is_ascii_hexdigit() && !b.is_ascii_uppercase()));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HexDigit;
+
+/// Sample a [`u8`] or [`prim@char`], uniformly distributed over uppercase
+/// hexadecimal digits: 0-9 and A-F.
+///
+/// See [`HexDigit`] for lowercase digits.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{SampleString, UpperHexDigit};
+///
+/// let token = UpperHexDigit.sample_string(&mut rand::rng(), 32);
+/// assert!(token.bytes().all(|b| b.is_ascii_hexdigit() && !b.is_ascii_lowercase()));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UpperHexDigit;
+
+/// Sample a [`u8`] or [`prim@char`], uniformly distributed over printable ASCII
+/// characters: the space and the graphic characters `!` to `~`.
+///
+/// These are the 95 characters from `0x20` to `0x7E`. Note that this includes
+/// characters requiring escaping in many contexts, e.g. quotes and
+/// backslashes. See [`UnicodePrintable`] for non-ASCII characters.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{AsciiPrintable, SampleString};
+///
+/// let s = AsciiPrintable.sample_string(&mut rand::rng(), 16);
+/// assert!(s.bytes().all(|b| b == b' ' || b.is_ascii_graphic()));
+/// ```
+///
+/// # Passwords
+///
+/// Refer to [`Alphanumeric#Passwords`].
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AsciiPrintable;
+
+/// Sample a `char`, uniformly distributed over assigned Unicode scalar
+/// values, excluding private-use characters.
+///
+/// In contrast, [`StandardUniform`] samples any valid `char`, most of which
+/// are unassigned. The set of assigned values is that of Unicode 16.0; it
+/// may be updated to newer versions of Unicode, changing sampled values,
+/// only in breaking releases. See also [`UnicodePrintable`].
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{SampleString, UnicodeAssigned};
+///
+/// let string = UnicodeAssigned.sample_string(&mut rand::rng(), 16);
+/// println!("Random string: {:?}", string);
+/// ```
+///
+/// [`StandardUniform`]: struct@crate::distr::StandardUniform
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnicodeAssigned;
+
+/// Sample a `char`, uniformly distributed over printable Unicode scalar
+/// values.
+///
+/// Printable values are all assigned values except for separators other
+/// than the ASCII space (e.g. line separators), controls, format characters
+/// and private-use characters, as of Unicode 16.0 (see [`UnicodeAssigned`]
+/// regarding updates).
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{SampleString, UnicodePrintable};
+///
+/// let string = UnicodePrintable.sample_string(&mut rand::rng(), 16);
+/// assert!(!string.chars().any(char::is_control));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnicodePrintable;
+
 // ----- Implementations of distributions -----
 
 impl Distribution<char> for StandardUniform {
@@ -137,9 +258,6 @@ impl SampleString for StandardUniform {
 impl Distribution<u8> for Alphanumeric {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u8 {
         const RANGE: u32 = 26 + 26 + 10;
-        const GEN_ASCII_STR_CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
-                abcdefghijklmnopqrstuvwxyz\
-                0123456789";
         // We can pick from 62 characters. This is so close to a power of 2, 64,
         // that we can do better than `Uniform`. Use a simple bitshift and
         // rejection sampling. We do not use a bitmask, because for small RNGs
@@ -147,7 +265,7 @@ impl Distribution<u8> for Alphanumeric {
         loop {
             let var = rng.next_u32() >> (32 - 6);
             if var < RANGE {
-                return GEN_ASCII_STR_CHARSET[var as usize];
+                return ALPHANUMERIC_CHARSET[var as usize];
             }
         }
     }
@@ -164,17 +282,105 @@ impl Distribution<u8> for Alphabetic {
     }
 }
 
+const ALPHANUMERIC_CHARSET: &[u8; 62] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
+        abcdefghijklmnopqrstuvwxyz\
+        0123456789";
+#[cfg(feature = "alloc")]
+const ALPHABETIC_CHARSET: &[u8; 52] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
+        abcdefghijklmnopqrstuvwxyz";
+const DIGIT_CHARSET: &[u8; 10] = b"0123456789";
+const HEX_DIGIT_CHARSET: &[u8; 16] = b"0123456789abcdef";
+const UPPER_HEX_DIGIT_CHARSET: &[u8; 16] = b"0123456789ABCDEF";
+const ASCII_PRINTABLE_CHARSET: &[u8; 95] = &{
+    let mut charset = [0; 95];
+    let mut i = 0;
+    while i < charset.len() {
+        charset[i] = b' ' + i as u8;
+        i += 1;
+    }
+    charset
+};
+
+/// The number of bits required to index into `charset`
+const fn charset_bits(charset: &[u8]) -> u32 {
+    usize::BITS - (charset.len() - 1).leading_zeros()
+}
+
+/// Sample a byte uniformly from `charset`
+///
+/// Indices are taken from the most significant bits of a `u32`; values not
+/// indexing into `charset` are rejected.
+#[inline]
+fn sample_from_charset<R: Rng + ?Sized>(rng: &mut R, charset: &[u8]) -> u8 {
+    let bits = charset_bits(charset);
+    loop {
+        let var = (rng.next_u32() >> (32 - bits)) as usize;
+        if let Some(&b) = charset.get(var) {
+            return b;
+        }
+    }
+}
+
+/// Append `len` bytes sampled uniformly from `charset` to `bytes`
+///
+/// Each `u64` from the RNG provides as many indices as fit (e.g. ten 6-bit
+/// indices for up to 64 characters), taken from the most significant bits;
+/// values not indexing into `charset` are rejected.
+#[cfg(feature = "alloc")]
+fn extend_from_charset<R: Rng + ?Sized>(
+    rng: &mut R,
+    bytes: &mut Vec<u8>,
+    len: usize,
+    charset: &[u8],
+) {
+    let n = charset_bits(charset);
+    bytes.reserve(len);
+    let mut remaining = len;
+    while remaining > 0 {
+        let mut bits = rng.next_u64();
+        for _ in 0..64 / n {
+            let var = (bits >> (64 - n)) as usize;
+            bits <<= n;
+            if let Some(&b) = charset.get(var) {
+                bytes.push(b);
+                remaining -= 1;
+                if remaining == 0 {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+impl Alphanumeric {
+    /// Append `len` random alphanumeric bytes to `bytes`
+    ///
+    /// Like [`SampleString::append_string`], this is faster than sampling
+    /// bytes individually, batching output of the RNG.
+    #[cfg(feature = "alloc")]
+    pub fn append_bytes<R: Rng + ?Sized>(&self, rng: &mut R, bytes: &mut Vec<u8>, len: usize) {
+        extend_from_charset(rng, bytes, len, ALPHANUMERIC_CHARSET);
+    }
+}
+
+impl Alphabetic {
+    /// Append `len` random alphabetic bytes to `bytes`
+    ///
+    /// Like [`SampleString::append_string`], this is faster than sampling
+    /// bytes individually, batching output of the RNG.
+    #[cfg(feature = "alloc")]
+    pub fn append_bytes<R: Rng + ?Sized>(&self, rng: &mut R, bytes: &mut Vec<u8>, len: usize) {
+        extend_from_charset(rng, bytes, len, ALPHABETIC_CHARSET);
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl SampleString for Alphanumeric {
     fn append_string<R: Rng + ?Sized>(&self, rng: &mut R, string: &mut String, len: usize) {
         // SAFETY: `self` only samples alphanumeric characters, which are valid UTF-8.
         unsafe {
             let v = string.as_mut_vec();
-            v.extend(
-                self.sample_iter(rng)
-                    .take(len)
-                    .inspect(|b| debug_assert!(b.is_ascii_alphanumeric())),
-            );
+            self.append_bytes(rng, v, len);
         }
     }
 }
@@ -187,9 +393,114 @@ impl SampleString for Alphabetic {
         // See [#1590](https://github.com/rust-random/rand/issues/1590).
         unsafe {
             let v = string.as_mut_vec();
-            v.reserve_exact(len);
-            v.extend(self.sample_iter(rng).take(len));
+            self.append_bytes(rng, v, len);
+        }
+    }
+}
+
+/// Implement distributions over ASCII bytes and `char`s sampled uniformly
+/// from a charset
+macro_rules! impl_ascii_charset {
+    ($ty:ident, $charset:ident, $name:literal) => {
+        impl Distribution<u8> for $ty {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u8 {
+                sample_from_charset(rng, $charset)
+            }
+        }
+
+        impl Distribution<char> for $ty {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+                char::from(sample_from_charset(rng, $charset))
+            }
+        }
+
+        impl $ty {
+            #[doc = concat!("Append `len` random ", $name, " bytes to `bytes`")]
+            ///
+            /// Like [`SampleString::append_string`], this is faster than sampling
+            /// bytes individually, batching output of the RNG.
+            #[cfg(feature = "alloc")]
+            pub fn append_bytes<R: Rng + ?Sized>(
+                &self,
+                rng: &mut R,
+                bytes: &mut Vec<u8>,
+                len: usize,
+            ) {
+                extend_from_charset(rng, bytes, len, $charset);
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl SampleString for $ty {
+            fn append_string<R: Rng + ?Sized>(&self, rng: &mut R, string: &mut String, len: usize) {
+                // SAFETY: the charset only contains ASCII characters, which are valid UTF-8.
+                unsafe {
+                    let v = string.as_mut_vec();
+                    self.append_bytes(rng, v, len);
+                }
+            }
         }
+    };
+}
+
+impl_ascii_charset!(Digit, DIGIT_CHARSET, "digit");
+impl_ascii_charset!(HexDigit, HEX_DIGIT_CHARSET, "lowercase hexadecimal digit");
+impl_ascii_charset!(
+    UpperHexDigit,
+    UPPER_HEX_DIGIT_CHARSET,
+    "uppercase hexadecimal digit"
+);
+impl_ascii_charset!(AsciiPrintable, ASCII_PRINTABLE_CHARSET, "printable ASCII");
+
+/// Get the `k`-th value from a table of ranges of Unicode scalar values
+#[inline]
+fn unicode_table_get(table: &[(u32, u32)], k: u32) -> char {
+    // The first entry has offset 0, thus the partition point is at least 1
+    let i = table.partition_point(|&(_, offset)| offset <= k) - 1;
+    let (start, offset) = table[i];
+    char::from_u32(start + (k - offset)).unwrap()
+}
+
+/// Sample from a table of ranges of Unicode scalar values
+#[inline]
+fn sample_unicode_table<R: Rng + ?Sized>(rng: &mut R, table: &[(u32, u32)], len: u32) -> char {
+    unicode_table_get(table, rng.random_range(0..len))
+}
+
+impl Distribution<char> for UnicodeAssigned {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        sample_unicode_table(rng, unicode_tables::ASSIGNED, unicode_tables::ASSIGNED_LEN)
+    }
+}
+
+impl Distribution<char> for UnicodePrintable {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        sample_unicode_table(
+            rng,
+            unicode_tables::PRINTABLE,
+            unicode_tables::PRINTABLE_LEN,
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SampleString for UnicodeAssigned {
+    fn append_string<R: Rng + ?Sized>(&self, rng: &mut R, s: &mut String, len: usize) {
+        // As for StandardUniform, reserve the maximum length of four bytes
+        s.reserve(4 * len);
+        s.extend(self.sample_iter(rng).take(len));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SampleString for UnicodePrintable {
+    fn append_string<R: Rng + ?Sized>(&self, rng: &mut R, s: &mut String, len: usize) {
+        s.reserve(4 * len);
+        s.extend(self.sample_iter(rng).take(len));
     }
 }
 
@@ -234,7 +545,6 @@ impl Distribution<bool> for StandardUniform {
 impl<T, const LANES: usize> Distribution<Mask<T, LANES>> for StandardUniform
 where
     T: MaskElement + Default,
-    LaneCount<LANES>: SupportedLaneCount,
     StandardUniform: Distribution<Simd<T, LANES>>,
     Simd<T, LANES>: SimdPartialOrd<Mask = Mask<T, LANES>>,
 {
@@ -312,6 +622,20 @@ where
     }
 }
 
+impl Distribution<Ipv4Addr> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ipv4Addr {
+        Ipv4Addr::from_bits(rng.random())
+    }
+}
+
+impl Distribution<Ipv6Addr> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ipv6Addr {
+        Ipv6Addr::from_bits(rng.random())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -354,6 +678,27 @@ mod tests {
         assert!(!incorrect);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_append_bytes() {
+        let mut rng = crate::test::rng(809);
+        let mut bytes = b"id-".to_vec();
+        Alphanumeric.append_bytes(&mut rng, &mut bytes, 1000);
+        assert_eq!(bytes.len(), 1003);
+        assert!(bytes[3..].iter().all(u8::is_ascii_alphanumeric));
+        // All chars occur in a sample of this size
+        assert!(ALPHANUMERIC_CHARSET.iter().all(|c| bytes.contains(c)));
+
+        let mut bytes = Vec::new();
+        Alphabetic.append_bytes(&mut rng, &mut bytes, 1000);
+        assert_eq!(bytes.len(), 1000);
+        assert!(bytes.iter().all(u8::is_ascii_alphabetic));
+        assert!(ALPHABETIC_CHARSET.iter().all(|c| bytes.contains(c)));
+
+        Alphabetic.append_bytes(&mut rng, &mut bytes, 0);
+        assert_eq!(bytes.len(), 1000);
+    }
+
     #[test]
     fn test_alphabetic() {
         let mut rng = crate::test::rng(806);
@@ -368,6 +713,80 @@ mod tests {
         assert!(!incorrect);
     }
 
+    #[test]
+    fn test_ascii_charsets() {
+        macro_rules! check {
+            ($distr:expr, $charset:expr, $f:expr) => {{
+                let f: fn(u8) -> bool = $f;
+                assert!($charset.iter().all(|&b| f(b)));
+                let mut rng = crate::test::rng(810);
+                for _ in 0..100 {
+                    assert!(f(rng.sample::<u8, _>($distr)));
+                    assert!(f(rng.sample::<char, _>($distr) as u8));
+                }
+
+                #[cfg(feature = "alloc")]
+                {
+                    let mut bytes = alloc::vec::Vec::new();
+                    $distr.append_bytes(&mut rng, &mut bytes, 1000);
+                    assert_eq!(bytes.len(), 1000);
+                    assert!(bytes.iter().all(|&b| f(b)));
+                    // All chars occur in a sample of this size
+                    assert!($charset.iter().all(|c| bytes.contains(c)));
+
+                    let s = $distr.sample_string(&mut rng, 100);
+                    assert_eq!(s.len(), 100);
+                    assert!(s.bytes().all(f));
+                }
+            }};
+        }
+
+        check!(Digit, DIGIT_CHARSET, |b| b.is_ascii_digit());
+        check!(HexDigit, HEX_DIGIT_CHARSET, |b| {
+            b.is_ascii_hexdigit() && !b.is_ascii_uppercase()
+        });
+        check!(UpperHexDigit, UPPER_HEX_DIGIT_CHARSET, |b| {
+            b.is_ascii_hexdigit() && !b.is_ascii_lowercase()
+        });
+        check!(AsciiPrintable, ASCII_PRINTABLE_CHARSET, |b| {
+            b == b' ' || b.is_ascii_graphic()
+        });
+        assert_eq!(ASCII_PRINTABLE_CHARSET.last(), Some(&b'~'));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_unicode_tables() {
+        for (table, len) in [
+            (unicode_tables::ASSIGNED, unicode_tables::ASSIGNED_LEN),
+            (unicode_tables::PRINTABLE, unicode_tables::PRINTABLE_LEN),
+        ] {
+            let mut last = None;
+            for k in 0..len {
+                let c = unicode_table_get(table, k);
+                assert!(last < Some(c));
+                assert!(!c.is_control() || table == unicode_tables::ASSIGNED);
+                assert!(!('\u{E000}'..='\u{F8FF}').contains(&c));
+                last = Some(c);
+            }
+        }
+        assert_eq!(unicode_table_get(unicode_tables::PRINTABLE, 0), ' ');
+        assert_eq!(unicode_table_get(unicode_tables::ASSIGNED, 0), '\0');
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut rng = crate::test::rng(808);
+            let s = UnicodePrintable.sample_string(&mut rng, 100);
+            assert_eq!(s.chars().count(), 100);
+            assert!(
+                s.chars()
+                    .all(|c| !c.is_control() && !c.is_whitespace() || c == ' ')
+            );
+            let s = UnicodeAssigned.sample_string(&mut rng, 100);
+            assert_eq!(s.chars().count(), 100);
+        }
+    }
+
     #[test]
     fn value_stability() {
         fn test_samples<T: Copy + core::fmt::Debug + PartialEq, D: Distribution<T>>(
@@ -409,6 +828,29 @@ mod tests {
             ],
         );
 
+        test_samples(
+            &StandardUniform,
+            Ipv4Addr::UNSPECIFIED,
+            &[
+                Ipv4Addr::new(132, 87, 126, 9),
+                Ipv4Addr::new(153, 123, 167, 247),
+                Ipv4Addr::new(120, 73, 149, 111),
+                Ipv4Addr::new(223, 98, 171, 68),
+                Ipv4Addr::new(50, 1, 121, 24),
+            ],
+        );
+        test_samples(
+            &StandardUniform,
+            Ipv6Addr::UNSPECIFIED,
+            &[
+                Ipv6Addr::from_bits(0xdf62_ab44_7849_956f_997b_a7f7_8457_7e09),
+                Ipv6Addr::from_bits(0x6d92_2c13_d538_0cae_14a4_2e0d_3201_7918),
+                Ipv6Addr::from_bits(0x5392_bfd5_4fc6_2a45_085b_68ac_6bc7_bcc2),
+                Ipv6Addr::from_bits(0xc791_5bfc_ed54_1a1d_b97f_e5cf_8395_a995),
+                Ipv6Addr::from_bits(0x2af7_227f_6d67_bb91_7f69_0e49_0868_f304),
+            ],
+        );
+
         // We test only sub-sets of tuple and array impls
         test_samples(&StandardUniform, (), &[(), (), (), (), ()]);
         test_samples(
@@ -0,0 +1,316 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Poisson distribution `Poisson(λ)`.
+
+use crate::Rng;
+use crate::distr::utils::{exp, floor, ln, log_factorial, sqrt};
+use crate::distr::{Distribution, Open01, StandardUniform};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [Poisson distribution](https://en.wikipedia.org/wiki/Poisson_distribution) `Poisson(λ)`.
+///
+/// The Poisson distribution expresses the probability of a given number of
+/// events occurring in a fixed interval, given that these events occur
+/// independently at a constant mean rate `λ`. Both the mean and the variance
+/// of `Poisson(λ)` are `λ`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Poisson};
+///
+/// // Number of arrivals in an interval with an average of 2.5 arrivals
+/// let poi = Poisson::new(2.5).unwrap();
+/// let v: u64 = poi.sample(&mut rand::rng());
+/// println!("{} is from a Poisson(2.5) distribution", v);
+/// ```
+///
+/// # Implementation
+///
+/// For `λ < 12`, this uses Knuth's method of multiplying uniform samples,
+/// requiring `λ + 1` samples on average. Otherwise, the PTRS algorithm
+/// (transformed rejection with squeeze) of W. Hörmann, "The transformed
+/// rejection method for generating Poisson random variables", 1993, is used.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Poisson {
+    lambda: f64,
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum Method {
+    Knuth {
+        exp_lambda: f64,
+    },
+    Rejection {
+        ln_lambda: f64,
+        b: f64,
+        a: f64,
+        ln_inv_alpha: f64,
+        vr: f64,
+    },
+}
+
+/// Error type returned from [`Poisson::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoissonError {
+    /// `λ <= 0` or `λ` is NaN.
+    ShapeTooSmall,
+    /// `λ` is infinite.
+    NonFinite,
+    /// `λ > Poisson::MAX_LAMBDA`.
+    ShapeTooLarge,
+}
+
+impl fmt::Display for PoissonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PoissonError::ShapeTooSmall => "lambda is not positive in Poisson distribution",
+            PoissonError::NonFinite => "lambda is infinite in Poisson distribution",
+            PoissonError::ShapeTooLarge => "lambda is too large in Poisson distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PoissonError {}
+
+impl Poisson {
+    /// The largest supported value of `λ`
+    ///
+    /// Larger values could result in samples exceeding `u64::MAX`.
+    pub const MAX_LAMBDA: f64 = 1.844e19;
+
+    /// Construct a new `Poisson` with the given mean `λ`.
+    pub fn new(lambda: f64) -> Result<Poisson, PoissonError> {
+        if !(lambda > 0.0) {
+            return Err(PoissonError::ShapeTooSmall);
+        }
+        if !lambda.is_finite() {
+            return Err(PoissonError::NonFinite);
+        }
+        if lambda > Self::MAX_LAMBDA {
+            return Err(PoissonError::ShapeTooLarge);
+        }
+
+        let method = if lambda < 12.0 {
+            Method::Knuth {
+                exp_lambda: exp(-lambda),
+            }
+        } else {
+            let b = 0.931 + 2.53 * sqrt(lambda);
+            Method::Rejection {
+                ln_lambda: ln(lambda),
+                b,
+                a: -0.059 + 0.02483 * b,
+                ln_inv_alpha: ln(1.1239 + 1.1328 / (b - 3.4)),
+                vr: 0.9277 - 3.6224 / (b - 2.0),
+            }
+        };
+        Ok(Poisson { lambda, method })
+    }
+
+    /// Returns the mean `λ` of the distribution.
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl Distribution<u64> for Poisson {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        match self.method {
+            Method::Knuth { exp_lambda } => {
+                let mut k = 0;
+                let mut p: f64 = rng.random();
+                while p > exp_lambda {
+                    k += 1;
+                    p *= rng.random::<f64>();
+                }
+                k
+            }
+            Method::Rejection {
+                ln_lambda,
+                b,
+                a,
+                ln_inv_alpha,
+                vr,
+            } => loop {
+                let u = rng.sample::<f64, _>(Open01) - 0.5;
+                let v: f64 = rng.sample(StandardUniform);
+                let us = 0.5 - u.abs();
+                let k = floor((2.0 * a / us + b) * u + self.lambda + 0.43);
+
+                // Squeeze acceptance
+                if us >= 0.07 && v <= vr {
+                    return k as u64;
+                }
+                if k < 0.0 || (us < 0.013 && v > us) {
+                    continue;
+                }
+
+                let lhs = ln(v) + ln_inv_alpha - ln(a / (us * us) + b);
+                let rhs = -self.lambda + k * ln_lambda - log_factorial(k);
+                if lhs <= rhs {
+                    return k as u64;
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Check the sample mean and variance of `Poisson(lambda)`
+    fn test_moments(lambda: f64, seed: u64) {
+        let mut rng = crate::test::rng(seed);
+        let poisson = Poisson::new(lambda).unwrap();
+        let n = 10_000;
+        let (mut sum, mut sum_sq) = (0.0, 0.0);
+        for _ in 0..n {
+            let x = poisson.sample(&mut rng) as f64;
+            sum += x;
+            sum_sq += x * x;
+        }
+        let mean = sum / n as f64;
+        let var = sum_sq / n as f64 - mean * mean;
+        // Allow for five standard errors of the mean
+        assert!((mean - lambda).abs() < 5.0 * sqrt(lambda / n as f64));
+        assert!((var / lambda - 1.0).abs() < 0.1);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_poisson() {
+        test_moments(0.1, 150);
+        test_moments(1.0, 151);
+        test_moments(11.9, 152);
+        test_moments(12.0, 153);
+        test_moments(100.0, 154);
+        test_moments(1e9, 155);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_poisson_probabilities() {
+        // Compare frequencies to the probability mass function
+        let mut rng = crate::test::rng(156);
+        let lambda = 20.0;
+        let poisson = Poisson::new(lambda).unwrap();
+        let mut counts = [0u32; 40];
+        let n = 100_000;
+        for _ in 0..n {
+            let k = poisson.sample(&mut rng) as usize;
+            counts[k.min(39)] += 1;
+        }
+        for (k, &count) in counts.iter().enumerate().take(39) {
+            let k = k as f64;
+            let p = exp(-lambda + k * ln(lambda) - log_factorial(k));
+            let expected = p * n as f64;
+            assert!((count as f64 - expected).abs() < 5.0 * sqrt(expected) + 1.0);
+        }
+    }
+
+    #[test]
+    fn test_poisson_invalid() {
+        assert_eq!(Poisson::new(0.0), Err(PoissonError::ShapeTooSmall));
+        assert_eq!(Poisson::new(-1.0), Err(PoissonError::ShapeTooSmall));
+        assert_eq!(Poisson::new(f64::NAN), Err(PoissonError::ShapeTooSmall));
+        assert_eq!(Poisson::new(f64::INFINITY), Err(PoissonError::NonFinite));
+        assert_eq!(Poisson::new(1e20), Err(PoissonError::ShapeTooLarge));
+        assert!(Poisson::new(Poisson::MAX_LAMBDA).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_log_factorial() {
+        let mut expected = 0.0;
+        for k in 1..30 {
+            expected += f64::ln(k as f64);
+            let actual = log_factorial(k as f64);
+            assert!((actual - expected).abs() <= 1e-13 * expected);
+        }
+    }
+
+    /// The distance in ulps between finite `x` and `y` of equal sign
+    #[cfg(feature = "std")]
+    fn ulps(x: f64, y: f64) -> u64 {
+        x.to_bits().abs_diff(y.to_bits())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_math_fns() {
+        let mut rng = crate::test::rng(157);
+        for _ in 0..10_000 {
+            // Log-uniform over most of the finite range, including subnormals
+            let e: f64 = crate::Rng::random_range(&mut rng, -1070.0..1020.0);
+            let x = 2f64.powf(e) * crate::Rng::random_range(&mut rng, 1.0..2.0);
+            assert!(ulps(sqrt(x), x.sqrt()) <= 1, "sqrt({x})");
+            assert!(ulps(ln(x), x.ln()) <= 2, "ln({x})");
+            assert_eq!(floor(x), x.floor());
+            assert_eq!(floor(-x), (-x).floor());
+
+            let y = e / 1.5;
+            assert!(ulps(exp(y), y.exp()) <= 2, "exp({y})");
+        }
+
+        for x in [
+            0.0,
+            0.5,
+            1.0,
+            1.5,
+            -0.5,
+            -1.0,
+            -1.5,
+            4503599627370495.5,
+            -1e300,
+        ] {
+            assert_eq!(floor(x), x.floor());
+        }
+        assert!(floor(f64::NAN).is_nan());
+        assert_eq!(floor(f64::NEG_INFINITY), f64::NEG_INFINITY);
+
+        assert_eq!(sqrt(0.0), 0.0);
+        assert_eq!(sqrt(f64::INFINITY), f64::INFINITY);
+        assert!(sqrt(-1.0).is_nan() && sqrt(f64::NAN).is_nan());
+
+        assert_eq!(ln(1.0), 0.0);
+        assert_eq!(ln(0.0), f64::NEG_INFINITY);
+        assert_eq!(ln(f64::INFINITY), f64::INFINITY);
+        assert!(ln(-1.0).is_nan() && ln(f64::NAN).is_nan());
+        for x in [
+            1.0 - 1e-12,
+            1.0 + 1e-12,
+            0.7,
+            0.999,
+            1.001,
+            core::f64::consts::SQRT_2,
+            1.4143,
+            2.0,
+        ] {
+            assert!(ulps(ln(x), x.ln()) <= 2, "ln({x})");
+        }
+
+        assert_eq!(exp(0.0), 1.0);
+        assert_eq!(exp(-750.0), 0.0);
+        assert_eq!(exp(710.0), f64::INFINITY);
+        assert!(exp(f64::NAN).is_nan());
+        for y in [-745.0, -709.0, -12.0, -1e-10, 1e-10, 5.5, 709.7] {
+            assert!(ulps(exp(y), y.exp()) <= 2, "exp({y})");
+        }
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The triangular distribution `Triangular(min, mode, max)`.
+
+use crate::Rng;
+use crate::distr::{Distribution, StandardUniform};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [triangular distribution](https://en.wikipedia.org/wiki/Triangular_distribution) `Triangular(min, mode, max)`.
+///
+/// A continuous distribution over `[min, max]` whose density increases
+/// linearly from `min` to a peak at the `mode`, then decreases linearly to
+/// `max`. It is commonly used where only a three-point estimate (minimum,
+/// most likely and maximum values) is known.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Triangular};
+///
+/// // Task duration: at least 2 days, most likely 3, at most 7
+/// let duration = Triangular::new(2.0, 3.0, 7.0).unwrap();
+/// let v = duration.sample(&mut rand::rng());
+/// assert!((2.0..=7.0).contains(&v));
+/// ```
+///
+/// # Implementation
+///
+/// Samples are generated by inversion of the CDF, which is continuous at the
+/// mode.
+///
+/// Note: this is feature-gated on `std` due to usage of `f64::sqrt`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Triangular {
+    min: f64,
+    mode: f64,
+    max: f64,
+}
+
+/// Error type returned from [`Triangular::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriangularError {
+    /// `max < min` or `min` or `max` is NaN.
+    RangeTooSmall,
+    /// `mode < min` or `mode > max` or `mode` is NaN.
+    ModeRange,
+    /// `min` or `max` is infinite.
+    NonFinite,
+}
+
+impl fmt::Display for TriangularError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TriangularError::RangeTooSmall => {
+                "requirement min <= max is not met in triangular distribution"
+            }
+            TriangularError::ModeRange => "mode is outside [min, max] in triangular distribution",
+            TriangularError::NonFinite => "min or max is infinite in triangular distribution",
+        })
+    }
+}
+
+impl std::error::Error for TriangularError {}
+
+impl Triangular {
+    /// Construct a new `Triangular` with minimum `min`, mode `mode` and
+    /// maximum `max`.
+    ///
+    /// Requires `min <= mode <= max` with `min` and `max` finite.
+    #[inline]
+    pub fn new(min: f64, mode: f64, max: f64) -> Result<Triangular, TriangularError> {
+        if !(max >= min) {
+            return Err(TriangularError::RangeTooSmall);
+        }
+        if !(min.is_finite() && max.is_finite()) {
+            return Err(TriangularError::NonFinite);
+        }
+        if !(mode >= min && max >= mode) {
+            return Err(TriangularError::ModeRange);
+        }
+        Ok(Triangular { min, mode, max })
+    }
+
+    /// Returns the minimum of the distribution.
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the mode of the distribution.
+    pub fn mode(&self) -> f64 {
+        self.mode
+    }
+
+    /// Returns the maximum of the distribution.
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+}
+
+impl Distribution<f64> for Triangular {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let f: f64 = rng.sample(StandardUniform);
+        let diff_mode = self.mode - self.min;
+        let range = self.max - self.min;
+        let f_range = f * range;
+        // At f_range == diff_mode both branches yield the mode
+        let x = if f_range < diff_mode {
+            self.min + (f_range * diff_mode).sqrt()
+        } else {
+            self.max - ((range - f_range) * (self.max - self.mode)).sqrt()
+        };
+        // Guard against rounding outside the support
+        x.clamp(self.min, self.max)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_triangular() {
+        let mut rng = crate::test::rng(395);
+        for &(min, mode, max) in &[
+            (0.0, 0.5, 1.0),
+            (-1.0, -1.0, 2.0),
+            (2.0, 7.0, 7.0),
+            (2.0, 3.0, 7.0),
+            (1e10, 1e10 + 1.0, 1e10 + 10.0),
+        ] {
+            let distr = Triangular::new(min, mode, max).unwrap();
+            let n = 10_000;
+            let mut sum = 0.0;
+            let mut below_mode = 0;
+            for _ in 0..n {
+                let x = distr.sample(&mut rng);
+                assert!((min..=max).contains(&x));
+                sum += x - min;
+                if x < mode {
+                    below_mode += 1;
+                }
+            }
+            let (a, b, c) = (0.0, max - min, mode - min);
+            let mean = (a + b + c) / 3.0;
+            let var = (a * a + b * b + c * c - a * b - a * c - b * c) / 18.0;
+            let std_err = (var / n as f64).sqrt();
+            assert!((sum / n as f64 - mean).abs() < 5.0 * std_err);
+
+            // P(X < mode) = (mode - min) / (max - min)
+            let p = c / b;
+            let expected = p * n as f64;
+            let tol = 5.0 * (p * (1.0 - p) * n as f64).sqrt();
+            assert!((below_mode as f64 - expected).abs() <= tol);
+        }
+
+        let distr = Triangular::new(3.0, 3.0, 3.0).unwrap();
+        assert_eq!(distr.sample(&mut rng), 3.0);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            Triangular::new(1.0, 1.0, 0.0),
+            Err(TriangularError::RangeTooSmall)
+        );
+        assert_eq!(
+            Triangular::new(f64::NAN, 0.0, 1.0),
+            Err(TriangularError::RangeTooSmall)
+        );
+        assert_eq!(
+            Triangular::new(0.0, 0.0, f64::INFINITY),
+            Err(TriangularError::NonFinite)
+        );
+        assert_eq!(
+            Triangular::new(0.0, 2.0, 1.0),
+            Err(TriangularError::ModeRange)
+        );
+        assert_eq!(
+            Triangular::new(0.0, f64::NAN, 1.0),
+            Err(TriangularError::ModeRange)
+        );
+    }
+}
@@ -0,0 +1,1498 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tables of Unicode scalar values, as of Unicode 16.0.0
+//!
+//! Generated by `utils/unicode_tables.py` from the Unicode Character
+//! Database file `DerivedGeneralCategory-16.0.0.txt`; do not edit.
+
+/// Assigned scalar values, excluding private-use characters
+///
+/// Each entry is the start of a range and the number of values in
+/// preceding ranges.
+pub(crate) static ASSIGNED: &[(u32, u32)] = &[
+    (0x0000, 0),
+    (0x037A, 888),
+    (0x0384, 894),
+    (0x038C, 901),
+    (0x038E, 902),
+    (0x03A3, 922),
+    (0x0531, 1319),
+    (0x0559, 1357),
+    (0x058D, 1407),
+    (0x0591, 1410),
+    (0x05D0, 1465),
+    (0x05EF, 1492),
+    (0x0600, 1498),
+    (0x070F, 1768),
+    (0x074D, 1828),
+    (0x07C0, 1929),
+    (0x07FD, 1988),
+    (0x0830, 2037),
+    (0x0840, 2052),
+    (0x085E, 2080),
+    (0x0860, 2081),
+    (0x0870, 2092),
+    (0x0890, 2123),
+    (0x0897, 2125),
+    (0x0985, 2362),
+    (0x098F, 2370),
+    (0x0993, 2372),
+    (0x09AA, 2394),
+    (0x09B2, 2401),
+    (0x09B6, 2402),
+    (0x09BC, 2406),
+    (0x09C7, 2415),
+    (0x09CB, 2417),
+    (0x09D7, 2421),
+    (0x09DC, 2422),
+    (0x09DF, 2424),
+    (0x09E6, 2429),
+    (0x0A01, 2454),
+    (0x0A05, 2457),
+    (0x0A0F, 2463),
+    (0x0A13, 2465),
+    (0x0A2A, 2487),
+    (0x0A32, 2494),
+    (0x0A35, 2496),
+    (0x0A38, 2498),
+    (0x0A3C, 2500),
+    (0x0A3E, 2501),
+    (0x0A47, 2506),
+    (0x0A4B, 2508),
+    (0x0A51, 2511),
+    (0x0A59, 2512),
+    (0x0A5E, 2516),
+    (0x0A66, 2517),
+    (0x0A81, 2534),
+    (0x0A85, 2537),
+    (0x0A8F, 2546),
+    (0x0A93, 2549),
+    (0x0AAA, 2571),
+    (0x0AB2, 2578),
+    (0x0AB5, 2580),
+    (0x0ABC, 2585),
+    (0x0AC7, 2595),
+    (0x0ACB, 2598),
+    (0x0AD0, 2601),
+    (0x0AE0, 2602),
+    (0x0AE6, 2606),
+    (0x0AF9, 2618),
+    (0x0B01, 2625),
+    (0x0B05, 2628),
+    (0x0B0F, 2636),
+    (0x0B13, 2638),
+    (0x0B2A, 2660),
+    (0x0B32, 2667),
+    (0x0B35, 2669),
+    (0x0B3C, 2674),
+    (0x0B47, 2683),
+    (0x0B4B, 2685),
+    (0x0B55, 2688),
+    (0x0B5C, 2691),
+    (0x0B5F, 2693),
+    (0x0B66, 2698),
+    (0x0B82, 2716),
+    (0x0B85, 2718),
+    (0x0B8E, 2724),
+    (0x0B92, 2727),
+    (0x0B99, 2731),
+    (0x0B9C, 2733),
+    (0x0B9E, 2734),
+    (0x0BA3, 2736),
+    (0x0BA8, 2738),
+    (0x0BAE, 2741),
+    (0x0BBE, 2753),
+    (0x0BC6, 2758),
+    (0x0BCA, 2761),
+    (0x0BD0, 2765),
+    (0x0BD7, 2766),
+    (0x0BE6, 2767),
+    (0x0C00, 2788),
+    (0x0C0E, 2801),
+    (0x0C12, 2804),
+    (0x0C2A, 2827),
+    (0x0C3C, 2843),
+    (0x0C46, 2852),
+    (0x0C4A, 2855),
+    (0x0C55, 2859),
+    (0x0C58, 2861),
+    (0x0C5D, 2864),
+    (0x0C60, 2865),
+    (0x0C66, 2869),
+    (0x0C77, 2879),
+    (0x0C8E, 2901),
+    (0x0C92, 2904),
+    (0x0CAA, 2927),
+    (0x0CB5, 2937),
+    (0x0CBC, 2942),
+    (0x0CC6, 2951),
+    (0x0CCA, 2954),
+    (0x0CD5, 2958),
+    (0x0CDD, 2960),
+    (0x0CE0, 2962),
+    (0x0CE6, 2966),
+    (0x0CF1, 2976),
+    (0x0D00, 2979),
+    (0x0D0E, 2992),
+    (0x0D12, 2995),
+    (0x0D46, 3046),
+    (0x0D4A, 3049),
+    (0x0D54, 3055),
+    (0x0D66, 3071),
+    (0x0D81, 3097),
+    (0x0D85, 3100),
+    (0x0D9A, 3118),
+    (0x0DB3, 3142),
+    (0x0DBD, 3151),
+    (0x0DC0, 3152),
+    (0x0DCA, 3159),
+    (0x0DCF, 3160),
+    (0x0DD6, 3166),
+    (0x0DD8, 3167),
+    (0x0DE6, 3175),
+    (0x0DF2, 3185),
+    (0x0E01, 3188),
+    (0x0E3F, 3246),
+    (0x0E81, 3275),
+    (0x0E84, 3277),
+    (0x0E86, 3278),
+    (0x0E8C, 3283),
+    (0x0EA5, 3307),
+    (0x0EA7, 3308),
+    (0x0EC0, 3331),
+    (0x0EC6, 3336),
+    (0x0EC8, 3337),
+    (0x0ED0, 3344),
+    (0x0EDC, 3354),
+    (0x0F00, 3358),
+    (0x0F49, 3430),
+    (0x0F71, 3466),
+    (0x0F99, 3505),
+    (0x0FBE, 3541),
+    (0x0FCE, 3556),
+    (0x1000, 3569),
+    (0x10C7, 3767),
+    (0x10CD, 3768),
+    (0x10D0, 3769),
+    (0x124A, 4146),
+    (0x1250, 4150),
+    (0x1258, 4157),
+    (0x125A, 4158),
+    (0x1260, 4162),
+    (0x128A, 4203),
+    (0x1290, 4207),
+    (0x12B2, 4240),
+    (0x12B8, 4244),
+    (0x12C0, 4251),
+    (0x12C2, 4252),
+    (0x12C8, 4256),
+    (0x12D8, 4271),
+    (0x1312, 4328),
+    (0x1318, 4332),
+    (0x135D, 4399),
+    (0x1380, 4431),
+    (0x13A0, 4457),
+    (0x13F8, 4543),
+    (0x1400, 4549),
+    (0x16A0, 5218),
+    (0x1700, 5307),
+    (0x171F, 5329),
+    (0x1740, 5353),
+    (0x1760, 5373),
+    (0x176E, 5386),
+    (0x1772, 5389),
+    (0x1780, 5391),
+    (0x17E0, 5485),
+    (0x17F0, 5495),
+    (0x1800, 5505),
+    (0x1820, 5531),
+    (0x1880, 5620),
+    (0x18B0, 5663),
+    (0x1900, 5733),
+    (0x1920, 5764),
+    (0x1930, 5776),
+    (0x1940, 5788),
+    (0x1944, 5789),
+    (0x1970, 5831),
+    (0x1980, 5836),
+    (0x19B0, 5880),
+    (0x19D0, 5906),
+    (0x19DE, 5917),
+    (0x1A1E, 5979),
+    (0x1A60, 6044),
+    (0x1A7F, 6073),
+    (0x1A90, 6084),
+    (0x1AA0, 6094),
+    (0x1AB0, 6108),
+    (0x1B00, 6139),
+    (0x1B4E, 6216),
+    (0x1BFC, 6382),
+    (0x1C3B, 6442),
+    (0x1C4D, 6457),
+    (0x1C90, 6519),
+    (0x1CBD, 6562),
+    (0x1CD0, 6573),
+    (0x1D00, 6616),
+    (0x1F18, 7150),
+    (0x1F20, 7156),
+    (0x1F48, 7194),
+    (0x1F50, 7200),
+    (0x1F59, 7208),
+    (0x1F5B, 7209),
+    (0x1F5D, 7210),
+    (0x1F5F, 7211),
+    (0x1F80, 7242),
+    (0x1FB6, 7295),
+    (0x1FC6, 7310),
+    (0x1FD6, 7324),
+    (0x1FDD, 7330),
+    (0x1FF2, 7349),
+    (0x1FF6, 7352),
+    (0x2000, 7361),
+    (0x2066, 7462),
+    (0x2074, 7474),
+    (0x2090, 7501),
+    (0x20A0, 7514),
+    (0x20D0, 7547),
+    (0x2100, 7580),
+    (0x2190, 7720),
+    (0x2440, 8386),
+    (0x2460, 8397),
+    (0x2B76, 10209),
+    (0x2B97, 10241),
+    (0x2CF9, 10590),
+    (0x2D27, 10635),
+    (0x2D2D, 10636),
+    (0x2D30, 10637),
+    (0x2D6F, 10693),
+    (0x2D7F, 10695),
+    (0x2DA0, 10719),
+    (0x2DA8, 10726),
+    (0x2DB0, 10733),
+    (0x2DB8, 10740),
+    (0x2DC0, 10747),
+    (0x2DC8, 10754),
+    (0x2DD0, 10761),
+    (0x2DD8, 10768),
+    (0x2DE0, 10775),
+    (0x2E80, 10901),
+    (0x2E9B, 10927),
+    (0x2F00, 11016),
+    (0x2FF0, 11230),
+    (0x3041, 11310),
+    (0x3099, 11396),
+    (0x3105, 11499),
+    (0x3131, 11542),
+    (0x3190, 11636),
+    (0x31EF, 11722),
+    (0x3220, 11770),
+    (0xA490, 41063),
+    (0xA4D0, 41118),
+    (0xA640, 41466),
+    (0xA700, 41650),
+    (0xA7D0, 41856),
+    (0xA7D3, 41858),
+    (0xA7D5, 41859),
+    (0xA7F2, 41867),
+    (0xA830, 41926),
+    (0xA840, 41936),
+    (0xA880, 41992),
+    (0xA8CE, 42062),
+    (0xA8E0, 42074),
+    (0xA95F, 42190),
+    (0xA980, 42220),
+    (0xA9CF, 42298),
+    (0xA9DE, 42309),
+    (0xAA00, 42342),
+    (0xAA40, 42397),
+    (0xAA50, 42411),
+    (0xAA5C, 42421),
+    (0xAADB, 42524),
+    (0xAB01, 42552),
+    (0xAB09, 42558),
+    (0xAB11, 42564),
+    (0xAB20, 42570),
+    (0xAB28, 42577),
+    (0xAB30, 42584),
+    (0xAB70, 42644),
+    (0xABF0, 42770),
+    (0xAC00, 42780),
+    (0xD7B0, 53952),
+    (0xD7CB, 53975),
+    (0xF900, 54024),
+    (0xFA70, 54390),
+    (0xFB00, 54496),
+    (0xFB13, 54503),
+    (0xFB1D, 54508),
+    (0xFB38, 54534),
+    (0xFB3E, 54539),
+    (0xFB40, 54540),
+    (0xFB43, 54542),
+    (0xFB46, 54544),
+    (0xFBD3, 54669),
+    (0xFD92, 55114),
+    (0xFDCF, 55168),
+    (0xFDF0, 55169),
+    (0xFE20, 55211),
+    (0xFE54, 55262),
+    (0xFE68, 55281),
+    (0xFE70, 55285),
+    (0xFE76, 55290),
+    (0xFEFF, 55425),
+    (0xFF01, 55426),
+    (0xFFC2, 55616),
+    (0xFFCA, 55622),
+    (0xFFD2, 55628),
+    (0xFFDA, 55634),
+    (0xFFE0, 55637),
+    (0xFFE8, 55644),
+    (0xFFF9, 55651),
+    (0x10000, 55656),
+    (0x1000D, 55668),
+    (0x10028, 55694),
+    (0x1003C, 55713),
+    (0x1003F, 55715),
+    (0x10050, 55730),
+    (0x10080, 55744),
+    (0x10100, 55867),
+    (0x10107, 55870),
+    (0x10137, 55915),
+    (0x10190, 56003),
+    (0x101A0, 56016),
+    (0x101D0, 56017),
+    (0x10280, 56063),
+    (0x102A0, 56092),
+    (0x102E0, 56141),
+    (0x10300, 56169),
+    (0x1032D, 56205),
+    (0x10350, 56235),
+    (0x10380, 56278),
+    (0x1039F, 56308),
+    (0x103C8, 56345),
+    (0x10400, 56359),
+    (0x104A0, 56517),
+    (0x104B0, 56527),
+    (0x104D8, 56563),
+    (0x10500, 56599),
+    (0x10530, 56639),
+    (0x1056F, 56691),
+    (0x1057C, 56703),
+    (0x1058C, 56718),
+    (0x10594, 56725),
+    (0x10597, 56727),
+    (0x105A3, 56738),
+    (0x105B3, 56753),
+    (0x105BB, 56760),
+    (0x105C0, 56762),
+    (0x10600, 56814),
+    (0x10740, 57125),
+    (0x10760, 57147),
+    (0x10780, 57155),
+    (0x10787, 57161),
+    (0x107B2, 57203),
+    (0x10800, 57212),
+    (0x10808, 57218),
+    (0x1080A, 57219),
+    (0x10837, 57263),
+    (0x1083C, 57265),
+    (0x1083F, 57266),
+    (0x10857, 57289),
+    (0x108A7, 57361),
+    (0x108E0, 57370),
+    (0x108F4, 57389),
+    (0x108FB, 57391),
+    (0x1091F, 57424),
+    (0x1093F, 57451),
+    (0x10980, 57452),
+    (0x109BC, 57508),
+    (0x109D2, 57528),
+    (0x10A05, 57578),
+    (0x10A0C, 57580),
+    (0x10A15, 57588),
+    (0x10A19, 57591),
+    (0x10A38, 57620),
+    (0x10A3F, 57623),
+    (0x10A50, 57633),
+    (0x10A60, 57642),
+    (0x10AC0, 57706),
+    (0x10AEB, 57745),
+    (0x10B00, 57757),
+    (0x10B39, 57811),
+    (0x10B58, 57840),
+    (0x10B78, 57867),
+    (0x10B99, 57893),
+    (0x10BA9, 57897),
+    (0x10C00, 57904),
+    (0x10C80, 57977),
+    (0x10CC0, 58028),
+    (0x10CFA, 58079),
+    (0x10D30, 58125),
+    (0x10D40, 58135),
+    (0x10D69, 58173),
+    (0x10D8E, 58202),
+    (0x10E60, 58204),
+    (0x10E80, 58235),
+    (0x10EAB, 58277),
+    (0x10EB0, 58280),
+    (0x10EC2, 58282),
+    (0x10EFC, 58285),
+    (0x10F30, 58329),
+    (0x10F70, 58371),
+    (0x10FB0, 58397),
+    (0x10FE0, 58425),
+    (0x11000, 58448),
+    (0x11052, 58526),
+    (0x1107F, 58562),
+    (0x110CD, 58630),
+    (0x110D0, 58631),
+    (0x110F0, 58656),
+    (0x11100, 58666),
+    (0x11136, 58719),
+    (0x11150, 58737),
+    (0x11180, 58776),
+    (0x111E1, 58872),
+    (0x11200, 58892),
+    (0x11213, 58910),
+    (0x11280, 58957),
+    (0x11288, 58964),
+    (0x1128A, 58965),
+    (0x1128F, 58969),
+    (0x1129F, 58984),
+    (0x112B0, 58995),
+    (0x112F0, 59054),
+    (0x11300, 59064),
+    (0x11305, 59068),
+    (0x1130F, 59076),
+    (0x11313, 59078),
+    (0x1132A, 59100),
+    (0x11332, 59107),
+    (0x11335, 59109),
+    (0x1133B, 59114),
+    (0x11347, 59124),
+    (0x1134B, 59126),
+    (0x11350, 59129),
+    (0x11357, 59130),
+    (0x1135D, 59131),
+    (0x11366, 59138),
+    (0x11370, 59145),
+    (0x11380, 59150),
+    (0x1138B, 59160),
+    (0x1138E, 59161),
+    (0x11390, 59162),
+    (0x113B7, 59200),
+    (0x113C2, 59210),
+    (0x113C5, 59211),
+    (0x113C7, 59212),
+    (0x113CC, 59216),
+    (0x113D7, 59226),
+    (0x113E1, 59228),
+    (0x11400, 59230),
+    (0x1145D, 59322),
+    (0x11480, 59327),
+    (0x114D0, 59399),
+    (0x11580, 59409),
+    (0x115B8, 59463),
+    (0x11600, 59501),
+    (0x11650, 59570),
+    (0x11660, 59580),
+    (0x11680, 59593),
+    (0x116C0, 59651),
+    (0x116D0, 59661),
+    (0x11700, 59681),
+    (0x1171D, 59708),
+    (0x11730, 59723),
+    (0x11800, 59746),
+    (0x118A0, 59806),
+    (0x118FF, 59889),
+    (0x11909, 59897),
+    (0x1190C, 59898),
+    (0x11915, 59906),
+    (0x11918, 59908),
+    (0x11937, 59938),
+    (0x1193B, 59940),
+    (0x11950, 59952),
+    (0x119A0, 59962),
+    (0x119AA, 59970),
+    (0x119DA, 60016),
+    (0x11A00, 60027),
+    (0x11A50, 60099),
+    (0x11AB0, 60182),
+    (0x11B00, 60255),
+    (0x11BC0, 60265),
+    (0x11BF0, 60299),
+    (0x11C00, 60309),
+    (0x11C0A, 60318),
+    (0x11C38, 60363),
+    (0x11C50, 60377),
+    (0x11C70, 60406),
+    (0x11C92, 60438),
+    (0x11CA9, 60460),
+    (0x11D00, 60474),
+    (0x11D08, 60481),
+    (0x11D0B, 60483),
+    (0x11D3A, 60527),
+    (0x11D3C, 60528),
+    (0x11D3F, 60530),
+    (0x11D50, 60539),
+    (0x11D60, 60549),
+    (0x11D67, 60555),
+    (0x11D6A, 60557),
+    (0x11D90, 60594),
+    (0x11D93, 60596),
+    (0x11DA0, 60602),
+    (0x11EE0, 60612),
+    (0x11F00, 60637),
+    (0x11F12, 60654),
+    (0x11F3E, 60695),
+    (0x11FB0, 60724),
+    (0x11FC0, 60725),
+    (0x11FFF, 60775),
+    (0x12400, 61698),
+    (0x12470, 61809),
+    (0x12480, 61814),
+    (0x12F90, 62010),
+    (0x13000, 62109),
+    (0x13460, 63219),
+    (0x14400, 67214),
+    (0x16100, 67797),
+    (0x16800, 67855),
+    (0x16A40, 68424),
+    (0x16A60, 68455),
+    (0x16A6E, 68465),
+    (0x16AC0, 68546),
+    (0x16AD0, 68556),
+    (0x16AF0, 68586),
+    (0x16B00, 68592),
+    (0x16B50, 68662),
+    (0x16B5B, 68672),
+    (0x16B63, 68679),
+    (0x16B7D, 68700),
+    (0x16D40, 68719),
+    (0x16E40, 68777),
+    (0x16F00, 68868),
+    (0x16F4F, 68943),
+    (0x16F8F, 69000),
+    (0x16FE0, 69017),
+    (0x16FF0, 69022),
+    (0x17000, 69024),
+    (0x18800, 75160),
+    (0x18CFF, 76398),
+    (0x1AFF0, 76408),
+    (0x1AFF5, 76412),
+    (0x1AFFD, 76419),
+    (0x1B000, 76421),
+    (0x1B132, 76712),
+    (0x1B150, 76713),
+    (0x1B155, 76716),
+    (0x1B164, 76717),
+    (0x1B170, 76721),
+    (0x1BC00, 77117),
+    (0x1BC70, 77224),
+    (0x1BC80, 77237),
+    (0x1BC90, 77246),
+    (0x1BC9C, 77256),
+    (0x1CC00, 77264),
+    (0x1CD00, 77514),
+    (0x1CF00, 77950),
+    (0x1CF30, 77996),
+    (0x1CF50, 78019),
+    (0x1D000, 78135),
+    (0x1D100, 78381),
+    (0x1D129, 78420),
+    (0x1D200, 78614),
+    (0x1D2C0, 78684),
+    (0x1D2E0, 78704),
+    (0x1D300, 78724),
+    (0x1D360, 78811),
+    (0x1D400, 78836),
+    (0x1D456, 78921),
+    (0x1D49E, 78992),
+    (0x1D4A2, 78994),
+    (0x1D4A5, 78995),
+    (0x1D4A9, 78997),
+    (0x1D4AE, 79001),
+    (0x1D4BB, 79013),
+    (0x1D4BD, 79014),
+    (0x1D4C5, 79021),
+    (0x1D507, 79086),
+    (0x1D50D, 79090),
+    (0x1D516, 79098),
+    (0x1D51E, 79105),
+    (0x1D53B, 79133),
+    (0x1D540, 79137),
+    (0x1D546, 79142),
+    (0x1D54A, 79143),
+    (0x1D552, 79150),
+    (0x1D6A8, 79490),
+    (0x1D7CE, 79782),
+    (0x1DA9B, 80484),
+    (0x1DAA1, 80489),
+    (0x1DF00, 80504),
+    (0x1DF25, 80535),
+    (0x1E000, 80541),
+    (0x1E008, 80548),
+    (0x1E01B, 80565),
+    (0x1E023, 80572),
+    (0x1E026, 80574),
+    (0x1E030, 80579),
+    (0x1E08F, 80641),
+    (0x1E100, 80642),
+    (0x1E130, 80687),
+    (0x1E140, 80701),
+    (0x1E14E, 80711),
+    (0x1E290, 80713),
+    (0x1E2C0, 80744),
+    (0x1E2FF, 80802),
+    (0x1E4D0, 80803),
+    (0x1E5D0, 80845),
+    (0x1E5FF, 80888),
+    (0x1E7E0, 80889),
+    (0x1E7E8, 80896),
+    (0x1E7ED, 80900),
+    (0x1E7F0, 80902),
+    (0x1E800, 80917),
+    (0x1E8C7, 81114),
+    (0x1E900, 81130),
+    (0x1E950, 81206),
+    (0x1E95E, 81216),
+    (0x1EC71, 81218),
+    (0x1ED01, 81286),
+    (0x1EE00, 81347),
+    (0x1EE05, 81351),
+    (0x1EE21, 81378),
+    (0x1EE24, 81380),
+    (0x1EE27, 81381),
+    (0x1EE29, 81382),
+    (0x1EE34, 81392),
+    (0x1EE39, 81396),
+    (0x1EE3B, 81397),
+    (0x1EE42, 81398),
+    (0x1EE47, 81399),
+    (0x1EE49, 81400),
+    (0x1EE4B, 81401),
+    (0x1EE4D, 81402),
+    (0x1EE51, 81405),
+    (0x1EE54, 81407),
+    (0x1EE57, 81408),
+    (0x1EE59, 81409),
+    (0x1EE5B, 81410),
+    (0x1EE5D, 81411),
+    (0x1EE5F, 81412),
+    (0x1EE61, 81413),
+    (0x1EE64, 81415),
+    (0x1EE67, 81416),
+    (0x1EE6C, 81420),
+    (0x1EE74, 81427),
+    (0x1EE79, 81431),
+    (0x1EE7E, 81435),
+    (0x1EE80, 81436),
+    (0x1EE8B, 81446),
+    (0x1EEA1, 81463),
+    (0x1EEA5, 81466),
+    (0x1EEAB, 81471),
+    (0x1EEF0, 81488),
+    (0x1F000, 81490),
+    (0x1F030, 81534),
+    (0x1F0A0, 81634),
+    (0x1F0B1, 81649),
+    (0x1F0C1, 81664),
+    (0x1F0D1, 81679),
+    (0x1F100, 81716),
+    (0x1F1E6, 81890),
+    (0x1F210, 81919),
+    (0x1F240, 81963),
+    (0x1F250, 81972),
+    (0x1F260, 81974),
+    (0x1F300, 81980),
+    (0x1F6DC, 82964),
+    (0x1F6F0, 82981),
+    (0x1F700, 82994),
+    (0x1F77B, 83113),
+    (0x1F7E0, 83208),
+    (0x1F7F0, 83220),
+    (0x1F800, 83221),
+    (0x1F810, 83233),
+    (0x1F850, 83289),
+    (0x1F860, 83299),
+    (0x1F890, 83339),
+    (0x1F8B0, 83369),
+    (0x1F8C0, 83381),
+    (0x1F900, 83383),
+    (0x1FA60, 83723),
+    (0x1FA70, 83737),
+    (0x1FA80, 83750),
+    (0x1FA8F, 83760),
+    (0x1FACE, 83816),
+    (0x1FADF, 83831),
+    (0x1FAF0, 83842),
+    (0x1FB00, 83851),
+    (0x1FB94, 83998),
+    (0x20000, 84100),
+    (0x2A700, 126820),
+    (0x2B740, 130974),
+    (0x2B820, 131196),
+    (0x2CEB0, 136958),
+    (0x2EBF0, 144431),
+    (0x2F800, 145053),
+    (0x30000, 145595),
+    (0x31350, 150534),
+    (0xE0001, 154726),
+    (0xE0020, 154727),
+    (0xE0100, 154823),
+];
+
+/// The number of values in [`ASSIGNED`]
+pub(crate) const ASSIGNED_LEN: u32 = 155063;
+
+/// Printable scalar values: all but separators other than space, control,
+/// format, surrogate, private-use and unassigned code points
+///
+/// Each entry is the start of a range and the number of values in
+/// preceding ranges.
+pub(crate) static PRINTABLE: &[(u32, u32)] = &[
+    (0x0020, 0),
+    (0x00A1, 95),
+    (0x00AE, 107),
+    (0x037A, 821),
+    (0x0384, 827),
+    (0x038C, 834),
+    (0x038E, 835),
+    (0x03A3, 855),
+    (0x0531, 1252),
+    (0x0559, 1290),
+    (0x058D, 1340),
+    (0x0591, 1343),
+    (0x05D0, 1398),
+    (0x05EF, 1425),
+    (0x0606, 1431),
+    (0x061D, 1453),
+    (0x06DE, 1645),
+    (0x0710, 1693),
+    (0x074D, 1752),
+    (0x07C0, 1853),
+    (0x07FD, 1912),
+    (0x0830, 1961),
+    (0x0840, 1976),
+    (0x085E, 2004),
+    (0x0860, 2005),
+    (0x0870, 2016),
+    (0x0897, 2047),
+    (0x08E3, 2122),
+    (0x0985, 2283),
+    (0x098F, 2291),
+    (0x0993, 2293),
+    (0x09AA, 2315),
+    (0x09B2, 2322),
+    (0x09B6, 2323),
+    (0x09BC, 2327),
+    (0x09C7, 2336),
+    (0x09CB, 2338),
+    (0x09D7, 2342),
+    (0x09DC, 2343),
+    (0x09DF, 2345),
+    (0x09E6, 2350),
+    (0x0A01, 2375),
+    (0x0A05, 2378),
+    (0x0A0F, 2384),
+    (0x0A13, 2386),
+    (0x0A2A, 2408),
+    (0x0A32, 2415),
+    (0x0A35, 2417),
+    (0x0A38, 2419),
+    (0x0A3C, 2421),
+    (0x0A3E, 2422),
+    (0x0A47, 2427),
+    (0x0A4B, 2429),
+    (0x0A51, 2432),
+    (0x0A59, 2433),
+    (0x0A5E, 2437),
+    (0x0A66, 2438),
+    (0x0A81, 2455),
+    (0x0A85, 2458),
+    (0x0A8F, 2467),
+    (0x0A93, 2470),
+    (0x0AAA, 2492),
+    (0x0AB2, 2499),
+    (0x0AB5, 2501),
+    (0x0ABC, 2506),
+    (0x0AC7, 2516),
+    (0x0ACB, 2519),
+    (0x0AD0, 2522),
+    (0x0AE0, 2523),
+    (0x0AE6, 2527),
+    (0x0AF9, 2539),
+    (0x0B01, 2546),
+    (0x0B05, 2549),
+    (0x0B0F, 2557),
+    (0x0B13, 2559),
+    (0x0B2A, 2581),
+    (0x0B32, 2588),
+    (0x0B35, 2590),
+    (0x0B3C, 2595),
+    (0x0B47, 2604),
+    (0x0B4B, 2606),
+    (0x0B55, 2609),
+    (0x0B5C, 2612),
+    (0x0B5F, 2614),
+    (0x0B66, 2619),
+    (0x0B82, 2637),
+    (0x0B85, 2639),
+    (0x0B8E, 2645),
+    (0x0B92, 2648),
+    (0x0B99, 2652),
+    (0x0B9C, 2654),
+    (0x0B9E, 2655),
+    (0x0BA3, 2657),
+    (0x0BA8, 2659),
+    (0x0BAE, 2662),
+    (0x0BBE, 2674),
+    (0x0BC6, 2679),
+    (0x0BCA, 2682),
+    (0x0BD0, 2686),
+    (0x0BD7, 2687),
+    (0x0BE6, 2688),
+    (0x0C00, 2709),
+    (0x0C0E, 2722),
+    (0x0C12, 2725),
+    (0x0C2A, 2748),
+    (0x0C3C, 2764),
+    (0x0C46, 2773),
+    (0x0C4A, 2776),
+    (0x0C55, 2780),
+    (0x0C58, 2782),
+    (0x0C5D, 2785),
+    (0x0C60, 2786),
+    (0x0C66, 2790),
+    (0x0C77, 2800),
+    (0x0C8E, 2822),
+    (0x0C92, 2825),
+    (0x0CAA, 2848),
+    (0x0CB5, 2858),
+    (0x0CBC, 2863),
+    (0x0CC6, 2872),
+    (0x0CCA, 2875),
+    (0x0CD5, 2879),
+    (0x0CDD, 2881),
+    (0x0CE0, 2883),
+    (0x0CE6, 2887),
+    (0x0CF1, 2897),
+    (0x0D00, 2900),
+    (0x0D0E, 2913),
+    (0x0D12, 2916),
+    (0x0D46, 2967),
+    (0x0D4A, 2970),
+    (0x0D54, 2976),
+    (0x0D66, 2992),
+    (0x0D81, 3018),
+    (0x0D85, 3021),
+    (0x0D9A, 3039),
+    (0x0DB3, 3063),
+    (0x0DBD, 3072),
+    (0x0DC0, 3073),
+    (0x0DCA, 3080),
+    (0x0DCF, 3081),
+    (0x0DD6, 3087),
+    (0x0DD8, 3088),
+    (0x0DE6, 3096),
+    (0x0DF2, 3106),
+    (0x0E01, 3109),
+    (0x0E3F, 3167),
+    (0x0E81, 3196),
+    (0x0E84, 3198),
+    (0x0E86, 3199),
+    (0x0E8C, 3204),
+    (0x0EA5, 3228),
+    (0x0EA7, 3229),
+    (0x0EC0, 3252),
+    (0x0EC6, 3257),
+    (0x0EC8, 3258),
+    (0x0ED0, 3265),
+    (0x0EDC, 3275),
+    (0x0F00, 3279),
+    (0x0F49, 3351),
+    (0x0F71, 3387),
+    (0x0F99, 3426),
+    (0x0FBE, 3462),
+    (0x0FCE, 3477),
+    (0x1000, 3490),
+    (0x10C7, 3688),
+    (0x10CD, 3689),
+    (0x10D0, 3690),
+    (0x124A, 4067),
+    (0x1250, 4071),
+    (0x1258, 4078),
+    (0x125A, 4079),
+    (0x1260, 4083),
+    (0x128A, 4124),
+    (0x1290, 4128),
+    (0x12B2, 4161),
+    (0x12B8, 4165),
+    (0x12C0, 4172),
+    (0x12C2, 4173),
+    (0x12C8, 4177),
+    (0x12D8, 4192),
+    (0x1312, 4249),
+    (0x1318, 4253),
+    (0x135D, 4320),
+    (0x1380, 4352),
+    (0x13A0, 4378),
+    (0x13F8, 4464),
+    (0x1400, 4470),
+    (0x1681, 5110),
+    (0x16A0, 5138),
+    (0x1700, 5227),
+    (0x171F, 5249),
+    (0x1740, 5273),
+    (0x1760, 5293),
+    (0x176E, 5306),
+    (0x1772, 5309),
+    (0x1780, 5311),
+    (0x17E0, 5405),
+    (0x17F0, 5415),
+    (0x1800, 5425),
+    (0x180F, 5439),
+    (0x1820, 5450),
+    (0x1880, 5539),
+    (0x18B0, 5582),
+    (0x1900, 5652),
+    (0x1920, 5683),
+    (0x1930, 5695),
+    (0x1940, 5707),
+    (0x1944, 5708),
+    (0x1970, 5750),
+    (0x1980, 5755),
+    (0x19B0, 5799),
+    (0x19D0, 5825),
+    (0x19DE, 5836),
+    (0x1A1E, 5898),
+    (0x1A60, 5963),
+    (0x1A7F, 5992),
+    (0x1A90, 6003),
+    (0x1AA0, 6013),
+    (0x1AB0, 6027),
+    (0x1B00, 6058),
+    (0x1B4E, 6135),
+    (0x1BFC, 6301),
+    (0x1C3B, 6361),
+    (0x1C4D, 6376),
+    (0x1C90, 6438),
+    (0x1CBD, 6481),
+    (0x1CD0, 6492),
+    (0x1D00, 6535),
+    (0x1F18, 7069),
+    (0x1F20, 7075),
+    (0x1F48, 7113),
+    (0x1F50, 7119),
+    (0x1F59, 7127),
+    (0x1F5B, 7128),
+    (0x1F5D, 7129),
+    (0x1F5F, 7130),
+    (0x1F80, 7161),
+    (0x1FB6, 7214),
+    (0x1FC6, 7229),
+    (0x1FD6, 7243),
+    (0x1FDD, 7249),
+    (0x1FF2, 7268),
+    (0x1FF6, 7271),
+    (0x2010, 7280),
+    (0x2030, 7304),
+    (0x2070, 7351),
+    (0x2074, 7353),
+    (0x2090, 7380),
+    (0x20A0, 7393),
+    (0x20D0, 7426),
+    (0x2100, 7459),
+    (0x2190, 7599),
+    (0x2440, 8265),
+    (0x2460, 8276),
+    (0x2B76, 10088),
+    (0x2B97, 10120),
+    (0x2CF9, 10469),
+    (0x2D27, 10514),
+    (0x2D2D, 10515),
+    (0x2D30, 10516),
+    (0x2D6F, 10572),
+    (0x2D7F, 10574),
+    (0x2DA0, 10598),
+    (0x2DA8, 10605),
+    (0x2DB0, 10612),
+    (0x2DB8, 10619),
+    (0x2DC0, 10626),
+    (0x2DC8, 10633),
+    (0x2DD0, 10640),
+    (0x2DD8, 10647),
+    (0x2DE0, 10654),
+    (0x2E80, 10780),
+    (0x2E9B, 10806),
+    (0x2F00, 10895),
+    (0x2FF0, 11109),
+    (0x3001, 11125),
+    (0x3041, 11188),
+    (0x3099, 11274),
+    (0x3105, 11377),
+    (0x3131, 11420),
+    (0x3190, 11514),
+    (0x31EF, 11600),
+    (0x3220, 11648),
+    (0xA490, 40941),
+    (0xA4D0, 40996),
+    (0xA640, 41344),
+    (0xA700, 41528),
+    (0xA7D0, 41734),
+    (0xA7D3, 41736),
+    (0xA7D5, 41737),
+    (0xA7F2, 41745),
+    (0xA830, 41804),
+    (0xA840, 41814),
+    (0xA880, 41870),
+    (0xA8CE, 41940),
+    (0xA8E0, 41952),
+    (0xA95F, 42068),
+    (0xA980, 42098),
+    (0xA9CF, 42176),
+    (0xA9DE, 42187),
+    (0xAA00, 42220),
+    (0xAA40, 42275),
+    (0xAA50, 42289),
+    (0xAA5C, 42299),
+    (0xAADB, 42402),
+    (0xAB01, 42430),
+    (0xAB09, 42436),
+    (0xAB11, 42442),
+    (0xAB20, 42448),
+    (0xAB28, 42455),
+    (0xAB30, 42462),
+    (0xAB70, 42522),
+    (0xABF0, 42648),
+    (0xAC00, 42658),
+    (0xD7B0, 53830),
+    (0xD7CB, 53853),
+    (0xF900, 53902),
+    (0xFA70, 54268),
+    (0xFB00, 54374),
+    (0xFB13, 54381),
+    (0xFB1D, 54386),
+    (0xFB38, 54412),
+    (0xFB3E, 54417),
+    (0xFB40, 54418),
+    (0xFB43, 54420),
+    (0xFB46, 54422),
+    (0xFBD3, 54547),
+    (0xFD92, 54992),
+    (0xFDCF, 55046),
+    (0xFDF0, 55047),
+    (0xFE20, 55089),
+    (0xFE54, 55140),
+    (0xFE68, 55159),
+    (0xFE70, 55163),
+    (0xFE76, 55168),
+    (0xFF01, 55303),
+    (0xFFC2, 55493),
+    (0xFFCA, 55499),
+    (0xFFD2, 55505),
+    (0xFFDA, 55511),
+    (0xFFE0, 55514),
+    (0xFFE8, 55521),
+    (0xFFFC, 55528),
+    (0x10000, 55530),
+    (0x1000D, 55542),
+    (0x10028, 55568),
+    (0x1003C, 55587),
+    (0x1003F, 55589),
+    (0x10050, 55604),
+    (0x10080, 55618),
+    (0x10100, 55741),
+    (0x10107, 55744),
+    (0x10137, 55789),
+    (0x10190, 55877),
+    (0x101A0, 55890),
+    (0x101D0, 55891),
+    (0x10280, 55937),
+    (0x102A0, 55966),
+    (0x102E0, 56015),
+    (0x10300, 56043),
+    (0x1032D, 56079),
+    (0x10350, 56109),
+    (0x10380, 56152),
+    (0x1039F, 56182),
+    (0x103C8, 56219),
+    (0x10400, 56233),
+    (0x104A0, 56391),
+    (0x104B0, 56401),
+    (0x104D8, 56437),
+    (0x10500, 56473),
+    (0x10530, 56513),
+    (0x1056F, 56565),
+    (0x1057C, 56577),
+    (0x1058C, 56592),
+    (0x10594, 56599),
+    (0x10597, 56601),
+    (0x105A3, 56612),
+    (0x105B3, 56627),
+    (0x105BB, 56634),
+    (0x105C0, 56636),
+    (0x10600, 56688),
+    (0x10740, 56999),
+    (0x10760, 57021),
+    (0x10780, 57029),
+    (0x10787, 57035),
+    (0x107B2, 57077),
+    (0x10800, 57086),
+    (0x10808, 57092),
+    (0x1080A, 57093),
+    (0x10837, 57137),
+    (0x1083C, 57139),
+    (0x1083F, 57140),
+    (0x10857, 57163),
+    (0x108A7, 57235),
+    (0x108E0, 57244),
+    (0x108F4, 57263),
+    (0x108FB, 57265),
+    (0x1091F, 57298),
+    (0x1093F, 57325),
+    (0x10980, 57326),
+    (0x109BC, 57382),
+    (0x109D2, 57402),
+    (0x10A05, 57452),
+    (0x10A0C, 57454),
+    (0x10A15, 57462),
+    (0x10A19, 57465),
+    (0x10A38, 57494),
+    (0x10A3F, 57497),
+    (0x10A50, 57507),
+    (0x10A60, 57516),
+    (0x10AC0, 57580),
+    (0x10AEB, 57619),
+    (0x10B00, 57631),
+    (0x10B39, 57685),
+    (0x10B58, 57714),
+    (0x10B78, 57741),
+    (0x10B99, 57767),
+    (0x10BA9, 57771),
+    (0x10C00, 57778),
+    (0x10C80, 57851),
+    (0x10CC0, 57902),
+    (0x10CFA, 57953),
+    (0x10D30, 57999),
+    (0x10D40, 58009),
+    (0x10D69, 58047),
+    (0x10D8E, 58076),
+    (0x10E60, 58078),
+    (0x10E80, 58109),
+    (0x10EAB, 58151),
+    (0x10EB0, 58154),
+    (0x10EC2, 58156),
+    (0x10EFC, 58159),
+    (0x10F30, 58203),
+    (0x10F70, 58245),
+    (0x10FB0, 58271),
+    (0x10FE0, 58299),
+    (0x11000, 58322),
+    (0x11052, 58400),
+    (0x1107F, 58436),
+    (0x110BE, 58498),
+    (0x110D0, 58503),
+    (0x110F0, 58528),
+    (0x11100, 58538),
+    (0x11136, 58591),
+    (0x11150, 58609),
+    (0x11180, 58648),
+    (0x111E1, 58744),
+    (0x11200, 58764),
+    (0x11213, 58782),
+    (0x11280, 58829),
+    (0x11288, 58836),
+    (0x1128A, 58837),
+    (0x1128F, 58841),
+    (0x1129F, 58856),
+    (0x112B0, 58867),
+    (0x112F0, 58926),
+    (0x11300, 58936),
+    (0x11305, 58940),
+    (0x1130F, 58948),
+    (0x11313, 58950),
+    (0x1132A, 58972),
+    (0x11332, 58979),
+    (0x11335, 58981),
+    (0x1133B, 58986),
+    (0x11347, 58996),
+    (0x1134B, 58998),
+    (0x11350, 59001),
+    (0x11357, 59002),
+    (0x1135D, 59003),
+    (0x11366, 59010),
+    (0x11370, 59017),
+    (0x11380, 59022),
+    (0x1138B, 59032),
+    (0x1138E, 59033),
+    (0x11390, 59034),
+    (0x113B7, 59072),
+    (0x113C2, 59082),
+    (0x113C5, 59083),
+    (0x113C7, 59084),
+    (0x113CC, 59088),
+    (0x113D7, 59098),
+    (0x113E1, 59100),
+    (0x11400, 59102),
+    (0x1145D, 59194),
+    (0x11480, 59199),
+    (0x114D0, 59271),
+    (0x11580, 59281),
+    (0x115B8, 59335),
+    (0x11600, 59373),
+    (0x11650, 59442),
+    (0x11660, 59452),
+    (0x11680, 59465),
+    (0x116C0, 59523),
+    (0x116D0, 59533),
+    (0x11700, 59553),
+    (0x1171D, 59580),
+    (0x11730, 59595),
+    (0x11800, 59618),
+    (0x118A0, 59678),
+    (0x118FF, 59761),
+    (0x11909, 59769),
+    (0x1190C, 59770),
+    (0x11915, 59778),
+    (0x11918, 59780),
+    (0x11937, 59810),
+    (0x1193B, 59812),
+    (0x11950, 59824),
+    (0x119A0, 59834),
+    (0x119AA, 59842),
+    (0x119DA, 59888),
+    (0x11A00, 59899),
+    (0x11A50, 59971),
+    (0x11AB0, 60054),
+    (0x11B00, 60127),
+    (0x11BC0, 60137),
+    (0x11BF0, 60171),
+    (0x11C00, 60181),
+    (0x11C0A, 60190),
+    (0x11C38, 60235),
+    (0x11C50, 60249),
+    (0x11C70, 60278),
+    (0x11C92, 60310),
+    (0x11CA9, 60332),
+    (0x11D00, 60346),
+    (0x11D08, 60353),
+    (0x11D0B, 60355),
+    (0x11D3A, 60399),
+    (0x11D3C, 60400),
+    (0x11D3F, 60402),
+    (0x11D50, 60411),
+    (0x11D60, 60421),
+    (0x11D67, 60427),
+    (0x11D6A, 60429),
+    (0x11D90, 60466),
+    (0x11D93, 60468),
+    (0x11DA0, 60474),
+    (0x11EE0, 60484),
+    (0x11F00, 60509),
+    (0x11F12, 60526),
+    (0x11F3E, 60567),
+    (0x11FB0, 60596),
+    (0x11FC0, 60597),
+    (0x11FFF, 60647),
+    (0x12400, 61570),
+    (0x12470, 61681),
+    (0x12480, 61686),
+    (0x12F90, 61882),
+    (0x13000, 61981),
+    (0x13440, 63053),
+    (0x13460, 63075),
+    (0x14400, 67070),
+    (0x16100, 67653),
+    (0x16800, 67711),
+    (0x16A40, 68280),
+    (0x16A60, 68311),
+    (0x16A6E, 68321),
+    (0x16AC0, 68402),
+    (0x16AD0, 68412),
+    (0x16AF0, 68442),
+    (0x16B00, 68448),
+    (0x16B50, 68518),
+    (0x16B5B, 68528),
+    (0x16B63, 68535),
+    (0x16B7D, 68556),
+    (0x16D40, 68575),
+    (0x16E40, 68633),
+    (0x16F00, 68724),
+    (0x16F4F, 68799),
+    (0x16F8F, 68856),
+    (0x16FE0, 68873),
+    (0x16FF0, 68878),
+    (0x17000, 68880),
+    (0x18800, 75016),
+    (0x18CFF, 76254),
+    (0x1AFF0, 76264),
+    (0x1AFF5, 76268),
+    (0x1AFFD, 76275),
+    (0x1B000, 76277),
+    (0x1B132, 76568),
+    (0x1B150, 76569),
+    (0x1B155, 76572),
+    (0x1B164, 76573),
+    (0x1B170, 76577),
+    (0x1BC00, 76973),
+    (0x1BC70, 77080),
+    (0x1BC80, 77093),
+    (0x1BC90, 77102),
+    (0x1BC9C, 77112),
+    (0x1CC00, 77116),
+    (0x1CD00, 77366),
+    (0x1CF00, 77802),
+    (0x1CF30, 77848),
+    (0x1CF50, 77871),
+    (0x1D000, 77987),
+    (0x1D100, 78233),
+    (0x1D129, 78272),
+    (0x1D17B, 78346),
+    (0x1D200, 78458),
+    (0x1D2C0, 78528),
+    (0x1D2E0, 78548),
+    (0x1D300, 78568),
+    (0x1D360, 78655),
+    (0x1D400, 78680),
+    (0x1D456, 78765),
+    (0x1D49E, 78836),
+    (0x1D4A2, 78838),
+    (0x1D4A5, 78839),
+    (0x1D4A9, 78841),
+    (0x1D4AE, 78845),
+    (0x1D4BB, 78857),
+    (0x1D4BD, 78858),
+    (0x1D4C5, 78865),
+    (0x1D507, 78930),
+    (0x1D50D, 78934),
+    (0x1D516, 78942),
+    (0x1D51E, 78949),
+    (0x1D53B, 78977),
+    (0x1D540, 78981),
+    (0x1D546, 78986),
+    (0x1D54A, 78987),
+    (0x1D552, 78994),
+    (0x1D6A8, 79334),
+    (0x1D7CE, 79626),
+    (0x1DA9B, 80328),
+    (0x1DAA1, 80333),
+    (0x1DF00, 80348),
+    (0x1DF25, 80379),
+    (0x1E000, 80385),
+    (0x1E008, 80392),
+    (0x1E01B, 80409),
+    (0x1E023, 80416),
+    (0x1E026, 80418),
+    (0x1E030, 80423),
+    (0x1E08F, 80485),
+    (0x1E100, 80486),
+    (0x1E130, 80531),
+    (0x1E140, 80545),
+    (0x1E14E, 80555),
+    (0x1E290, 80557),
+    (0x1E2C0, 80588),
+    (0x1E2FF, 80646),
+    (0x1E4D0, 80647),
+    (0x1E5D0, 80689),
+    (0x1E5FF, 80732),
+    (0x1E7E0, 80733),
+    (0x1E7E8, 80740),
+    (0x1E7ED, 80744),
+    (0x1E7F0, 80746),
+    (0x1E800, 80761),
+    (0x1E8C7, 80958),
+    (0x1E900, 80974),
+    (0x1E950, 81050),
+    (0x1E95E, 81060),
+    (0x1EC71, 81062),
+    (0x1ED01, 81130),
+    (0x1EE00, 81191),
+    (0x1EE05, 81195),
+    (0x1EE21, 81222),
+    (0x1EE24, 81224),
+    (0x1EE27, 81225),
+    (0x1EE29, 81226),
+    (0x1EE34, 81236),
+    (0x1EE39, 81240),
+    (0x1EE3B, 81241),
+    (0x1EE42, 81242),
+    (0x1EE47, 81243),
+    (0x1EE49, 81244),
+    (0x1EE4B, 81245),
+    (0x1EE4D, 81246),
+    (0x1EE51, 81249),
+    (0x1EE54, 81251),
+    (0x1EE57, 81252),
+    (0x1EE59, 81253),
+    (0x1EE5B, 81254),
+    (0x1EE5D, 81255),
+    (0x1EE5F, 81256),
+    (0x1EE61, 81257),
+    (0x1EE64, 81259),
+    (0x1EE67, 81260),
+    (0x1EE6C, 81264),
+    (0x1EE74, 81271),
+    (0x1EE79, 81275),
+    (0x1EE7E, 81279),
+    (0x1EE80, 81280),
+    (0x1EE8B, 81290),
+    (0x1EEA1, 81307),
+    (0x1EEA5, 81310),
+    (0x1EEAB, 81315),
+    (0x1EEF0, 81332),
+    (0x1F000, 81334),
+    (0x1F030, 81378),
+    (0x1F0A0, 81478),
+    (0x1F0B1, 81493),
+    (0x1F0C1, 81508),
+    (0x1F0D1, 81523),
+    (0x1F100, 81560),
+    (0x1F1E6, 81734),
+    (0x1F210, 81763),
+    (0x1F240, 81807),
+    (0x1F250, 81816),
+    (0x1F260, 81818),
+    (0x1F300, 81824),
+    (0x1F6DC, 82808),
+    (0x1F6F0, 82825),
+    (0x1F700, 82838),
+    (0x1F77B, 82957),
+    (0x1F7E0, 83052),
+    (0x1F7F0, 83064),
+    (0x1F800, 83065),
+    (0x1F810, 83077),
+    (0x1F850, 83133),
+    (0x1F860, 83143),
+    (0x1F890, 83183),
+    (0x1F8B0, 83213),
+    (0x1F8C0, 83225),
+    (0x1F900, 83227),
+    (0x1FA60, 83567),
+    (0x1FA70, 83581),
+    (0x1FA80, 83594),
+    (0x1FA8F, 83604),
+    (0x1FACE, 83660),
+    (0x1FADF, 83675),
+    (0x1FAF0, 83686),
+    (0x1FB00, 83695),
+    (0x1FB94, 83842),
+    (0x20000, 83944),
+    (0x2A700, 126664),
+    (0x2B740, 130818),
+    (0x2B820, 131040),
+    (0x2CEB0, 136802),
+    (0x2EBF0, 144275),
+    (0x2F800, 144897),
+    (0x30000, 145439),
+    (0x31350, 150378),
+    (0xE0100, 154570),
+];
+
+/// The number of values in [`PRINTABLE`]
+pub(crate) const PRINTABLE_LEN: u32 = 154810;
@@ -107,7 +107,7 @@ pub use int::{UniformInt, UniformUsize};
 #[path = "uniform_other.rs"]
 mod other;
 #[doc(inline)]
-pub use other::{UniformChar, UniformDuration};
+pub use other::{UniformChar, UniformDuration, UniformIpv4Addr, UniformIpv6Addr, UniformNonZero};
 
 use core::fmt;
 use core::ops::{Range, RangeInclusive, RangeTo, RangeToInclusive};
@@ -122,6 +122,9 @@ pub enum Error {
     EmptyRange,
     /// Input or range `high - low` is non-finite. Not relevant to integer types.
     NonFinite,
+    /// The prefix length exceeds the length of the address, in
+    /// [`Uniform::new_prefix`].
+    InvalidPrefix,
 }
 
 impl fmt::Display for Error {
@@ -129,6 +132,7 @@ impl fmt::Display for Error {
         f.write_str(match self {
             Error::EmptyRange => "low > high (or equal if exclusive) in uniform distribution",
             Error::NonFinite => "Non-finite range in uniform distribution",
+            Error::InvalidPrefix => "prefix length exceeds address length in uniform distribution",
         })
     }
 }
@@ -154,7 +158,7 @@ use serde::{Deserialize, Serialize};
 /// When sampling from a constant range, many calculations can happen at
 /// compile-time and all methods should be fast; for floating-point ranges and
 /// the full range of integer types, this should have comparable performance to
-/// the [`StandardUniform`](super::StandardUniform) distribution.
+/// the [`StandardUniform`](struct@super::StandardUniform) distribution.
 ///
 /// # Provided implementations
 ///
@@ -173,6 +177,13 @@ use serde::{Deserialize, Serialize};
 ///   64-bit CPU architectures.
 /// - `Duration` ([`UniformDuration`]): samples a range over the implementation
 ///   for `u32` or `u64`
+/// - `Ipv4Addr` and `Ipv6Addr` ([`UniformIpv4Addr`], [`UniformIpv6Addr`]):
+///   samples a range over the implementation for `u32` or `u128`; see also
+///   [`Uniform::<Ipv4Addr>::new_prefix`](Uniform::new_prefix) for sampling
+///   within a network
+/// - `NonZero` integer types except `NonZeroUsize` and `NonZeroIsize`
+///   ([`UniformNonZero`]): samples a range excluding zero over the
+///   implementation for the underlying integer type
 /// - SIMD types (requires [`simd_support`] feature) like x86's [`__m128i`]
 ///   and `std::simd`'s [`u32x4`], [`f32x4`] and [`mask32x4`] types are
 ///   effectively arrays of integer or floating-point types. Each lane is
@@ -256,6 +267,10 @@ impl<X: SampleUniform> Distribution<X> for Uniform<X> {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> X {
         self.0.sample(rng)
     }
+
+    fn sample_fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [X]) {
+        self.0.sample_fill(rng, out)
+    }
 }
 
 /// Helper trait for creating objects using the correct implementation of
@@ -310,6 +325,17 @@ pub trait UniformSampler: Sized {
     /// Sample a value.
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X;
 
+    /// Fill `out` with sampled values.
+    ///
+    /// This backs [`Uniform`]'s implementation of
+    /// [`Distribution::sample_fill`], with the same guarantees. The default
+    /// implementation calls [`UniformSampler::sample`] for each element.
+    fn sample_fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [Self::X]) {
+        for x in out {
+            *x = self.sample(rng);
+        }
+    }
+
     /// Sample a single value uniformly from a range with inclusive lower bound
     /// and exclusive upper bound `[low, high)`.
     ///
@@ -486,6 +512,91 @@ impl_sample_range_u!(u64);
 impl_sample_range_u!(u128);
 impl_sample_range_u!(usize);
 
+/// Integer ranges which may be sampled at multiples of a step
+///
+/// This is used by [`Rng::random_range_step`] to sample uniformly from
+/// `start`, `start + step`, `start + 2 * step`, ... within a range, for
+/// example grid-aligned positions. Unlike rounding a sample of the range to a
+/// multiple of `step`, this is unbiased even where the length of the range is
+/// not a multiple of `step`.
+///
+/// It is implemented for `low..high` and `low..=high` of all integer types.
+///
+/// [`Rng::random_range_step`]: crate::Rng::random_range_step
+pub trait SampleRangeStep<T> {
+    /// Generate a sample of `start + k * step` within the range, for a
+    /// uniformly chosen integer `k >= 0`.
+    ///
+    /// Fails with [`Error::EmptyRange`] if the range is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step <= 0`.
+    fn sample_single_step<R: RngCore + ?Sized>(self, step: T, rng: &mut R) -> Result<T, Error>;
+}
+
+macro_rules! impl_sample_range_step {
+    ($ty:ty, $unsigned:ident) => {
+        impl SampleRangeStep<$ty> for Range<$ty> {
+            #[inline]
+            fn sample_single_step<R: RngCore + ?Sized>(
+                self,
+                step: $ty,
+                rng: &mut R,
+            ) -> Result<$ty, Error> {
+                if !(self.start < self.end) {
+                    return Err(Error::EmptyRange);
+                }
+                let span = self.end.wrapping_sub(self.start) as $unsigned - 1;
+                Ok(impl_sample_range_step!(@sample $ty, $unsigned, self.start, span, step, rng))
+            }
+        }
+
+        impl SampleRangeStep<$ty> for RangeInclusive<$ty> {
+            #[inline]
+            fn sample_single_step<R: RngCore + ?Sized>(
+                self,
+                step: $ty,
+                rng: &mut R,
+            ) -> Result<$ty, Error> {
+                let (start, end) = self.into_inner();
+                if !(start <= end) {
+                    return Err(Error::EmptyRange);
+                }
+                let span = end.wrapping_sub(start) as $unsigned;
+                Ok(impl_sample_range_step!(@sample $ty, $unsigned, start, span, step, rng))
+            }
+        }
+    };
+
+    // Sample `low + k * step <= low + span` for uniform `k`
+    (@sample $ty:ty, $unsigned:ident, $low:expr, $span:expr, $step:expr, $rng:expr) => {{
+        assert!($step > 0, "step must be positive");
+        let step = $step as $unsigned;
+        let k = <$unsigned as SampleUniform>::Sampler::sample_single_inclusive(
+            0,
+            $span / step,
+            $rng,
+        )
+        .unwrap();
+        // `k * step <= span` cannot overflow
+        $low.wrapping_add((k * step) as $ty)
+    }};
+}
+
+impl_sample_range_step!(i8, u8);
+impl_sample_range_step!(i16, u16);
+impl_sample_range_step!(i32, u32);
+impl_sample_range_step!(i64, u64);
+impl_sample_range_step!(i128, u128);
+impl_sample_range_step!(isize, usize);
+impl_sample_range_step!(u8, u8);
+impl_sample_range_step!(u16, u16);
+impl_sample_range_step!(u32, u32);
+impl_sample_range_step!(u64, u64);
+impl_sample_range_step!(u128, u128);
+impl_sample_range_step!(usize, usize);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -505,6 +616,31 @@ mod tests {
         assert_eq!(unit_box.0, de_unit_box.0);
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_uniform_deserialization_validation() {
+        let dist: Uniform<u8> = Uniform::new(0, 10).unwrap();
+        let json = serde_json::to_string(&dist).unwrap();
+        assert_eq!(json, r#"{"low":0,"range":10,"thresh":6}"#);
+        let de_dist: Uniform<u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(de_dist, dist);
+        let full: Uniform<u64> = serde_json::from_str(r#"{"low":0,"range":0,"thresh":0}"#).unwrap();
+        assert_eq!(full, Uniform::new_inclusive(0, u64::MAX).unwrap());
+        assert!(serde_json::from_str::<Uniform<u8>>(r#"{"low":0,"range":10,"thresh":0}"#).is_err());
+
+        let dist: Uniform<f64> = Uniform::new(-1.0, 1.0).unwrap();
+        let json = serde_json::to_string(&dist).unwrap();
+        let de_dist: Uniform<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(de_dist, dist);
+        for json in [
+            r#"{"low":0.0,"scale":-1.0}"#,
+            r#"{"low":0.0,"scale":1e309}"#,
+            r#"{"low":1.7e308,"scale":1.7e308}"#,
+        ] {
+            assert!(serde_json::from_str::<Uniform<f64>>(json).is_err());
+        }
+    }
+
     #[test]
     fn test_custom_uniform() {
         use crate::distr::uniform::{SampleBorrow, SampleUniform, UniformFloat, UniformSampler};
@@ -9,15 +9,13 @@
 
 //! `UniformFloat` implementation
 
-use super::{Error, SampleBorrow, SampleUniform, UniformSampler};
+use super::{Error, SampleBorrow, SampleUniform, Uniform, UniformSampler};
 use crate::Rng;
 use crate::distr::float::IntoFloat;
 use crate::distr::utils::{BoolAsSIMD, FloatAsSIMD, FloatSIMDUtils, IntAsSIMD};
 
 #[cfg(feature = "simd_support")]
 use core::simd::prelude::*;
-// #[cfg(feature = "simd_support")]
-// use core::simd::{LaneCount, SupportedLaneCount};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -40,18 +38,52 @@ use serde::{Deserialize, Serialize};
 /// It is not guaranteed that exclusive limits of a range are respected; i.e.
 /// when sampling the range `[a, b)` it is not guaranteed that `b` is never
 /// sampled.
+/// Use [`Uniform::new_open`] or [`Uniform::new_open_closed`] where `a` must
+/// never be sampled.
+///
+/// # Serialization
+///
+/// With the `serde` feature, deserialization checks that `low` and the upper
+/// bound of the range are finite and that the scale is non-negative.
 ///
 /// [`new`]: UniformSampler::new
 /// [`new_inclusive`]: UniformSampler::new_inclusive
-/// [`StandardUniform`]: crate::distr::StandardUniform
+/// [`StandardUniform`]: struct@crate::distr::StandardUniform
 /// [`Uniform`]: super::Uniform
 #[derive(Clone, Copy, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct UniformFloat<X> {
     low: X,
     scale: X,
 }
 
+#[cfg(feature = "serde")]
+macro_rules! uniform_float_deserialize {
+    ($ty:ty) => {
+        impl<'de> Deserialize<'de> for UniformFloat<$ty> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(Deserialize)]
+                #[serde(rename = "UniformFloat")]
+                struct Repr {
+                    low: $ty,
+                    scale: $ty,
+                }
+
+                let Repr { low, scale } = Repr::deserialize(deserializer)?;
+                if !(low.is_finite() && scale >= 0.0 && (low + scale).is_finite()) {
+                    return Err(serde::de::Error::custom("invalid UniformFloat parameters"));
+                }
+                Ok(UniformFloat { low, scale })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+uniform_float_deserialize! { f32 }
+#[cfg(feature = "serde")]
+uniform_float_deserialize! { f64 }
+
 macro_rules! uniform_float_impl {
     ($($meta:meta)?, $ty:ty, $uty:ident, $f_scalar:ident, $u_scalar:ident, $bits_to_discard:expr) => {
         $(#[cfg($meta)])?
@@ -196,9 +228,63 @@ macro_rules! uniform_float_impl {
     };
 }
 
+#[cfg(feature = "f16")]
+uniform_float_impl! { feature = "f16", f16, u16, f16, u16, 16 - 10 }
 uniform_float_impl! { , f32, u32, f32, u32, 32 - 23 }
 uniform_float_impl! { , f64, u64, f64, u64, 64 - 52 }
 
+macro_rules! uniform_float_open_impl {
+    ($ty:ident) => {
+        impl Uniform<$ty> {
+            /// Create a new `Uniform` instance, which samples uniformly from
+            /// the open range `(low, high)` (excluding both `low` and `high`).
+            ///
+            /// This is equivalent to [`Uniform::new_inclusive`] from the
+            /// smallest value greater than `low` to the largest value less
+            /// than `high`, thus, unlike [`Uniform::new`], neither limit is
+            /// ever sampled.
+            ///
+            /// Fails if no value lies strictly between `low` and `high`, or if
+            /// `low`, `high` or the range `high - low` is non-finite. In
+            /// release mode, only the range is checked.
+            pub fn new_open(low: $ty, high: $ty) -> Result<Self, Error> {
+                Uniform::new_inclusive(Self::next_up(low), -Self::next_up(-high))
+            }
+
+            /// Create a new `Uniform` instance, which samples uniformly from
+            /// the half-open range `(low, high]` (excluding `low`).
+            ///
+            /// This is equivalent to [`Uniform::new_inclusive`] from the
+            /// smallest value greater than `low`, thus `low` is never sampled.
+            ///
+            /// Fails if `low >= high`, or if `low`, `high` or the range
+            /// `high - low` is non-finite. In release mode, only the range
+            /// is checked.
+            pub fn new_open_closed(low: $ty, high: $ty) -> Result<Self, Error> {
+                Uniform::new_inclusive(Self::next_up(low), high)
+            }
+
+            /// The smallest value greater than `x`
+            fn next_up(x: $ty) -> $ty {
+                if x.is_nan() || x == $ty::INFINITY {
+                    x
+                } else if x == 0.0 {
+                    $ty::from_bits(1)
+                } else if x > 0.0 {
+                    $ty::from_bits(x.to_bits() + 1)
+                } else {
+                    $ty::from_bits(x.to_bits() - 1)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "f16")]
+uniform_float_open_impl! { f16 }
+uniform_float_open_impl! { f32 }
+uniform_float_open_impl! { f64 }
+
 #[cfg(feature = "simd_support")]
 uniform_float_impl! { feature = "simd_support", f32x2, u32x2, f32, u32, 32 - 23 }
 #[cfg(feature = "simd_support")]
@@ -359,6 +445,43 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "f16")]
+    fn test_f16() {
+        use crate::distr::Distribution;
+        let mut rng = crate::test::rng(253);
+
+        for &(low, high) in &[
+            (0.0, 1.0),
+            (-100.0, 100.0),
+            (-f16::MAX * 0.4, f16::MAX * 0.5),
+        ] {
+            let d = Uniform::<f16>::new(low, high).unwrap();
+            let d_incl = Uniform::<f16>::new_inclusive(low, high).unwrap();
+            for _ in 0..100 {
+                let x = d.sample(&mut rng);
+                assert!(low <= x && x < high);
+                let x = d_incl.sample(&mut rng);
+                assert!(low <= x && x <= high);
+                let x = rng.random_range(low..high);
+                assert!(low <= x && x <= high);
+            }
+            assert_eq!(d.sample(&mut const_rng(0)), low);
+            assert!(d_incl.sample(&mut const_rng(u64::MAX)) <= high);
+        }
+
+        let d = Uniform::<f16>::new_open(-1.0, 1.0).unwrap();
+        for _ in 0..100 {
+            let x = d.sample(&mut rng);
+            assert!(-1.0 < x && x < 1.0);
+        }
+        assert_eq!(Uniform::<f16>::new(1.0, 1.0), Err(Error::EmptyRange));
+        assert_eq!(
+            Uniform::<f16>::new(-f16::MAX, f16::MAX),
+            Err(Error::NonFinite)
+        );
+    }
+
     #[test]
     fn test_float_overflow() {
         assert_eq!(Uniform::try_from(f64::MIN..f64::MAX), Err(Error::NonFinite));
@@ -371,6 +494,42 @@ mod tests {
         rng.random_range(f64::MIN..f64::MAX);
     }
 
+    #[test]
+    fn test_float_open() {
+        use crate::distr::Distribution;
+        let mut rng = crate::test::rng(254);
+
+        // The full range of values is sampled by a step RNG
+        let mut step = step_rng(0, 1 << (64 - 52));
+        let d = Uniform::<f64>::new_open_closed(0.0, 1.0).unwrap();
+        assert!((0..1000).all(|_| d.sample(&mut step) > 0.0));
+        assert_eq!(d.sample(&mut const_rng(0)), f64::from_bits(1));
+        assert_eq!(d.sample(&mut const_rng(u64::MAX)), 1.0);
+
+        let d = Uniform::<f32>::new_open(-1.0, 1.0).unwrap();
+        for _ in 0..1000 {
+            let x = d.sample(&mut rng);
+            assert!(-1.0 < x && x < 1.0);
+        }
+        assert!(d.sample(&mut const_rng(0)) > -1.0);
+        assert!(d.sample(&mut const_rng(u64::MAX)) < 1.0);
+
+        // Ranges containing a single value
+        let tiny = f64::from_bits(1);
+        let d = Uniform::<f64>::new_open(0.0, 2.0 * tiny).unwrap();
+        assert_eq!(d.sample(&mut rng), tiny);
+        let d = Uniform::<f64>::new_open_closed(-tiny, 0.0).unwrap();
+        assert_eq!(d.sample(&mut rng), 0.0);
+
+        assert_eq!(Uniform::<f64>::new_open(0.0, tiny), Err(Error::EmptyRange));
+        assert_eq!(
+            Uniform::<f32>::new_open_closed(1.0, 1.0),
+            Err(Error::EmptyRange)
+        );
+        assert!(Uniform::<f64>::new_open(1.0, 1.0 + f64::EPSILON).is_err());
+        assert!(Uniform::<f64>::new_open(-1.0, 1.0 + f64::EPSILON).is_ok());
+    }
+
     #[test]
     #[cfg(all(feature = "std", panic = "unwind"))]
     fn test_float_assertions() {
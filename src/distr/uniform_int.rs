@@ -17,8 +17,6 @@ use crate::distr::{Distribution, StandardUniform};
 
 #[cfg(feature = "simd_support")]
 use core::simd::prelude::*;
-#[cfg(feature = "simd_support")]
-use core::simd::{LaneCount, SupportedLaneCount};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -58,16 +56,26 @@ use serde::{Deserialize, Serialize};
 /// multiply by `range`, the result is in the high word. Then comparing the low
 /// word against `zone` makes sure our distribution is uniform.
 ///
+/// For 128-bit types, ranges below `2^64` are sampled using 64-bit arithmetic.
+/// Samples from ranges of at least `2^96` would frequently be rejected, hence
+/// for these we use 192 bits of randomness, computed via partial products with
+/// a 64-bit word.
+///
 /// # Bias
 ///
 /// Unless the `unbiased` feature flag is used, outputs may have a small bias.
 /// In the worst case, bias affects 1 in `2^n` samples where n is
-/// 56 (`i8` and `u8`), 48 (`i16` and `u16`), 96 (`i32` and `u32`), 64 (`i64`
-/// and `u64`), 128 (`i128` and `u128`).
+/// 56 (`i8` and `u8`), 48 (`i16` and `u16`), 96 (`i32` and `u32`), 64 (`i64`,
+/// `u64`, `i128` and `u128`).
+///
+/// # Serialization
+///
+/// With the `serde` feature, deserialization checks that the stored rejection
+/// threshold is consistent with the stored range.
 ///
 /// [`Uniform`]: super::Uniform
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct UniformInt<X> {
     pub(super) low: X,
     pub(super) range: X,
@@ -80,6 +88,33 @@ macro_rules! uniform_int_impl {
             type Sampler = UniformInt<$ty>;
         }
 
+        #[cfg(feature = "serde")]
+        impl<'de> Deserialize<'de> for UniformInt<$ty> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(Deserialize)]
+                #[serde(rename = "UniformInt")]
+                struct Repr {
+                    low: $ty,
+                    range: $ty,
+                    thresh: $ty,
+                }
+
+                let Repr { low, range, thresh } = Repr::deserialize(deserializer)?;
+                let range_s = range as $uty as $sample_ty;
+                let expected = if range_s > 0 {
+                    range_s.wrapping_neg() % range_s
+                } else {
+                    0
+                };
+                if thresh != expected as $uty as $ty {
+                    return Err(serde::de::Error::custom(
+                        "UniformInt threshold does not match range",
+                    ));
+                }
+                Ok(UniformInt { low, range, thresh })
+            }
+        }
+
         impl UniformSampler for UniformInt<$ty> {
             // We play free and fast with unsigned vs signed here
             // (when $ty is signed), but that's fine, since the
@@ -95,12 +130,7 @@ macro_rules! uniform_int_impl {
                 B1: SampleBorrow<Self::X> + Sized,
                 B2: SampleBorrow<Self::X> + Sized,
             {
-                let low = *low_b.borrow();
-                let high = *high_b.borrow();
-                if !(low < high) {
-                    return Err(Error::EmptyRange);
-                }
-                UniformSampler::new_inclusive(low, high - 1)
+                Self::new_const(*low_b.borrow(), *high_b.borrow())
             }
 
             #[inline] // if the range is constant, this helps LLVM to do the
@@ -110,25 +140,7 @@ macro_rules! uniform_int_impl {
                 B1: SampleBorrow<Self::X> + Sized,
                 B2: SampleBorrow<Self::X> + Sized,
             {
-                let low = *low_b.borrow();
-                let high = *high_b.borrow();
-                if !(low <= high) {
-                    return Err(Error::EmptyRange);
-                }
-
-                let range = high.wrapping_sub(low).wrapping_add(1) as $uty;
-                let thresh = if range > 0 {
-                    let range = $sample_ty::from(range);
-                    (range.wrapping_neg() % range)
-                } else {
-                    0
-                };
-
-                Ok(UniformInt {
-                    low,
-                    range: range as $ty,           // type: $uty
-                    thresh: thresh as $uty as $ty, // type: $sample_ty
-                })
+                Self::new_inclusive_const(*low_b.borrow(), *high_b.borrow())
             }
 
             /// Sample from distribution, Lemire's method, unbiased
@@ -140,6 +152,10 @@ macro_rules! uniform_int_impl {
                 }
 
                 let thresh = self.thresh as $uty as $sample_ty;
+                if $sample_ty::BITS == 128 {
+                    let hi = sample_u128(rng, range as u128, thresh as u128);
+                    return self.low.wrapping_add(hi as $ty);
+                }
                 let hi = loop {
                     let (hi, lo) = rng.random::<$sample_ty>().wmul(range);
                     if lo >= thresh {
@@ -149,6 +165,30 @@ macro_rules! uniform_int_impl {
                 self.low.wrapping_add(hi as $ty)
             }
 
+            /// Fill `out` using Lemire's method, yielding the same values as
+            /// repeated calls to [`UniformSampler::sample`]
+            fn sample_fill<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [Self::X]) {
+                let range = self.range as $uty as $sample_ty;
+                if range == 0 || $sample_ty::BITS == 128 {
+                    for x in out {
+                        *x = self.sample(rng);
+                    }
+                    return;
+                }
+
+                // Range checks are hoisted out of the loop
+                let thresh = self.thresh as $uty as $sample_ty;
+                for x in out {
+                    let hi = loop {
+                        let (hi, lo) = rng.random::<$sample_ty>().wmul(range);
+                        if lo >= thresh {
+                            break hi;
+                        }
+                    };
+                    *x = self.low.wrapping_add(hi as $ty);
+                }
+            }
+
             #[inline]
             fn sample_single<R: Rng + ?Sized, B1, B2>(
                 low_b: B1,
@@ -170,7 +210,7 @@ macro_rules! uniform_int_impl {
             /// Sample single value, Canon's method, biased
             ///
             /// In the worst case, bias affects 1 in `2^n` samples where n is
-            /// 56 (`i8`), 48 (`i16`), 96 (`i32`), 64 (`i64`), 128 (`i128`).
+            /// 56 (`i8`), 48 (`i16`), 96 (`i32`), 64 (`i64`), 64 (`i128`).
             #[cfg(not(feature = "unbiased"))]
             #[inline]
             fn sample_single_inclusive<R: Rng + ?Sized, B1, B2>(
@@ -192,6 +232,10 @@ macro_rules! uniform_int_impl {
                     // Range is MAX+1 (unrepresentable), so we need a special case
                     return Ok(rng.random());
                 }
+                if $sample_ty::BITS == 128 {
+                    let result = sample_single_u128(rng, range as u128);
+                    return Ok(low.wrapping_add(result as $ty));
+                }
 
                 // generate a sample using a sensible integer type
                 let (mut result, lo_order) = rng.random::<$sample_ty>().wmul(range);
@@ -230,6 +274,10 @@ macro_rules! uniform_int_impl {
                     // Range is MAX+1 (unrepresentable), so we need a special case
                     return Ok(rng.random());
                 }
+                if $sample_ty::BITS == 128 {
+                    let result = sample_single_u128(rng, range as u128);
+                    return Ok(low.wrapping_add(result as $ty));
+                }
 
                 let (mut result, mut lo) = rng.random::<$sample_ty>().wmul(range);
 
@@ -257,7 +305,279 @@ macro_rules! uniform_int_impl {
                 Ok(low.wrapping_add(result as $ty))
             }
         }
+
+        impl UniformInt<$ty> {
+            /// Construct, as [`UniformSampler::new`], in const context
+            #[inline]
+            pub const fn new_const(low: $ty, high: $ty) -> Result<Self, Error> {
+                if !(low < high) {
+                    return Err(Error::EmptyRange);
+                }
+                Self::new_inclusive_const(low, high - 1)
+            }
+
+            /// Construct, as [`UniformSampler::new_inclusive`], in const
+            /// context
+            #[inline]
+            pub const fn new_inclusive_const(low: $ty, high: $ty) -> Result<Self, Error> {
+                if !(low <= high) {
+                    return Err(Error::EmptyRange);
+                }
+
+                let range = high.wrapping_sub(low).wrapping_add(1) as $uty;
+                let thresh = if range > 0 {
+                    let range = range as $sample_ty;
+                    (range.wrapping_neg() % range)
+                } else {
+                    0
+                };
+
+                Ok(UniformInt {
+                    low,
+                    range: range as $ty,           // type: $uty
+                    thresh: thresh as $uty as $ty, // type: $sample_ty
+                })
+            }
+
+            /// Sample from the distribution without data-dependent branches or
+            /// loops
+            ///
+            /// See [`Uniform::sample_ct`](super::Uniform::sample_ct).
+            #[inline]
+            pub fn sample_ct<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                // With `range = 0` representing the full range, this is
+                // `range - 1` in all cases
+                let range_m1 = (self.range as $uty).wrapping_sub(1) as u128;
+                let hi = if <$uty>::BITS == 128 {
+                    mul_shift_ct::<3, R>(rng, range_m1)
+                } else {
+                    mul_shift_ct::<2, R>(rng, range_m1)
+                };
+                self.low.wrapping_add(hi as $ty)
+            }
+        }
+
+        impl super::Uniform<$ty> {
+            /// Create a new `Uniform` instance, as [`Uniform::new`], in const
+            /// context
+            ///
+            /// This allows a `Uniform` over an integer type in `const` and
+            /// `static` items:
+            ///
+            /// ```
+            /// use rand::distr::{Distribution, Uniform};
+            ///
+            /// static DIE: Uniform<u8> = match Uniform::<u8>::new_const(1, 7) {
+            ///     Ok(distr) => distr,
+            ///     Err(_) => panic!("empty range"),
+            /// };
+            /// let roll = DIE.sample(&mut rand::rng());
+            /// assert!((1..7).contains(&roll));
+            /// ```
+            ///
+            /// [`Uniform::new`]: super::Uniform::new
+            #[inline]
+            pub const fn new_const(low: $ty, high: $ty) -> Result<Self, Error> {
+                match UniformInt::<$ty>::new_const(low, high) {
+                    Ok(sampler) => Ok(super::Uniform(sampler)),
+                    Err(e) => Err(e),
+                }
+            }
+
+            /// Create a new `Uniform` instance, as [`Uniform::new_inclusive`],
+            /// in const context
+            ///
+            /// [`Uniform::new_inclusive`]: super::Uniform::new_inclusive
+            #[inline]
+            pub const fn new_inclusive_const(low: $ty, high: $ty) -> Result<Self, Error> {
+                match UniformInt::<$ty>::new_inclusive_const(low, high) {
+                    Ok(sampler) => Ok(super::Uniform(sampler)),
+                    Err(e) => Err(e),
+                }
+            }
+
+            /// Sample from the distribution in constant time
+            ///
+            /// Unlike [`Distribution::sample`], this uses no rejection loop
+            /// and no branches depending on the range or the output; it
+            /// always consumes the same amount of randomness: two `u64` words
+            /// for types of up to 64 bits and three for 128-bit types. This is
+            /// suited to secret-dependent sampling, e.g. of nonces, as far as
+            /// compiled code follows the source: Rust gives no guarantee of
+            /// constant-time code generation.
+            ///
+            /// Instead of rejection, the sample space is extended by 64 bits
+            /// beyond the range, thus the bias of any output is below
+            /// `2^-64`. Results differ from [`Distribution::sample`].
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use rand::distr::Uniform;
+            ///
+            /// let d = Uniform::new(0u64, 1_000_000_007).unwrap();
+            /// let x = d.sample_ct(&mut rand::rng());
+            /// assert!(x < 1_000_000_007);
+            /// ```
+            ///
+            /// [`Distribution::sample`]: crate::distr::Distribution::sample
+            #[inline]
+            pub fn sample_ct<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                self.0.sample_ct(rng)
+            }
+        }
+    };
+}
+
+/// Compute `(x * (range_m1 + 1)) >> (64 * K)` for `K` random words `x`,
+/// using a fixed sequence of operations
+///
+/// The result is less than `range_m1 + 1`. For `range_m1 < 2^(64 * (K - 1))`,
+/// the bias of each result is below `2^-64`.
+#[inline]
+fn mul_shift_ct<const K: usize, R: Rng + ?Sized>(rng: &mut R, range_m1: u128) -> u128 {
+    let x: [u64; K] = core::array::from_fn(|_| rng.next_u64());
+    let m = [range_m1 as u64, (range_m1 >> 64) as u64];
+
+    // Schoolbook multiplication of x by m, plus x itself, in 64-bit limbs
+    let mut acc = [0u64; 5];
+    for (i, &xi) in x.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &mj) in m.iter().enumerate() {
+            let t = u128::from(xi) * u128::from(mj) + u128::from(acc[i + j]) + carry;
+            acc[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        acc[i + 2] = carry as u64;
+    }
+    let mut carry = 0u128;
+    for (i, limb) in acc.iter_mut().enumerate() {
+        let t = u128::from(*limb) + u128::from(x.get(i).copied().unwrap_or(0)) + carry;
+        *limb = t as u64;
+        carry = t >> 64;
+    }
+    u128::from(acc[K]) | (u128::from(acc[K + 1]) << 64)
+}
+
+/// Sample from `0..range` given `thresh = 2^128 % range`, unbiased
+///
+/// Ranges below `2^64` use 64-bit arithmetic. Ranges of at least `2^96`, for
+/// which a 128-bit sample would frequently be rejected, use Lemire's method
+/// with 192 bits of randomness; the threshold for rejection is then only
+/// needed (and computed) with probability less than `2^-64`.
+#[inline]
+fn sample_u128<R: Rng + ?Sized>(rng: &mut R, range: u128, thresh: u128) -> u128 {
+    if let Ok(range) = u64::try_from(range) {
+        return u128::from(sample_u64(rng, range));
+    }
+
+    if range >> 96 == 0 {
+        loop {
+            let (hi, lo) = rng.random::<u128>().wmul(range);
+            if lo >= thresh {
+                return hi;
+            }
+        }
+    }
+
+    loop {
+        let (hi, lo) = sample_u192(rng, range);
+        // Rejection requires lo < 2^192 % range < range
+        if lo >= range || lo >= thresh_u192(range, thresh) {
+            return hi;
+        }
+    }
+}
+
+/// Sample from `0..range`, Lemire's nearly divisionless method, unbiased
+#[inline]
+fn sample_u64<R: Rng + ?Sized>(rng: &mut R, range: u64) -> u64 {
+    let (mut hi, mut lo) = rng.next_u64().wmul(range);
+    if lo < range {
+        let thresh = range.wrapping_neg() % range;
+        while lo < thresh {
+            (hi, lo) = rng.next_u64().wmul(range);
+        }
+    }
+    hi
+}
+
+/// Multiply `range` by a random 192-bit fixed-point fraction
+///
+/// Returns the integer part and the 192-bit fractional part, the latter
+/// truncated to its low 128 bits, or saturated to `u128::MAX` when its top 64
+/// bits are non-zero. Since `range < 2^128`, both the full and the saturated
+/// fraction then satisfy `frac >= x` for any `x <= range`; thus comparisons
+/// against `range` and `2^192 % range`, as for rejection, are preserved.
+#[inline(always)]
+fn sample_u192<R: Rng + ?Sized>(rng: &mut R, range: u128) -> (u128, u128) {
+    // Partial products with 128-bit and 64-bit random words:
+    // range * (x * 2^64 + y) = hi * 2^192 + (lo + y_hi) * 2^64 + y_lo
+    let (hi, lo) = rng.random::<u128>().wmul(range);
+    let y = rng.next_u64();
+    let y_lo = (range as u64).wmul(y);
+    let y_hi = (range >> 64) * u128::from(y) + u128::from(y_lo.0);
+    let (mid, carry) = lo.overflowing_add(y_hi);
+    let frac = if mid >> 64 != 0 {
+        u128::MAX
+    } else {
+        (mid << 64) | u128::from(y_lo.1)
     };
+    (hi + u128::from(carry), frac)
+}
+
+/// Compute `2^192 % range` from `thresh = 2^128 % range`
+#[cold]
+fn thresh_u192(range: u128, mut thresh: u128) -> u128 {
+    for _ in 0..64 {
+        let (double, overflow) = thresh.overflowing_add(thresh);
+        thresh = if overflow || double >= range {
+            double.wrapping_sub(range)
+        } else {
+            double
+        };
+    }
+    thresh
+}
+
+/// Sample from `0..range`, Canon's method, biased
+///
+/// Ranges below `2^64` use 64-bit arithmetic. Larger ranges use 128 bits of
+/// randomness, or 192 bits for ranges of at least `2^96`, thus bias affects
+/// less than 1 in `2^64` samples.
+#[cfg(not(feature = "unbiased"))]
+#[inline]
+fn sample_single_u128<R: Rng + ?Sized>(rng: &mut R, range: u128) -> u128 {
+    if let Ok(range) = u64::try_from(range) {
+        return u128::from(UniformInt::<u64>::sample_single(0, range, rng).unwrap());
+    }
+
+    if range >> 96 == 0 {
+        let (mut result, lo) = rng.random::<u128>().wmul(range);
+        if lo > range.wrapping_neg() {
+            let (new_hi, _) = rng.random::<u128>().wmul(range);
+            result += lo.checked_add(new_hi).is_none() as u128;
+        }
+        return result;
+    }
+
+    sample_u192(rng, range).0
+}
+
+/// Sample from `0..range`, unbiased
+#[cfg(feature = "unbiased")]
+#[inline]
+fn sample_single_u128<R: Rng + ?Sized>(rng: &mut R, range: u128) -> u128 {
+    if let Ok(range) = u64::try_from(range) {
+        return u128::from(UniformInt::<u64>::sample_single(0, range, rng).unwrap());
+    }
+
+    loop {
+        let (hi, lo) = sample_u192(rng, range);
+        if lo >= range || lo >= thresh_u192(range, range.wrapping_neg() % range) {
+            return hi;
+        }
+    }
 }
 
 uniform_int_impl! { i8, u8, u32 }
@@ -283,7 +603,6 @@ macro_rules! uniform_simd_int_impl {
         #[cfg(feature = "simd_support")]
         impl<const LANES: usize> SampleUniform for Simd<$ty, LANES>
         where
-            LaneCount<LANES>: SupportedLaneCount,
             Simd<$unsigned, LANES>:
                 WideningMultiply<Output = (Simd<$unsigned, LANES>, Simd<$unsigned, LANES>)>,
             StandardUniform: Distribution<Simd<$unsigned, LANES>>,
@@ -294,7 +613,6 @@ macro_rules! uniform_simd_int_impl {
         #[cfg(feature = "simd_support")]
         impl<const LANES: usize> UniformSampler for UniformInt<Simd<$ty, LANES>>
         where
-            LaneCount<LANES>: SupportedLaneCount,
             Simd<$unsigned, LANES>:
                 WideningMultiply<Output = (Simd<$unsigned, LANES>, Simd<$unsigned, LANES>)>,
             StandardUniform: Distribution<Simd<$unsigned, LANES>>,
@@ -400,18 +718,16 @@ uniform_simd_int_impl! { (u8, i8), (u16, i16), (u32, i32), (u64, i64) }
 /// In part to optimise for this use-case, but mostly to ensure that results
 /// are portable across 32-bit and 64-bit architectures (as far as is possible),
 /// this implementation will use 32-bit sampling when possible.
+///
+/// # Serialization
+///
+/// With the `serde` feature, deserialization checks that the stored rejection
+/// threshold is consistent with the stored range, and whether 64-bit sampling
+/// is used. Values serialized on 32-bit may be deserialized on 64-bit; the
+/// reverse fails for samplers using 64-bit sampling.
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[cfg_attr(all(feature = "serde"), derive(Serialize))]
-// To be able to deserialize on 32-bit we need to replace this with a custom
-// implementation of the Deserialize trait, to be able to:
-// - panic when `mode64` is `true` on 32-bit,
-// - assign the default value to `mode64` when it's missing on 64-bit,
-// - panic when the `usize` fields are greater than `u32::MAX` on 32-bit.
-#[cfg_attr(
-    all(feature = "serde", target_pointer_width = "64"),
-    derive(Deserialize)
-)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct UniformUsize {
     /// The lowest possible value.
     low: usize,
@@ -421,46 +737,91 @@ pub struct UniformUsize {
     thresh: usize,
     /// Whether the largest possible value is greater than `u32::MAX`.
     #[cfg(target_pointer_width = "64")]
-    // Handle missing field when deserializing on 64-bit an object serialized
-    // on 32-bit. Can be removed when switching to a custom deserializer.
-    #[cfg_attr(feature = "serde", serde(default))]
     mode64: bool,
 }
 
+#[cfg(all(
+    feature = "serde",
+    any(target_pointer_width = "32", target_pointer_width = "64")
+))]
+impl<'de> Deserialize<'de> for UniformUsize {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "UniformUsize")]
+        struct Repr {
+            low: usize,
+            range: usize,
+            thresh: usize,
+            // Missing when serialized on 32-bit
+            #[serde(default)]
+            mode64: bool,
+        }
+
+        let Repr {
+            low,
+            range,
+            thresh,
+            mode64,
+        } = Repr::deserialize(deserializer)?;
+        if cfg!(target_pointer_width = "32") && mode64 {
+            return Err(serde::de::Error::custom(
+                "UniformUsize uses 64-bit sampling, which is not supported on 32-bit",
+            ));
+        }
+        let expected = if mode64 {
+            let range = range as u64;
+            if range > 0 {
+                (range.wrapping_neg() % range) as usize
+            } else {
+                0
+            }
+        } else {
+            let range32 = range as u32;
+            if range32 as usize != range {
+                return Err(serde::de::Error::custom(
+                    "UniformUsize range exceeds 32 bits without 64-bit sampling",
+                ));
+            }
+            if range32 > 0 {
+                (range32.wrapping_neg() % range32) as usize
+            } else {
+                0
+            }
+        };
+        if thresh != expected {
+            return Err(serde::de::Error::custom(
+                "UniformUsize threshold does not match range",
+            ));
+        }
+        Ok(UniformUsize {
+            low,
+            range,
+            thresh,
+            #[cfg(target_pointer_width = "64")]
+            mode64,
+        })
+    }
+}
+
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
 impl SampleUniform for usize {
     type Sampler = UniformUsize;
 }
 
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
-impl UniformSampler for UniformUsize {
-    type X = usize;
-
-    #[inline] // if the range is constant, this helps LLVM to do the
-    // calculations at compile-time.
-    fn new<B1, B2>(low_b: B1, high_b: B2) -> Result<Self, Error>
-    where
-        B1: SampleBorrow<Self::X> + Sized,
-        B2: SampleBorrow<Self::X> + Sized,
-    {
-        let low = *low_b.borrow();
-        let high = *high_b.borrow();
+impl UniformUsize {
+    /// Construct, as [`UniformSampler::new`], in const context
+    #[inline]
+    pub const fn new_const(low: usize, high: usize) -> Result<Self, Error> {
         if !(low < high) {
             return Err(Error::EmptyRange);
         }
-
-        UniformSampler::new_inclusive(low, high - 1)
+        Self::new_inclusive_const(low, high - 1)
     }
 
-    #[inline] // if the range is constant, this helps LLVM to do the
-    // calculations at compile-time.
-    fn new_inclusive<B1, B2>(low_b: B1, high_b: B2) -> Result<Self, Error>
-    where
-        B1: SampleBorrow<Self::X> + Sized,
-        B2: SampleBorrow<Self::X> + Sized,
-    {
-        let low = *low_b.borrow();
-        let high = *high_b.borrow();
+    /// Construct, as [`UniformSampler::new_inclusive`], in const context
+    #[inline]
+    pub const fn new_inclusive_const(low: usize, high: usize) -> Result<Self, Error> {
         if !(low <= high) {
             return Err(Error::EmptyRange);
         }
@@ -496,6 +857,57 @@ impl UniformSampler for UniformUsize {
             mode64,
         })
     }
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl super::Uniform<usize> {
+    /// Create a new `Uniform` instance, as [`Uniform::new`], in const context
+    ///
+    /// [`Uniform::new`]: super::Uniform::new
+    #[inline]
+    pub const fn new_const(low: usize, high: usize) -> Result<Self, Error> {
+        match UniformUsize::new_const(low, high) {
+            Ok(sampler) => Ok(super::Uniform(sampler)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Create a new `Uniform` instance, as [`Uniform::new_inclusive`], in
+    /// const context
+    ///
+    /// [`Uniform::new_inclusive`]: super::Uniform::new_inclusive
+    #[inline]
+    pub const fn new_inclusive_const(low: usize, high: usize) -> Result<Self, Error> {
+        match UniformUsize::new_inclusive_const(low, high) {
+            Ok(sampler) => Ok(super::Uniform(sampler)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl UniformSampler for UniformUsize {
+    type X = usize;
+
+    #[inline] // if the range is constant, this helps LLVM to do the
+    // calculations at compile-time.
+    fn new<B1, B2>(low_b: B1, high_b: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self::new_const(*low_b.borrow(), *high_b.borrow())
+    }
+
+    #[inline] // if the range is constant, this helps LLVM to do the
+    // calculations at compile-time.
+    fn new_inclusive<B1, B2>(low_b: B1, high_b: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self::new_inclusive_const(*low_b.borrow(), *high_b.borrow())
+    }
 
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
@@ -692,6 +1104,170 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_thresh_u192() {
+        for (range, thresh) in [
+            (0x1_0000_0000_0000_0001, 0x1_0000_0000_0000_0000),
+            (3 << 126, 1 << 126),
+            (
+                0x5555_5555_5555_5555_5555_5555_5555_5556,
+                0x5555_5555_5555_5553_5555_5555_5555_5556,
+            ),
+            (u128::MAX, 0x1_0000_0000_0000_0000),
+            (
+                0x10_0000_0000_0000_0000_0000_3039,
+                0xc_7000_0000_0000_0000_0009_3ae9,
+            ),
+        ] {
+            assert_eq!(thresh_u192(range, range.wrapping_neg() % range), thresh);
+        }
+    }
+
+    #[test]
+    fn test_u192_rejection() {
+        // An RNG yielding the given words
+        struct SeqRng<'a>(core::slice::Iter<'a, u64>);
+        impl crate::RngCore for SeqRng<'_> {
+            fn next_u32(&mut self) -> u32 {
+                self.next_u64() as u32
+            }
+            fn next_u64(&mut self) -> u64 {
+                *self.0.next().unwrap()
+            }
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                rand_core::impls::fill_bytes_via_next(self, dst)
+            }
+        }
+
+        let range: u128 = 0x10_0000_0000_0000_0000_0000_3039;
+        let thresh = range.wrapping_neg() % range;
+        // The fraction of the first sample is at least 2^64 but less than
+        // 2^192 % range, thus rejected; the second sample is 0
+        let words = [0xea89_7cbb_e090_0000, 0x6458_a6ba_031c_4e62, 1, 0, 0, 1];
+        let (_, frac) = sample_u192(&mut SeqRng(words.iter()), range);
+        assert_eq!(frac, (1 << 84) | 0x3039);
+        assert!(frac < thresh_u192(range, thresh));
+
+        let mut rng = SeqRng(words.iter());
+        assert_eq!(sample_u128(&mut rng, range, thresh), 0);
+        assert!(rng.0.next().is_none());
+        #[cfg(feature = "unbiased")]
+        assert_eq!(sample_single_u128(&mut SeqRng(words.iter()), range), 0);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_u128_large_range() {
+        let mut rng = crate::test::rng(898);
+        // Ranges for 128-bit and 192-bit sampling
+        for shift in [94, 126] {
+            let high = (3 << shift) - 1;
+            let distr = Uniform::new_inclusive(0u128, high).unwrap();
+            let mut counts = [[0; 3]; 2];
+            for _ in 0..30000 {
+                counts[0][(distr.sample(&mut rng) >> shift) as usize] += 1;
+                counts[1][(rng.random_range(0..=high) >> shift) as usize] += 1;
+            }
+            for count in counts.iter().flatten() {
+                assert!((9600..=10400).contains(count), "{:?}", counts);
+            }
+
+            let mut max_rng = crate::test::const_rng(u64::MAX);
+            assert_eq!(distr.sample(&mut max_rng), high);
+            assert_eq!(max_rng.random_range(0..=high), high);
+        }
+    }
+
+    #[test]
+    fn test_sample_ct() {
+        macro_rules! check {
+            ($low:expr, $high:expr, $bucket:expr) => {{
+                let distr = Uniform::new_inclusive($low, $high).unwrap();
+                let mut rng = crate::test::rng(899);
+                let mut counts = [0; 4];
+                for _ in 0..4000 {
+                    let x = distr.sample_ct(&mut rng);
+                    assert!(($low..=$high).contains(&x));
+                    counts[$bucket(x)] += 1;
+                }
+                for count in counts {
+                    assert!((900..=1100).contains(&count), "{:?}", counts);
+                }
+                assert_eq!(distr.sample_ct(&mut crate::test::const_rng(0)), $low);
+                assert_eq!(
+                    distr.sample_ct(&mut crate::test::const_rng(u64::MAX)),
+                    $high
+                );
+            }};
+        }
+
+        check!(-2i8, 1, |x| (x + 2) as usize);
+        check!(0u16, 399, |x| x as usize / 100);
+        check!(u32::MIN, u32::MAX, |x| (x >> 30) as usize);
+        check!(10u64, 13, |x| x as usize - 10);
+        check!(i64::MIN, i64::MAX, |x| ((x as u64) >> 62) as usize);
+        check!(0u128, (1 << 100) - 1, |x| (x >> 98) as usize);
+        check!(i128::MIN, i128::MAX, |x| ((x as u128) >> 126) as usize);
+    }
+
+    #[test]
+    fn test_sample_fill() {
+        macro_rules! check {
+            ($low:expr, $high:expr, $bucket:expr) => {{
+                let distr = Uniform::new_inclusive($low, $high).unwrap();
+                let mut rng = crate::test::rng(900);
+                // Not a multiple of the batch size
+                let mut buf = [$low; 4001];
+                distr.sample_fill(&mut rng, &mut buf);
+                let mut counts = [0; 4];
+                for x in buf {
+                    assert!(($low..=$high).contains(&x));
+                    counts[$bucket(x)] += 1;
+                }
+                for count in counts {
+                    assert!((900..=1100).contains(&count), "{:?}", counts);
+                }
+
+                // Values equal those of repeated calls to `sample`
+                let mut rng = crate::test::rng(900);
+                assert!(buf.iter().all(|&x| x == distr.sample(&mut rng)));
+            }};
+        }
+
+        check!(-2i8, 1, |x| (x + 2) as usize);
+        check!(0u16, 399, |x| x as usize / 100);
+        check!(u32::MIN, u32::MAX, |x| (x >> 30) as usize);
+        check!(10u64, 13, |x| x as usize - 10);
+        check!(0u128, (1 << 100) - 1, |x| (x >> 98) as usize);
+    }
+
+    #[test]
+    fn test_new_const() {
+        const DIE: Uniform<u8> = match Uniform::<u8>::new_inclusive_const(1, 6) {
+            Ok(distr) => distr,
+            Err(_) => panic!(),
+        };
+        static INDEX: Result<Uniform<usize>, Error> =
+            Uniform::<usize>::new_const(0, usize::MAX / 2);
+        assert_eq!(DIE, Uniform::new_inclusive(1, 6).unwrap());
+        assert_eq!(INDEX, Uniform::new(0, usize::MAX / 2));
+
+        assert_eq!(Uniform::<i8>::new_const(-3, 7), Uniform::new(-3, 7));
+        assert_eq!(
+            Uniform::<i128>::new_inclusive_const(i128::MIN, i128::MAX),
+            Uniform::new_inclusive(i128::MIN, i128::MAX)
+        );
+        assert_eq!(
+            Uniform::<usize>::new_inclusive_const(3, 3),
+            Uniform::new_inclusive(3, 3)
+        );
+        assert_eq!(Uniform::<u32>::new_const(5, 5), Err(Error::EmptyRange));
+        assert_eq!(
+            Uniform::<usize>::new_inclusive_const(5, 4),
+            Err(Error::EmptyRange)
+        );
+    }
+
     #[test]
     fn test_uniform_from_std_range() {
         let r = Uniform::try_from(2u32..7).unwrap();
@@ -776,18 +1352,18 @@ mod tests {
             0,
             &[
                 -30725222750250982319765550926688025855,
-                -75088619368053423329503924805178012357,
-                -64950748766625548510467638647674468829,
+                -68757090514612910340228473298135257484,
                 -41794017901603587121582892414659436495,
-                -63623852319608406524605295913876414006,
-                -17404679390297612013597359206379189023,
+                -136507184425384233828432438713770642796,
+                -26528313350672880401748531716975559727,
+                -29106726141593452051151656407260679619,
             ],
         );
         test_samples(11u8, 218, 219, &[17, 66, 214, 181, 93, 165]);
         test_samples(11u16, 218, 219, &[17, 66, 214, 181, 93, 165]);
         test_samples(11u32, 218, 219, &[17, 66, 214, 181, 93, 165]);
         test_samples(11u64, 218, 219, &[66, 181, 165, 127, 134, 139]);
-        test_samples(11u128, 218, 219, &[181, 127, 139, 167, 141, 197]);
+        test_samples(11u128, 218, 219, &[66, 181, 165, 127, 134, 139]);
         test_samples(11usize, 218, 219, &[17, 66, 214, 181, 93, 165]);
 
         #[cfg(feature = "simd_support")]
@@ -864,8 +1440,7 @@ mod tests {
         );
     }
 
-    // This could be run also on 32-bit when deserialization is implemented.
-    #[cfg(all(feature = "serde", target_pointer_width = "64"))]
+    #[cfg(feature = "serde")]
     #[test]
     fn test_uniform_usize_deserialization() {
         use serde_json;
@@ -876,7 +1451,7 @@ mod tests {
         assert_eq!(deserialized, original);
     }
 
-    #[cfg(all(feature = "serde", target_pointer_width = "64"))]
+    #[cfg(feature = "serde")]
     #[test]
     fn test_uniform_usize_deserialization_from_32bit() {
         use serde_json;
@@ -900,4 +1475,24 @@ mod tests {
             serde_json::from_str(&serialized).expect("deserialization");
         assert_eq!(deserialized, original);
     }
+
+    #[cfg(all(feature = "serde", target_pointer_width = "64"))]
+    #[test]
+    fn test_uniform_usize_deserialization_invalid() {
+        use serde_json;
+        for json in [
+            r#"{"low":10,"range":91,"thresh":0}"#,
+            r#"{"low":10,"range":91,"thresh":74,"mode64":true}"#,
+            r#"{"low":0,"range":4294967297,"thresh":0}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<UniformUsize>(json).is_err(),
+                "{json}"
+            );
+        }
+        let original = UniformUsize::new_inclusive(1, u64::MAX as usize - 1).expect("creation");
+        let serialized = serde_json::to_string(&original).expect("serialization");
+        let invalid = serialized.replace(r#""mode64":true"#, r#""mode64":false"#);
+        assert!(serde_json::from_str::<UniformUsize>(&invalid).is_err());
+    }
 }
@@ -7,11 +7,17 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! `UniformChar`, `UniformDuration` implementations
+//! `UniformChar`, `UniformDuration`, `UniformNonZero`, `UniformIpv4Addr`,
+//! `UniformIpv6Addr` implementations
 
 use super::{Error, SampleBorrow, SampleUniform, Uniform, UniformInt, UniformSampler};
 use crate::Rng;
 use crate::distr::Distribution;
+use core::net::{Ipv4Addr, Ipv6Addr};
+use core::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroU8, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU128,
+};
 use core::time::Duration;
 
 #[cfg(feature = "serde")]
@@ -31,11 +37,30 @@ impl SampleUniform for char {
 /// valid Unicode code points. We must therefore avoid sampling values in this
 /// range.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct UniformChar {
     sampler: UniformInt<u32>,
 }
 
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for UniformChar {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "UniformChar")]
+        struct Repr {
+            sampler: UniformInt<u32>,
+        }
+
+        // Sampling assumes that all values of the range map to a `char`
+        let Repr { sampler } = Repr::deserialize(deserializer)?;
+        let high = u64::from(sampler.low) + u64::from(sampler.range);
+        if sampler.range == 0 || high > u64::from(char_to_comp_u32(char::MAX)) + 1 {
+            return Err(serde::de::Error::custom("UniformChar range is not valid"));
+        }
+        Ok(UniformChar { sampler })
+    }
+}
+
 /// UTF-16 surrogate range start
 const CHAR_SURROGATE_START: u32 = 0xD800;
 /// UTF-16 surrogate range size
@@ -114,13 +139,78 @@ impl crate::distr::SampleString for Uniform<char> {
 ///
 /// Unless you are implementing [`UniformSampler`] for your own types, this type
 /// should not be used directly, use [`Uniform`] instead.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+/// use rand::Rng;
+///
+/// // Retry after a random delay
+/// let delay = rand::rng().random_range(Duration::from_millis(100)..Duration::from_millis(500));
+/// assert!(Duration::from_millis(100) <= delay && delay < Duration::from_millis(500));
+/// ```
+///
+/// # Serialization
+///
+/// With the `serde` feature, deserialization checks that all sampled values
+/// are representable as a `Duration`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct UniformDuration {
     mode: UniformDurationMode,
     offset: u32,
 }
 
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for UniformDuration {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "UniformDuration")]
+        struct Repr {
+            mode: UniformDurationMode,
+            offset: u32,
+        }
+
+        /// The inclusive bounds of the values sampled by `sampler`, unless
+        /// these wrap around
+        fn bounds<X: Into<u64> + Copy>(sampler: &UniformInt<X>, max: u64) -> Option<(u64, u64)> {
+            let (low, range) = (sampler.low.into(), sampler.range.into());
+            if range == 0 {
+                return Some((0, max));
+            }
+            let high = low.checked_add(range - 1)?;
+            (high <= max).then_some((low, high))
+        }
+
+        let Repr { mode, offset } = Repr::deserialize(deserializer)?;
+        let valid = match mode {
+            UniformDurationMode::Small { nanos, .. } => {
+                matches!(bounds(&nanos.0, u32::MAX.into()), Some((_, high)) if high < 1_000_000_000)
+            }
+            UniformDurationMode::Medium { .. } => true,
+            UniformDurationMode::Large {
+                max_secs,
+                max_nanos,
+                secs,
+            } => {
+                // Sampled nanoseconds may carry into the seconds, except for
+                // those of `max_secs`
+                offset < 1_000_000_000
+                    && max_nanos < 1_000_000_000
+                    && (max_secs < u64::MAX || offset + max_nanos < 1_000_000_000)
+                    && matches!(bounds(&secs.0, u64::MAX), Some((_, high)) if high == max_secs)
+            }
+        };
+        if !valid {
+            return Err(serde::de::Error::custom(
+                "UniformDuration range is not valid",
+            ));
+        }
+        Ok(UniformDuration { mode, offset })
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 enum UniformDurationMode {
@@ -243,6 +333,215 @@ impl UniformSampler for UniformDuration {
     }
 }
 
+/// The back-end implementing [`UniformSampler`] for `NonZero` integers.
+///
+/// Unless you are implementing [`UniformSampler`] for your own types, this type
+/// should not be used directly, use [`Uniform`] instead.
+///
+/// Similar to [`UniformChar`], the value zero is removed from the range
+/// before sampling. All non-zero values of a range, which may include both
+/// negative and positive values, are therefore equally likely.
+///
+/// # Serialization
+///
+/// With the `serde` feature, deserialization checks that the range does not
+/// include zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "UniformInt<X>: Serialize"))
+)]
+pub struct UniformNonZero<X> {
+    // Samples values in the range with positive values decreased by one
+    sampler: UniformInt<X>,
+}
+
+macro_rules! uniform_nonzero_impl {
+    ($ty:ty, $nz:ty, unsigned) => {
+        uniform_nonzero_impl!($ty, $nz, |x: $ty| x - 1, |y: $ty| y + 1);
+    };
+    ($ty:ty, $nz:ty, signed) => {
+        uniform_nonzero_impl!(
+            $ty,
+            $nz,
+            |x: $ty| if x > 0 { x - 1 } else { x },
+            |y: $ty| if y >= 0 { y + 1 } else { y }
+        );
+    };
+    ($ty:ty, $nz:ty, $compress:expr, $expand:expr) => {
+        impl SampleUniform for $nz {
+            type Sampler = UniformNonZero<$ty>;
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> Deserialize<'de> for UniformNonZero<$ty> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(Deserialize)]
+                #[serde(rename = "UniformNonZero")]
+                struct Repr {
+                    sampler: UniformInt<$ty>,
+                }
+
+                // The compressed range must neither wrap around nor include
+                // the maximum value, which would expand to zero
+                let Repr { sampler } = Repr::deserialize(deserializer)?;
+                let high = sampler.low.wrapping_add(sampler.range.wrapping_sub(1));
+                if sampler.range == 0 || high < sampler.low || high == <$ty>::MAX {
+                    return Err(serde::de::Error::custom(
+                        "UniformNonZero range is not valid",
+                    ));
+                }
+                Ok(UniformNonZero { sampler })
+            }
+        }
+
+        impl UniformSampler for UniformNonZero<$ty> {
+            type X = $nz;
+
+            #[inline] // if the range is constant, this helps LLVM to do the
+            // calculations at compile-time.
+            fn new<B1, B2>(low_b: B1, high_b: B2) -> Result<Self, Error>
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let low = $compress(low_b.borrow().get());
+                let high = $compress(high_b.borrow().get());
+                let sampler = UniformInt::<$ty>::new(low, high);
+                sampler.map(|sampler| UniformNonZero { sampler })
+            }
+
+            #[inline] // if the range is constant, this helps LLVM to do the
+            // calculations at compile-time.
+            fn new_inclusive<B1, B2>(low_b: B1, high_b: B2) -> Result<Self, Error>
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let low = $compress(low_b.borrow().get());
+                let high = $compress(high_b.borrow().get());
+                let sampler = UniformInt::<$ty>::new_inclusive(low, high);
+                sampler.map(|sampler| UniformNonZero { sampler })
+            }
+
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                // The compressed range excludes the maximum value, hence
+                // this does not overflow and the result is non-zero.
+                <$nz>::new($expand(self.sampler.sample(rng))).unwrap()
+            }
+        }
+    };
+}
+
+uniform_nonzero_impl!(u8, NonZeroU8, unsigned);
+uniform_nonzero_impl!(u16, NonZeroU16, unsigned);
+uniform_nonzero_impl!(u32, NonZeroU32, unsigned);
+uniform_nonzero_impl!(u64, NonZeroU64, unsigned);
+uniform_nonzero_impl!(u128, NonZeroU128, unsigned);
+
+uniform_nonzero_impl!(i8, NonZeroI8, signed);
+uniform_nonzero_impl!(i16, NonZeroI16, signed);
+uniform_nonzero_impl!(i32, NonZeroI32, signed);
+uniform_nonzero_impl!(i64, NonZeroI64, signed);
+uniform_nonzero_impl!(i128, NonZeroI128, signed);
+
+/// The back-end implementing [`UniformSampler`] for `Ipv4Addr`.
+///
+/// Unless you are implementing [`UniformSampler`] for your own types, this type
+/// should not be used directly, use [`Uniform`] instead.
+///
+/// Addresses are ordered and sampled as their `u32` representation, as given
+/// by [`Ipv4Addr::to_bits`]. See also [`Uniform::<Ipv4Addr>::new_prefix`] for
+/// sampling addresses within a network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UniformIpv4Addr {
+    sampler: UniformInt<u32>,
+}
+
+/// The back-end implementing [`UniformSampler`] for `Ipv6Addr`.
+///
+/// Unless you are implementing [`UniformSampler`] for your own types, this type
+/// should not be used directly, use [`Uniform`] instead.
+///
+/// Addresses are ordered and sampled as their `u128` representation, as given
+/// by [`Ipv6Addr::to_bits`]. See also [`Uniform::<Ipv6Addr>::new_prefix`] for
+/// sampling addresses within a network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UniformIpv6Addr {
+    sampler: UniformInt<u128>,
+}
+
+macro_rules! uniform_ip_impl {
+    ($ip:ident, $sampler:ident, $ty:ty) => {
+        impl SampleUniform for $ip {
+            type Sampler = $sampler;
+        }
+
+        impl UniformSampler for $sampler {
+            type X = $ip;
+
+            #[inline]
+            fn new<B1, B2>(low_b: B1, high_b: B2) -> Result<Self, Error>
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let low = low_b.borrow().to_bits();
+                let high = high_b.borrow().to_bits();
+                let sampler = UniformInt::<$ty>::new(low, high);
+                sampler.map(|sampler| $sampler { sampler })
+            }
+
+            #[inline]
+            fn new_inclusive<B1, B2>(low_b: B1, high_b: B2) -> Result<Self, Error>
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let low = low_b.borrow().to_bits();
+                let high = high_b.borrow().to_bits();
+                let sampler = UniformInt::<$ty>::new_inclusive(low, high);
+                sampler.map(|sampler| $sampler { sampler })
+            }
+
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                $ip::from_bits(self.sampler.sample(rng))
+            }
+        }
+
+        impl Uniform<$ip> {
+            /// Sample addresses of the network `addr/prefix_len`
+            ///
+            /// The result samples uniformly from all addresses sharing their
+            /// first `prefix_len` bits with `addr`, including the network and
+            /// broadcast addresses. The remaining bits of `addr` are ignored.
+            ///
+            /// # Errors
+            ///
+            #[doc = concat!("Fails with [`Error::InvalidPrefix`] if `prefix_len > ", stringify!($ty), "::BITS`.")]
+            pub fn new_prefix(addr: $ip, prefix_len: u32) -> Result<Self, Error> {
+                if prefix_len > <$ty>::BITS {
+                    return Err(Error::InvalidPrefix);
+                }
+                let mask = <$ty>::MAX
+                    .checked_shl(<$ty>::BITS - prefix_len)
+                    .unwrap_or(0);
+                let low = addr.to_bits() & mask;
+                let sampler = UniformInt::<$ty>::new_inclusive(low, low | !mask)?;
+                Ok(Uniform($sampler { sampler }))
+            }
+        }
+    };
+}
+
+uniform_ip_impl!(Ipv4Addr, UniformIpv4Addr, u32);
+uniform_ip_impl!(Ipv6Addr, UniformIpv6Addr, u128);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,6 +555,83 @@ mod tests {
         assert_eq!(distr, de_distr);
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serialization_uniform_duration_invalid() {
+        let small = UniformDuration {
+            mode: UniformDurationMode::Small {
+                secs: 1,
+                nanos: Uniform::new(0, 2_000_000_000).unwrap(),
+            },
+            offset: 0,
+        };
+        let large = |max_secs, max_nanos, offset, secs| UniformDuration {
+            mode: UniformDurationMode::Large {
+                max_secs,
+                max_nanos,
+                secs: Uniform::new_inclusive(0, secs).unwrap(),
+            },
+            offset,
+        };
+        for distr in [
+            small,
+            large(u64::MAX, 0, u32::MAX, u64::MAX),
+            large(u64::MAX, 500_000_000, 500_000_000, u64::MAX),
+            large(10, 0, 0, 20),
+        ] {
+            let json = serde_json::to_string(&distr).unwrap();
+            assert!(serde_json::from_str::<UniformDuration>(&json).is_err());
+        }
+
+        let distr = UniformDuration::new_inclusive(Duration::ZERO, Duration::MAX).unwrap();
+        assert!(matches!(distr.mode, UniformDurationMode::Large { .. }));
+        let json = serde_json::to_string(&distr).unwrap();
+        assert_eq!(
+            serde_json::from_str::<UniformDuration>(&json).unwrap(),
+            distr
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serialization_uniform_nonzero() {
+        let distr = UniformNonZero::new_inclusive(NonZeroI8::MIN, NonZeroI8::MAX).unwrap();
+        let json = serde_json::to_string(&distr).unwrap();
+        assert_eq!(
+            serde_json::from_str::<UniformNonZero<i8>>(&json).unwrap(),
+            distr
+        );
+
+        for (low, high) in [(0, 255), (1, 255), (0, 0)] {
+            let sampler = UniformInt::<u8>::new_inclusive(low, high).unwrap();
+            let json = serde_json::to_string(&UniformNonZero { sampler }).unwrap();
+            assert_eq!(
+                serde_json::from_str::<UniformNonZero<u8>>(&json).is_ok(),
+                high < 255
+            );
+        }
+        let mut sampler = UniformInt::<i8>::new_inclusive(-100, 99).unwrap();
+        sampler.low = 50;
+        let json = serde_json::to_string(&UniformNonZero { sampler }).unwrap();
+        assert!(serde_json::from_str::<UniformNonZero<i8>>(&json).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serialization_uniform_char() {
+        let distr = UniformChar::new_inclusive('\u{3040}', char::MAX).unwrap();
+        let json = serde_json::to_string(&distr).unwrap();
+        let de_distr: UniformChar = serde_json::from_str(&json).unwrap();
+        assert_eq!(distr, de_distr);
+
+        let sampler = UniformInt::<u32>::new_inclusive(0x3040, 0x10F800).unwrap();
+        let json = serde_json::to_string(&UniformChar { sampler }).unwrap();
+        assert!(serde_json::from_str::<UniformChar>(&json).is_err());
+        let sampler = UniformInt::<u32>::new_inclusive(0, u32::MAX).unwrap();
+        let json = serde_json::to_string(&UniformChar { sampler }).unwrap();
+        assert!(serde_json::from_str::<UniformChar>(&json).is_err());
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_char() {
@@ -267,6 +643,21 @@ mod tests {
             max = max.max(c);
         }
         assert_eq!(max, 'Z');
+        // The only values spanning the surrogate gap are equally likely
+        let mut low = 0;
+        for _ in 0..1000 {
+            match rng.random_range('\u{D7FF}'..='\u{E000}') {
+                '\u{D7FF}' => low += 1,
+                c => assert_eq!(c, '\u{E000}'),
+            }
+        }
+        assert!((430..=570).contains(&low));
+        assert_eq!(rng.random_range('\u{D7FF}'..'\u{E000}'), '\u{D7FF}');
+        let kana = Uniform::new_inclusive('\u{3040}', '\u{30ff}').unwrap();
+        for _ in 0..100 {
+            let c = kana.sample(&mut rng);
+            assert!(('\u{3040}'..='\u{30ff}').contains(&c));
+        }
         let d = Uniform::new(
             core::char::from_u32(0xD7F0).unwrap(),
             core::char::from_u32(0xE010).unwrap(),
@@ -315,5 +706,107 @@ mod tests {
                 assert!(low <= v && v < high);
             }
         }
+
+        // Samples must be carried across whole seconds
+        let (low, high) = (Duration::from_millis(900), Duration::from_millis(1100));
+        let mut below = 0;
+        for _ in 0..1000 {
+            let v = rng.random_range(low..high);
+            assert!(low <= v && v < high);
+            if v.as_secs() == 0 {
+                below += 1;
+            }
+        }
+        assert!((400..=600).contains(&below));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_nonzero() {
+        let mut rng = crate::test::rng(254);
+
+        let nz = |x| NonZeroI8::new(x).unwrap();
+        let mut counts = [0; 5];
+        for _ in 0..1000 {
+            let x = rng.random_range(nz(-2)..=nz(2)).get();
+            counts[(x + 2) as usize] += 1;
+        }
+        assert_eq!(counts[2], 0);
+        for i in [0, 1, 3, 4] {
+            assert!((200..=300).contains(&counts[i]));
+        }
+
+        for _ in 0..100 {
+            let x = rng.random_range(nz(-3)..nz(1)).get();
+            assert!((-3..0).contains(&x));
+            let x = rng.random_range(nz(i8::MIN)..=nz(i8::MAX)).get();
+            assert_ne!(x, 0);
+        }
+        assert!(Uniform::new(nz(-1), nz(1)).is_ok());
+        assert!(Uniform::new(nz(1), nz(1)).is_err());
+        assert!(Uniform::new_inclusive(nz(2), nz(1)).is_err());
+
+        let max = NonZeroU32::MAX;
+        let d = Uniform::new_inclusive(NonZeroU32::MIN, max).unwrap();
+        for _ in 0..100 {
+            d.sample(&mut rng);
+        }
+        let x = rng.random_range(NonZeroU32::new(7).unwrap()..NonZeroU32::new(8).unwrap());
+        assert_eq!(x.get(), 7);
+        assert_eq!(rng.random_range(max..=max), max);
+    }
+
+    #[test]
+    fn test_ip_addrs() {
+        let mut rng = crate::test::rng(255);
+
+        let (low, high) = (Ipv4Addr::new(10, 0, 0, 250), Ipv4Addr::new(10, 0, 1, 5));
+        for _ in 0..100 {
+            let addr = rng.random_range(low..high);
+            assert!(low <= addr && addr < high);
+        }
+        assert!(Uniform::new(high, low).is_err());
+
+        let net = Uniform::<Ipv4Addr>::new_prefix(Ipv4Addr::new(192, 168, 37, 200), 30).unwrap();
+        let mut seen = [false; 4];
+        for _ in 0..100 {
+            let [a, b, c, d] = net.sample(&mut rng).octets();
+            assert_eq!([a, b, c], [192, 168, 37]);
+            assert!((200..204).contains(&d));
+            seen[usize::from(d - 200)] = true;
+        }
+        assert_eq!(seen, [true; 4]);
+        let host = Ipv4Addr::new(1, 2, 3, 4);
+        assert_eq!(
+            Uniform::<Ipv4Addr>::new_prefix(host, 32)
+                .unwrap()
+                .sample(&mut rng),
+            host
+        );
+        Uniform::<Ipv4Addr>::new_prefix(host, 0)
+            .unwrap()
+            .sample(&mut rng);
+
+        let net =
+            Uniform::<Ipv6Addr>::new_prefix(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32)
+                .unwrap();
+        for _ in 0..100 {
+            let addr = net.sample(&mut rng);
+            assert_eq!(addr.segments()[..2], [0x2001, 0xdb8]);
+        }
+        let uniform = Uniform::new_inclusive(Ipv6Addr::LOCALHOST, Ipv6Addr::LOCALHOST).unwrap();
+        assert_eq!(uniform.sample(&mut rng), Ipv6Addr::LOCALHOST);
+    }
+
+    #[test]
+    fn test_ip_prefix_too_long() {
+        assert_eq!(
+            Uniform::<Ipv4Addr>::new_prefix(Ipv4Addr::LOCALHOST, 33),
+            Err(Error::InvalidPrefix)
+        );
+        assert_eq!(
+            Uniform::<Ipv6Addr>::new_prefix(Ipv6Addr::LOCALHOST, 129),
+            Err(Error::InvalidPrefix)
+        );
     }
 }
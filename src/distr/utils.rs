@@ -9,9 +9,9 @@
 //! Math helper functions
 
 #[cfg(feature = "simd_support")]
-use core::simd::prelude::*;
+use core::simd::SimdElement;
 #[cfg(feature = "simd_support")]
-use core::simd::{LaneCount, SimdElement, SupportedLaneCount};
+use core::simd::prelude::*;
 
 pub(crate) trait WideningMultiply<RHS = Self> {
     type Output;
@@ -180,7 +180,10 @@ mod simd_wmul {
 
                 #[inline(always)]
                 fn wmul(self, x: $ty) -> Self::Output {
+                    // SAFETY: the macro is only used where the target
+                    // feature required by the intrinsics is enabled.
                     let hi = unsafe { $mulhi(self.into(), x.into()) }.into();
+                    // SAFETY: as above.
                     let lo = unsafe { $mullo(self.into(), x.into()) }.into();
                     (hi, lo)
                 }
@@ -257,6 +260,8 @@ pub(crate) trait IntAsSIMD: Sized {
     }
 }
 
+#[cfg(feature = "f16")]
+impl IntAsSIMD for u16 {}
 impl IntAsSIMD for u32 {}
 impl IntAsSIMD for u64 {}
 
@@ -330,16 +335,15 @@ macro_rules! scalar_float_impl {
     };
 }
 
+#[cfg(feature = "f16")]
+scalar_float_impl!(f16, u16);
 scalar_float_impl!(f32, u32);
 scalar_float_impl!(f64, u64);
 
 #[cfg(feature = "simd_support")]
 macro_rules! simd_impl {
     ($fty:ident, $uty:ident) => {
-        impl<const LANES: usize> FloatSIMDUtils for Simd<$fty, LANES>
-        where
-            LaneCount<LANES>: SupportedLaneCount,
-        {
+        impl<const LANES: usize> FloatSIMDUtils for Simd<$fty, LANES> {
             type Mask = Mask<<$fty as SimdElement>::Mask, LANES>;
             type UInt = Simd<$uty, LANES>;
 
@@ -372,7 +376,7 @@ macro_rules! simd_impl {
                 // value representable by $fty. This works even when the
                 // current value is infinity.
                 debug_assert!(mask.any(), "At least one lane must be set");
-                Self::from_bits(self.to_bits() + mask.to_int().cast())
+                Self::from_bits(self.to_bits() + mask.to_simd().cast())
             }
 
             #[inline]
@@ -382,10 +386,7 @@ macro_rules! simd_impl {
         }
 
         #[cfg(test)]
-        impl<const LANES: usize> FloatSIMDScalarUtils for Simd<$fty, LANES>
-        where
-            LaneCount<LANES>: SupportedLaneCount,
-        {
+        impl<const LANES: usize> FloatSIMDScalarUtils for Simd<$fty, LANES> {
             type Scalar = $fty;
 
             #[inline]
@@ -406,3 +407,207 @@ macro_rules! simd_impl {
 simd_impl!(f32, u32);
 #[cfg(feature = "simd_support")]
 simd_impl!(f64, u64);
+
+/// Round `x` towards negative infinity
+///
+/// This is a `core`-only replacement of `f64::floor`, except for returning
+/// `0.0` in place of `-0.0`.
+pub(crate) fn floor(x: f64) -> f64 {
+    // Magnitudes of at least 2^52 are integral, as are infinities; NaN passes
+    if !(x.abs() < 4503599627370496.0) {
+        return x;
+    }
+    let t = x as i64 as f64;
+    if t > x { t - 1.0 } else { t }
+}
+
+/// The square root of `x`
+///
+/// This is a `core`-only replacement of `f64::sqrt`, accurate to one ulp.
+pub(crate) fn sqrt(x: f64) -> f64 {
+    if !(x > 0.0) || x == f64::INFINITY {
+        // NaN for NaN or negative input; preserves 0 and infinity
+        return if x == 0.0 || x == f64::INFINITY {
+            x
+        } else {
+            f64::NAN
+        };
+    }
+    if x < f64::MIN_POSITIVE {
+        // Scale subnormals by 2^108, then the result by 2^-54
+        return sqrt(x * 3.2451855365842673e32) * 5.551115123125783e-17;
+    }
+    // Halving the exponent gives an estimate within 6%; each Newton step
+    // squares the relative error
+    let mut y = f64::from_bits((x.to_bits() + (1023 << 52)) >> 1);
+    for _ in 0..5 {
+        y = 0.5 * (y + x / y);
+    }
+    y
+}
+
+/// `ln(2)` split such that `k * LN_2_HI` is exact for `|k| < 2^20` (as in
+/// fdlibm), with `LN_2_LO` the remainder
+const LN_2_HI: f64 = 0.6931471803691238;
+const LN_2_LO: f64 = 1.9082149292705877e-10;
+
+/// The natural logarithm of `x`
+///
+/// This is a `core`-only replacement of `f64::ln`, accurate to a few ulps.
+pub(crate) fn ln(x: f64) -> f64 {
+    if !(x > 0.0) || x == f64::INFINITY {
+        return if x == 0.0 {
+            f64::NEG_INFINITY
+        } else if x == f64::INFINITY {
+            x
+        } else {
+            f64::NAN
+        };
+    }
+    if x < f64::MIN_POSITIVE {
+        // Scale subnormals by 2^54
+        return ln(x * 18014398509481984.0) - 54.0 * core::f64::consts::LN_2;
+    }
+
+    // x = m 2^e where m is in [sqrt(1/2), sqrt(2))
+    let bits = x.to_bits();
+    let mut e = (bits >> 52) as i32 - 1023;
+    let mut m = f64::from_bits((bits & ((1 << 52) - 1)) | (1023 << 52));
+    if m >= core::f64::consts::SQRT_2 {
+        m *= 0.5;
+        e += 1;
+    }
+
+    // ln(m) = 2 atanh(s) = 2 (s + s^3 / 3 + s^5 / 5 + ...) where |s| < 0.172,
+    // thus eleven terms beyond the first suffice
+    let s = (m - 1.0) / (m + 1.0);
+    let z = s * s;
+    let mut series = 0.0;
+    for i in (1..=11).rev() {
+        series = z * (1.0 / f64::from(2 * i + 1) + series);
+    }
+    let ln_m = 2.0 * s + 2.0 * s * series;
+
+    let e = f64::from(e);
+    e * LN_2_HI + (e * LN_2_LO + ln_m)
+}
+
+/// The exponential function `e^x`
+///
+/// This is a `core`-only replacement of `f64::exp`, accurate to a few ulps.
+pub(crate) fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    } else if x > 709.782712893384 {
+        return f64::INFINITY;
+    } else if x < -745.1332191019412 {
+        return 0.0;
+    }
+
+    // x = k ln(2) + r where |r| <= ln(2) / 2
+    let k = floor(x * core::f64::consts::LOG2_E + 0.5);
+    let r = (x - k * LN_2_HI) - k * LN_2_LO;
+
+    // Taylor series; the first omitted term is below 2^-57
+    let mut p = 1.0;
+    for i in (1..=13).rev() {
+        p = 1.0 + p * r / f64::from(i);
+    }
+
+    // Multiply by 2^k in up to two steps, avoiding overflow of the exponent
+    let pow2 = |n: i32| f64::from_bits(((n + 1023) as u64) << 52);
+    let k = k as i32;
+    if k < -1000 {
+        p * pow2(-1000) * pow2(k + 1000)
+    } else if k > 1023 {
+        p * pow2(1023) * pow2(k - 1023)
+    } else {
+        p * pow2(k)
+    }
+}
+
+/// Compute `ln(k!)` for a non-negative integer `k`
+///
+/// Small values are computed directly, others via Stirling's series, with a
+/// relative error below `1e-13`.
+pub(crate) fn log_factorial(k: f64) -> f64 {
+    /// `ln(2 π) / 2`
+    const LN_SQRT_2PI: f64 = 0.918_938_533_204_672_8;
+
+    debug_assert!(k >= 0.0 && k == floor(k));
+    if k < 10.0 {
+        let mut product = 1.0;
+        let mut i = 2.0;
+        while i <= k {
+            product *= i;
+            i += 1.0;
+        }
+        return ln(product);
+    }
+
+    // ln Γ(x) for x = k + 1
+    let x = k + 1.0;
+    let x2 = x * x;
+    let series = (1.0 / 12.0 - (1.0 / 360.0 - (1.0 / 1260.0 - 1.0 / (1680.0 * x2)) / x2) / x2) / x;
+    (x - 0.5) * ln(x) - x + LN_SQRT_2PI + series
+}
+
+/// Sample a random number using the ziggurat method
+///
+/// Specifically, this uses the method described in G. Marsaglia and
+/// W. W. Tsang, "The Ziggurat Method for Generating Random Variables", 2000,
+/// with 256 layers given by `x_tab` and `f_tab` (the pdf at each `x`).
+///
+/// * `symmetric`: whether the pdf is symmetric around 0; if so, the sign of
+///   the result is randomised.
+/// * `pdf`: the (unnormalised) probability density function.
+/// * `zero_case`: samples from the tail, beyond `x_tab[1]`, given a uniform
+///   value in `[-1, 1)` (symmetric) or `(0, 1)` to determine its sign.
+#[cfg(feature = "std")]
+#[inline(always)]
+pub(crate) fn ziggurat<R: crate::Rng + ?Sized, P, Z>(
+    rng: &mut R,
+    symmetric: bool,
+    x_tab: super::ziggurat_tables::ZigTable,
+    f_tab: super::ziggurat_tables::ZigTable,
+    mut pdf: P,
+    mut zero_case: Z,
+) -> f64
+where
+    P: FnMut(f64) -> f64,
+    Z: FnMut(&mut R, f64) -> f64,
+{
+    use super::float::IntoFloat;
+    use crate::distr::{Distribution, StandardUniform};
+
+    loop {
+        // As an optimisation, the lowest 8 bits select the layer while the
+        // highest 52 bits give the uniform value; these do not overlap.
+        let bits = rng.next_u64();
+        let i = bits as usize & 0xff;
+
+        let u = if symmetric {
+            // [2, 4) minus 3 gives [-1, 1)
+            (bits >> 12).into_float_with_exponent(1) - 3.0
+        } else {
+            // [1, 2) minus (1 - ε/2) gives (0, 1)
+            (bits >> 12).into_float_with_exponent(0) - (1.0 - f64::EPSILON / 2.0)
+        };
+        let x = u * x_tab[i];
+
+        let test_x = if symmetric { x.abs() } else { x };
+
+        // Inside the rectangle
+        if test_x < x_tab[i + 1] {
+            return x;
+        }
+        if i == 0 {
+            return zero_case(rng, u);
+        }
+        // Otherwise test against the density within the layer
+        let v: f64 = StandardUniform.sample(rng);
+        if f_tab[i + 1] + (f_tab[i] - f_tab[i + 1]) * v < pdf(x) {
+            return x;
+        }
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Weighted index sampling from log-weights
+
+use super::Error;
+use crate::Rng;
+use crate::distr::uniform::SampleBorrow;
+use crate::distr::{Distribution, Exp1};
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A distribution using weighted sampling of discrete items, given the
+/// logarithms of their weights.
+///
+/// Sampling a `LogWeightedIndex` distribution returns the index of a randomly
+/// selected element from the sequence of log-weights `l` used to create it,
+/// where index `i` is chosen with probability `exp(l[i]) / Σ exp(l[j])`
+/// (the "softmax" of `l`). Log-weights may be `-inf`, representing a weight
+/// of zero.
+///
+/// Unlike converting log-weights for use with [`WeightedIndex`], no
+/// exponentiation or normalization is required, hence there is no loss of
+/// precision or underflow for log-weights of large magnitude or range, as
+/// for the logits of machine learning models.
+///
+/// # Performance
+///
+/// Sampling uses the Gumbel-max trick: each sample is the index maximizing
+/// `l[i] + g[i]` where the `g[i]` are independent samples from the standard
+/// Gumbel distribution. This requires `O(n)` time per sample, in contrast to
+/// `O(log n)` for [`WeightedIndex`]; it is therefore best suited where
+/// log-weights change between samples, or where few samples are taken.
+///
+/// Note: this is feature-gated on `std` due to usage of `f64::ln`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::weighted::LogWeightedIndex;
+/// use rand::prelude::*;
+///
+/// // Weights of e^-1000, e^-1001 and e^-1002 would underflow to zero
+/// let logits = [-1000.0, -1001.0, -1002.0];
+/// let dist = LogWeightedIndex::new(&logits).unwrap();
+/// let mut rng = rand::rng();
+/// for _ in 0..10 {
+///     // Index 0 has a chance of about 67%, index 1 24% and index 2 9%
+///     println!("{}", dist.sample(&mut rng));
+/// }
+/// ```
+///
+/// [`WeightedIndex`]: super::WeightedIndex
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogWeightedIndex {
+    log_weights: Vec<f64>,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for LogWeightedIndex {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("LogWeightedIndex", 1)?;
+        state.serialize_field("log_weights", &self.log_weights)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for LogWeightedIndex {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "LogWeightedIndex")]
+        struct Repr {
+            log_weights: Vec<f64>,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        LogWeightedIndex::new(repr.log_weights).map_err(serde::de::Error::custom)
+    }
+}
+
+impl LogWeightedIndex {
+    /// Creates a new `LogWeightedIndex` [`Distribution`] using the
+    /// log-weights `log_weights`.
+    ///
+    /// Error cases:
+    /// -   [`Error::InvalidInput`] when the iterator `log_weights` is empty.
+    /// -   [`Error::InvalidWeight`] when a log-weight is not-a-number or
+    ///     positive infinity.
+    /// -   [`Error::InsufficientNonZero`] when all log-weights are negative
+    ///     infinity.
+    pub fn new<I>(log_weights: I) -> Result<LogWeightedIndex, Error>
+    where
+        I: IntoIterator,
+        I::Item: SampleBorrow<f64>,
+    {
+        let log_weights: Vec<f64> = log_weights.into_iter().map(|w| *w.borrow()).collect();
+        if log_weights.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+        if log_weights.iter().any(|w| !(*w < f64::INFINITY)) {
+            return Err(Error::InvalidWeight);
+        }
+        if log_weights.iter().all(|w| *w == f64::NEG_INFINITY) {
+            return Err(Error::InsufficientNonZero);
+        }
+        Ok(LogWeightedIndex { log_weights })
+    }
+
+    /// Returns the log-weights of all indices
+    pub fn log_weights(&self) -> &[f64] {
+        &self.log_weights
+    }
+}
+
+impl Distribution<usize> for LogWeightedIndex {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        // A standard Gumbel variate is -ln(E) for E ~ Exp(1), thus we take the
+        // index maximizing l[i] - ln(E[i]).
+        let mut best = (0, f64::NEG_INFINITY);
+        for (i, &w) in self.log_weights.iter().enumerate() {
+            if w == f64::NEG_INFINITY {
+                continue;
+            }
+            let e: f64 = rng.sample(Exp1);
+            let key = w - e.ln();
+            if key > best.1 || best.1 == f64::NEG_INFINITY {
+                best = (i, key);
+            }
+        }
+        best.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_log_weighted_index() {
+        let mut rng = crate::test::rng(400);
+        for (log_weights, probs) in [
+            (&[0.0, 0.0][..], &[0.5, 0.5][..]),
+            (
+                &[-1000.0, -1001.0, f64::NEG_INFINITY, -1002.0],
+                &[
+                    0.6652409557748219,
+                    0.24472847105479764,
+                    0.0,
+                    0.09003057317038046,
+                ],
+            ),
+            (&[800.0, 0.0, 800.0], &[0.5, 0.0, 0.5]),
+            (&[f64::NEG_INFINITY, 3.0], &[0.0, 1.0]),
+        ] {
+            let distr = LogWeightedIndex::new(log_weights).unwrap();
+            assert_eq!(distr.log_weights(), log_weights);
+
+            let n = 10_000;
+            let mut counts = [0u32; 4];
+            for _ in 0..n {
+                counts[distr.sample(&mut rng)] += 1;
+            }
+            for (&count, &p) in counts.iter().zip(probs) {
+                let expected = p * n as f64;
+                let tol = 5.0 * (p * (1.0 - p) * n as f64).sqrt();
+                assert!((count as f64 - expected).abs() <= tol);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(LogWeightedIndex::new([0.0f64; 0]), Err(Error::InvalidInput));
+        assert_eq!(
+            LogWeightedIndex::new([0.0, f64::NAN]),
+            Err(Error::InvalidWeight)
+        );
+        assert_eq!(
+            LogWeightedIndex::new([0.0, f64::INFINITY]),
+            Err(Error::InvalidWeight)
+        );
+        assert_eq!(
+            LogWeightedIndex::new([f64::NEG_INFINITY]),
+            Err(Error::InsufficientNonZero)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialization() {
+        let distr = LogWeightedIndex::new([-1.0, -2.5]).unwrap();
+        let json = serde_json::to_string(&distr).unwrap();
+        assert_eq!(json, r#"{"log_weights":[-1.0,-2.5]}"#);
+        let de: LogWeightedIndex = serde_json::from_str(&json).unwrap();
+        assert_eq!(de, distr);
+        assert!(serde_json::from_str::<LogWeightedIndex>(r#"{"log_weights":[]}"#).is_err());
+    }
+}
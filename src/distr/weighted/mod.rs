@@ -9,15 +9,32 @@
 //! Weighted (index) sampling
 //!
 //! Primarily, this module houses the [`WeightedIndex`] distribution.
-//! See also [`rand_distr::weighted`] for alternative implementations supporting
-//! potentially-faster sampling or a more easily modifiable tree structure.
+//! [`WeightedAliasIndex`] offers `O(1)` sampling at the cost of slower
+//! construction and no support for weight updates, while
+//! [`WeightedTreeIndex`] supports adding, removing and updating weights in
+//! `O(log n)` time. These distributions require the `alloc` feature.
 //!
-//! [`rand_distr::weighted`]: https://docs.rs/rand_distr/latest/rand_distr/weighted/index.html
+//! With the `std` feature, [`LogWeightedIndex`] samples indices given the
+//! logarithms of their weights.
 
 use core::fmt;
+#[cfg(feature = "std")]
+mod log_weighted_index;
+#[cfg(feature = "alloc")]
+mod weighted_alias;
+#[cfg(feature = "alloc")]
 mod weighted_index;
+#[cfg(feature = "alloc")]
+mod weighted_tree;
 
+#[cfg(feature = "std")]
+pub use log_weighted_index::LogWeightedIndex;
+#[cfg(feature = "alloc")]
+pub use weighted_alias::{AliasableWeight, WeightedAliasIndex};
+#[cfg(feature = "alloc")]
 pub use weighted_index::WeightedIndex;
+#[cfg(feature = "alloc")]
+pub use weighted_tree::WeightedTreeIndex;
 
 /// Bounds on a weight
 ///
@@ -30,15 +30,17 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Performance
 ///
-/// Time complexity of sampling from `WeightedIndex` is `O(log N)` where
-/// `N` is the number of weights.
-/// See also [`rand_distr::weighted`] for alternative implementations supporting
-/// potentially-faster sampling or a more easily modifiable tree structure.
+/// Weights are stored in an implicit binary indexed tree (Fenwick tree).
+/// Construction is `O(N)` where `N` is the number of weights, while time
+/// complexity of both sampling and of updating a single weight is `O(log N)`.
+/// See also [`WeightedAliasIndex`] for `O(1)` sampling and
+/// [`WeightedTreeIndex`] which additionally supports adding and removing
+/// weights.
 ///
 /// A `WeightedIndex<X>` contains a `Vec<X>` and a [`Uniform<X>`] and so its
 /// size is the sum of the size of those objects, possibly plus some alignment.
 ///
-/// Creating a `WeightedIndex<X>` will allocate enough space to hold `N - 1`
+/// Creating a `WeightedIndex<X>` will allocate enough space to hold `2N`
 /// weights of type `X`, where `N` is the number of weights. However, since
 /// `Vec` doesn't guarantee a particular growth strategy, additional memory
 /// might be allocated but not used. Since the `WeightedIndex` object also
@@ -50,6 +52,23 @@ use serde::{Deserialize, Serialize};
 /// will request a single value from the underlying [`RngCore`], though the
 /// exact number depends on the implementation of `Uniform<X>::sample`.
 ///
+/// # Accuracy
+///
+/// With floating-point weights, each sum held by the tree is accumulated
+/// pairwise, with a relative rounding error of at most about `log₂(N) · ε`
+/// (where `ε` is the machine epsilon of `X`), and sampling adds at most
+/// `log₂ N` of these sums. Thus the rounding error of the cumulative weight
+/// preceding any element is `O(log²(N) · ε)` times the total weight in the
+/// worst case, compared to `O(N · ε)` for a sequential running sum; since
+/// rounding errors tend to cancel out, it is typically closer to
+/// `log₂(N) · ε`. The probabilities of later elements are not biased by the
+/// number of elements preceding them.
+///
+/// Independently of this, the sampled weight has a resolution of about `ε`
+/// times the total weight. With `f32` weights (`ε = 2⁻²³`), the probability
+/// of an element of weight below roughly `2⁻¹⁶` times the total is therefore
+/// only approximate; prefer `f64` weights for large tables of small weights.
+///
 /// # Example
 ///
 /// ```
@@ -75,15 +94,56 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Uniform<X>`]: crate::distr::Uniform
 /// [`RngCore`]: crate::RngCore
-/// [`rand_distr::weighted`]: https://docs.rs/rand_distr/latest/rand_distr/weighted/index.html
+/// [`WeightedAliasIndex`]: super::WeightedAliasIndex
+/// [`WeightedTreeIndex`]: super::WeightedTreeIndex
+///
+/// # Serialization
+///
+/// With the `serde` feature, only the weights are serialized. Deserialization
+/// rebuilds the distribution as in [`WeightedIndex::new`], failing with the
+/// same errors on invalid weights.
 #[derive(Debug, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WeightedIndex<X: SampleUniform + PartialOrd> {
-    cumulative_weights: Vec<X>,
+    weights: Vec<X>,
+    /// Fenwick tree: `tree[k - 1]` holds the sum of the weights at indices
+    /// `k - lowbit(k)..k`, where `lowbit(k)` is the lowest set bit of `k`,
+    /// as computed by [`Self::node_sum`]
+    tree: Vec<X>,
+    /// The sum of all weights, computed from `tree` as in [`Self::prefix`]
     total_weight: X,
     weight_distribution: X::Sampler,
 }
 
+#[cfg(feature = "serde")]
+impl<X> Serialize for WeightedIndex<X>
+where
+    X: SampleUniform + PartialOrd + Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WeightedIndex", 1)?;
+        state.serialize_field("weights", &self.weights)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, X> Deserialize<'de> for WeightedIndex<X>
+where
+    X: SampleUniform + PartialOrd + Weight + Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "WeightedIndex")]
+        struct Repr<X> {
+            weights: Vec<X>,
+        }
+
+        let repr = Repr::<X>::deserialize(deserializer)?;
+        WeightedIndex::new(repr.weights).map_err(serde::de::Error::custom)
+    }
+}
+
 impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
     /// Creates a new a `WeightedIndex` [`Distribution`] using the values
     /// in `weights`. The weights can use any type `X` for which an
@@ -102,14 +162,9 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
         I::Item: SampleBorrow<X>,
         X: Weight,
     {
-        let mut iter = weights.into_iter();
-        let mut total_weight: X = iter.next().ok_or(Error::InvalidInput)?.borrow().clone();
-
+        let iter = weights.into_iter();
         let zero = X::ZERO;
-        if !(total_weight >= zero) {
-            return Err(Error::InvalidWeight);
-        }
-
+        let mut total_weight = zero.clone();
         let mut weights = Vec::<X>::with_capacity(iter.size_hint().0);
         for w in iter {
             // Note that `!(w >= x)` is not equivalent to `w < x` for partially
@@ -117,30 +172,92 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
             if !(w.borrow() >= &zero) {
                 return Err(Error::InvalidWeight);
             }
-            weights.push(total_weight.clone());
-
             if let Err(()) = total_weight.checked_add_assign(w.borrow()) {
                 return Err(Error::Overflow);
             }
+            weights.push(w.borrow().clone());
         }
 
+        if weights.is_empty() {
+            return Err(Error::InvalidInput);
+        }
         if total_weight == zero {
             return Err(Error::InsufficientNonZero);
         }
-        let distr = X::Sampler::new(zero, total_weight.clone()).unwrap();
 
-        Ok(WeightedIndex {
-            cumulative_weights: weights,
-            total_weight,
-            weight_distribution: distr,
-        })
+        // Build the tree in place, adding each node to its parent. Each
+        // partial sum is bounded by the total, hence this cannot overflow.
+        let mut tree = weights.clone();
+        for k in 1..=tree.len() {
+            let parent = k + lowbit(k);
+            if parent <= tree.len() {
+                let w = tree[k - 1].clone();
+                let _ = tree[parent - 1].checked_add_assign(&w);
+            }
+        }
+
+        let mut distr = WeightedIndex {
+            weights,
+            tree,
+            total_weight: zero.clone(),
+            weight_distribution: X::Sampler::new(zero.clone(), total_weight).unwrap(),
+        };
+        distr.set_total_weight();
+        Ok(distr)
+    }
+
+    /// Set `total_weight` and `weight_distribution` from the tree
+    fn set_total_weight(&mut self)
+    where
+        X: Weight,
+    {
+        // The total is computed as the sum over the same tree nodes as
+        // visited by sampling, thus sampling cannot run past the last index.
+        self.total_weight = self.prefix(self.tree.len());
+        self.weight_distribution = X::Sampler::new(X::ZERO, self.total_weight.clone()).unwrap();
+    }
+
+    /// Compute the value of node `k` from its weight and its children
+    ///
+    /// This adds in the same order as construction, thus the result is
+    /// exactly that of construction, even for floating-point weights.
+    fn node_sum(&self, k: usize) -> X
+    where
+        X: Weight,
+    {
+        let mut sum = self.weights[k - 1].clone();
+        let mut step = lowbit(k) >> 1;
+        while step > 0 {
+            let _ = sum.checked_add_assign(&self.tree[k - step - 1]);
+            step >>= 1;
+        }
+        sum
+    }
+
+    /// The sum of the weights at indices `0..end`
+    fn prefix(&self, end: usize) -> X
+    where
+        X: Weight,
+    {
+        // Visit nodes from the largest to the smallest range, as in sampling
+        let mut sum = X::ZERO;
+        let mut k = 0;
+        let mut step = top_step(self.tree.len());
+        while step > 0 {
+            if end & step != 0 {
+                k += step;
+                let _ = sum.checked_add_assign(&self.tree[k - 1]);
+            }
+            step >>= 1;
+        }
+        sum
     }
 
     /// Update a subset of weights, without changing the number of weights.
     ///
     /// `new_weights` must be sorted by the index.
     ///
-    /// Using this method instead of `new` might be more efficient if only a small number of
+    /// Using this method instead of `new` is more efficient if only a small number of
     /// weights is modified. No allocations are performed, unless the weight type `X` uses
     /// allocation internally.
     ///
@@ -149,19 +266,20 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
     ///     index or an index is too large.
     /// -   [`Error::InvalidWeight`] when a weight is not-a-number or negative.
     /// -   [`Error::InsufficientNonZero`] when the sum of all weights is zero.
-    ///     Note that due to floating-point loss of precision, this case is not
-    ///     always correctly detected; usage of a fixed-point weight type may be
-    ///     preferred.
+    /// -   [`Error::Overflow`] when the sum of all weights overflows.
     ///
-    /// Updates take `O(N)` time. If you need to frequently update weights, consider
-    /// [`rand_distr::weighted_tree`](https://docs.rs/rand_distr/*/rand_distr/weighted_tree/index.html)
-    /// as an alternative where an update is `O(log N)`.
+    /// The result is identical to that of constructing a new `WeightedIndex`
+    /// from the updated weights; rounding errors do not accumulate.
+    /// Updating `M` weights takes `O(M log² N)` time: `O(log N)` sums of
+    /// `O(log N)` nodes each are recomputed, instead of being adjusted by the
+    /// change in weight, for exactness with floating-point weights.
     pub fn update_weights(&mut self, new_weights: &[(usize, &X)]) -> Result<(), Error>
     where
         X: for<'a> core::ops::AddAssign<&'a X>
             + for<'a> core::ops::SubAssign<&'a X>
             + Clone
-            + Default,
+            + Default
+            + Weight,
     {
         if new_weights.is_empty() {
             return Ok(());
@@ -183,59 +301,124 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
             if !(*w >= zero) {
                 return Err(Error::InvalidWeight);
             }
-            if i > self.cumulative_weights.len() {
+            if i >= self.tree.len() {
                 return Err(Error::InvalidInput);
             }
 
-            let mut old_w = if i < self.cumulative_weights.len() {
-                self.cumulative_weights[i].clone()
-            } else {
-                self.total_weight.clone()
-            };
-            if i > 0 {
-                old_w -= &self.cumulative_weights[i - 1];
-            }
-
-            total_weight -= &old_w;
-            total_weight += w;
+            total_weight -= &self.weights[i];
             prev_i = Some(i);
         }
+        for &(_, w) in new_weights {
+            if let Err(()) = total_weight.checked_add_assign(w) {
+                return Err(Error::Overflow);
+            }
+        }
         if total_weight <= zero {
-            return Err(Error::InsufficientNonZero);
+            // The sum may be inexact for floating-point weights; check
+            // exactly whether any weight remains positive.
+            let updated = |j: usize| new_weights.binary_search_by_key(&j, |&(i, _)| i).is_ok();
+            let positive = new_weights.iter().any(|&(_, w)| *w > zero)
+                || (self.weights.iter().enumerate()).any(|(j, w)| *w > zero && !updated(j));
+            if !positive {
+                return Err(Error::InsufficientNonZero);
+            }
+        }
+
+        // Update the weights and the tree nodes covering them. Because we
+        // checked all the preconditions in the previous loop, this should
+        // never panic.
+        for &(i, w) in new_weights {
+            self.weights[i] = w.clone();
+            let mut k = i + 1;
+            while k <= self.tree.len() {
+                self.tree[k - 1] = self.node_sum(k);
+                k += lowbit(k);
+            }
         }
 
-        // Update the weights. Because we checked all the preconditions in the
-        // previous loop, this should never panic.
-        let mut iter = new_weights.iter();
+        self.set_total_weight();
+        Ok(())
+    }
+
+    /// Sample `amount` distinct indices without replacement
+    ///
+    /// Each index is chosen with probability proportional to its weight
+    /// among the indices not yet chosen. Indices are returned in the order
+    /// they were chosen. `self` is not modified.
+    ///
+    /// This is equivalent to sampling once, setting the weight of the result
+    /// to zero and repeating, but only requires a single copy of the
+    /// distribution: complexity is `O(N + amount · log² N)`.
+    ///
+    /// Error cases:
+    /// -   [`Error::InsufficientNonZero`] when fewer than `amount` weights
+    ///     are non-zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::distr::weighted::WeightedIndex;
+    ///
+    /// let dist = WeightedIndex::new([1, 0, 2, 3]).unwrap();
+    /// let mut rng = rand::rng();
+    /// let mut chosen = dist.sample_multiple(&mut rng, 3).unwrap();
+    /// chosen.sort_unstable();
+    /// assert_eq!(chosen, [0, 2, 3]);
+    /// ```
+    pub fn sample_multiple<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        amount: usize,
+    ) -> Result<Vec<usize>, Error>
+    where
+        X: Weight,
+    {
+        let non_zero = self.weights.iter().filter(|w| **w > X::ZERO).count();
+        if non_zero < amount {
+            return Err(Error::InsufficientNonZero);
+        }
 
-        let mut prev_weight = zero.clone();
-        let mut next_new_weight = iter.next();
-        let &(first_new_index, _) = next_new_weight.unwrap();
-        let mut cumulative_weight = if first_new_index > 0 {
-            self.cumulative_weights[first_new_index - 1].clone()
-        } else {
-            zero.clone()
+        let mut remaining = WeightedIndex {
+            weights: self.weights.clone(),
+            tree: self.tree.clone(),
+            total_weight: self.total_weight.clone(),
+            weight_distribution: X::Sampler::new(X::ZERO, self.total_weight.clone()).unwrap(),
         };
-        for i in first_new_index..self.cumulative_weights.len() {
-            match next_new_weight {
-                Some(&(j, w)) if i == j => {
-                    cumulative_weight += w;
-                    next_new_weight = iter.next();
-                }
-                _ => {
-                    let mut tmp = self.cumulative_weights[i].clone();
-                    tmp -= &prev_weight; // We know this is positive.
-                    cumulative_weight += &tmp;
-                }
+        let mut indices = Vec::with_capacity(amount);
+        for n in 0..amount {
+            let i = remaining.sample(rng);
+            indices.push(i);
+            if n + 1 == amount {
+                break;
             }
-            prev_weight = cumulative_weight.clone();
-            core::mem::swap(&mut prev_weight, &mut self.cumulative_weights[i]);
+
+            // Mask the chosen index. A non-zero weight remains, hence the
+            // total stays positive.
+            remaining.weights[i] = X::ZERO;
+            let mut k = i + 1;
+            while k <= remaining.tree.len() {
+                remaining.tree[k - 1] = remaining.node_sum(k);
+                k += lowbit(k);
+            }
+            remaining.set_total_weight();
         }
+        Ok(indices)
+    }
+}
 
-        self.total_weight = total_weight;
-        self.weight_distribution = X::Sampler::new(zero, self.total_weight.clone()).unwrap();
+/// The lowest set bit of `k`
+#[inline]
+fn lowbit(k: usize) -> usize {
+    k & k.wrapping_neg()
+}
 
-        Ok(())
+/// The largest power of two not exceeding `len`, or zero if `len == 0`
+#[inline]
+fn top_step(len: usize) -> usize {
+    if len == 0 {
+        0
+    } else {
+        1 << (usize::BITS - 1 - len.leading_zeros())
     }
 }
 
@@ -273,7 +456,7 @@ where
 
 impl<X> Iterator for WeightedIndexIter<'_, X>
 where
-    X: for<'b> core::ops::SubAssign<&'b X> + SampleUniform + PartialOrd + Clone,
+    X: SampleUniform + PartialOrd + Clone,
 {
     type Item = X;
 
@@ -305,22 +488,8 @@ impl<X: SampleUniform + PartialOrd + Clone> WeightedIndex<X> {
     /// assert_eq!(dist.weight(2), Some(2));
     /// assert_eq!(dist.weight(3), None);
     /// ```
-    pub fn weight(&self, index: usize) -> Option<X>
-    where
-        X: for<'a> core::ops::SubAssign<&'a X>,
-    {
-        use core::cmp::Ordering::*;
-
-        let mut weight = match index.cmp(&self.cumulative_weights.len()) {
-            Less => self.cumulative_weights[index].clone(),
-            Equal => self.total_weight.clone(),
-            Greater => return None,
-        };
-
-        if index > 0 {
-            weight -= &self.cumulative_weights[index - 1];
-        }
-        Some(weight)
+    pub fn weight(&self, index: usize) -> Option<X> {
+        self.weights.get(index).cloned()
     }
 
     /// Returns a lazy-loading iterator containing the current weights of this distribution.
@@ -339,10 +508,7 @@ impl<X: SampleUniform + PartialOrd + Clone> WeightedIndex<X> {
     /// dist.update_weights(&[(0, &2)]).unwrap();
     /// assert_eq!(dist.weights().collect::<Vec<_>>(), vec![2, 2, 3]);
     /// ```
-    pub fn weights(&self) -> WeightedIndexIter<'_, X>
-    where
-        X: for<'a> core::ops::SubAssign<&'a X>,
-    {
+    pub fn weights(&self) -> WeightedIndexIter<'_, X> {
         WeightedIndexIter {
             weighted_index: self,
             index: 0,
@@ -357,13 +523,34 @@ impl<X: SampleUniform + PartialOrd + Clone> WeightedIndex<X> {
 
 impl<X> Distribution<usize> for WeightedIndex<X>
 where
-    X: SampleUniform + PartialOrd,
+    X: SampleUniform + PartialOrd + Weight,
 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
-        let chosen_weight = self.weight_distribution.sample(rng);
-        // Find the first item which has a weight *higher* than the chosen weight.
-        self.cumulative_weights
-            .partition_point(|w| w <= &chosen_weight)
+        loop {
+            let chosen_weight = self.weight_distribution.sample(rng);
+            // Find the first item whose cumulative weight is *higher* than
+            // the chosen weight by descending the tree.
+            let mut index = 0;
+            let mut sum = X::ZERO;
+            let mut step = top_step(self.tree.len());
+            while step > 0 {
+                if index + step <= self.tree.len() {
+                    let mut next = sum.clone();
+                    let _ = next.checked_add_assign(&self.tree[index + step - 1]);
+                    if next <= chosen_weight {
+                        index += step;
+                        sum = next;
+                    }
+                }
+                step >>= 1;
+            }
+            // With floating-point weights, sums over different nodes may
+            // round differently, rarely resulting in an index of zero weight.
+            // Such samples are rejected.
+            if index < self.weights.len() && self.weights[index] != X::ZERO {
+                return index;
+            }
+        }
     }
 }
 
@@ -380,11 +567,57 @@ mod test {
         let de_weighted_index: WeightedIndex<i32> =
             bincode::deserialize(&ser_weighted_index).unwrap();
 
+        assert_eq!(de_weighted_index, weighted_index);
+
+        let json = serde_json::to_string(&weighted_index).unwrap();
+        assert_eq!(json, r#"{"weights":[1,2,3,4,5,6,7,8,9,10]}"#);
+        let de_weighted_index: WeightedIndex<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(de_weighted_index, weighted_index);
+
+        for json in [
+            r#"{"weights":[]}"#,
+            r#"{"weights":[0,0]}"#,
+            r#"{"weights":[1,-1]}"#,
+            r#"{"weights":[2147483647,1]}"#,
+        ] {
+            assert!(serde_json::from_str::<WeightedIndex<i32>>(json).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_sample_multiple() {
+        let mut r = crate::test::rng(700);
+        let dist = WeightedIndex::new([1.0, 0.0, 2.0, 3.0, 4.0]).unwrap();
+
+        assert!(dist.sample_multiple(&mut r, 0).unwrap().is_empty());
+        assert_eq!(
+            dist.sample_multiple(&mut r, 5).unwrap_err(),
+            Error::InsufficientNonZero
+        );
+
+        let mut all = dist.sample_multiple(&mut r, 4).unwrap();
+        all.sort_unstable();
+        assert_eq!(all, [0, 2, 3, 4]);
+
+        // With weights 1, 2, 3, 4 the probability of index 0 being chosen
+        // second is 1/10 · 0 + 2/10 · 1/8 + 3/10 · 1/7 + 4/10 · 1/6
+        let mut seconds = [0; 5];
+        let mut firsts = [0; 5];
+        for _ in 0..10_000 {
+            let pair = dist.sample_multiple(&mut r, 2).unwrap();
+            assert_ne!(pair[0], pair[1]);
+            firsts[pair[0]] += 1;
+            seconds[pair[1]] += 1;
+        }
+        assert_eq!(firsts[1] + seconds[1], 0);
+        assert!((880..=1120).contains(&firsts[0]));
+        let expected = 10_000.0 * (2.0 / 80.0 + 3.0 / 70.0 + 4.0 / 60.0);
+        assert!((seconds[0] as f64 - expected).abs() < 150.0);
         assert_eq!(
-            de_weighted_index.cumulative_weights,
-            weighted_index.cumulative_weights
+            dist.weights().collect::<Vec<_>>(),
+            [1.0, 0.0, 2.0, 3.0, 4.0]
         );
-        assert_eq!(de_weighted_index.total_weight, weighted_index.total_weight);
     }
 
     #[test]
@@ -509,13 +742,76 @@ mod test {
             let expected_distr = WeightedIndex::new(expected_weights.to_vec()).unwrap();
             assert_eq!(distr.total_weight, expected_total_weight);
             assert_eq!(distr.total_weight, expected_distr.total_weight);
-            assert_eq!(distr.cumulative_weights, expected_distr.cumulative_weights);
+            assert_eq!(distr.tree, expected_distr.tree);
+            assert_eq!(
+                distr.weights().collect::<Vec<_>>(),
+                expected_weights.to_vec()
+            );
+        }
+    }
+
+    #[test]
+    fn test_update_weights_float() {
+        // Updates are exact, even where a change in weight would be
+        // absorbed by rounding
+        let mut distr = WeightedIndex::new([1e20f64, 1.0]).unwrap();
+        distr.update_weights(&[(0, &0.0)]).unwrap();
+        assert_eq!(distr.total_weight(), 1.0);
+        let mut r = crate::test::rng(702);
+        for _ in 0..10 {
+            assert_eq!(distr.sample(&mut r), 1);
+        }
+
+        // The result equals that of construction from the updated weights
+        let mut weights: Vec<f64> = (0..100).map(|i| 0.1 * i as f64).collect();
+        let mut distr = WeightedIndex::new(&weights).unwrap();
+        for step in 0..500 {
+            let i = r.random_range(..weights.len());
+            let w = if step % 3 == 0 {
+                0.0
+            } else {
+                r.random::<f64>()
+            };
+            weights[i] = w;
+            distr.update_weights(&[(i, &w)]).unwrap();
+        }
+        let expected = WeightedIndex::new(&weights).unwrap();
+        assert_eq!(distr, expected);
+        for _ in 0..1000 {
+            assert!(weights[distr.sample(&mut r)] > 0.0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_accuracy_f32() {
+        // Many small weights which are not exactly representable: a running
+        // sum in f32 would drift by far more than the bound checked here
+        let n = 300_000;
+        let weights: Vec<f32> = (0..n).map(|i| 0.1 * (1 + i % 7) as f32).collect();
+        let distr = WeightedIndex::new(&weights).unwrap();
+
+        let mut exact = 0.0f64;
+        let mut running = 0.0f32;
+        let mut max_error = 0.0f64;
+        for (i, &w) in weights.iter().enumerate() {
+            exact += w as f64;
+            running += w;
+            if i % 997 == 0 || i == n - 1 {
+                let error = (distr.prefix(i + 1) as f64 - exact).abs();
+                max_error = max_error.max(error);
+            }
         }
+        let bound = 2.0 * (n as f64).log2() * f32::EPSILON as f64 * exact;
+        assert!(max_error <= bound, "{max_error} > {bound}");
+        assert!((running as f64 - exact).abs() > bound);
+        assert!((distr.total_weight() as f64 - exact).abs() <= bound);
     }
 
     #[test]
     fn test_update_weights_errors() {
         let data = [
+            (&[1, i32::MAX - 10, 3][..], &[(0, &20)][..], Error::Overflow),
             (
                 &[1i32, 0, 0][..],
                 &[(0, &0)][..],
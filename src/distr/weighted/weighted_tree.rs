@@ -0,0 +1,446 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module contains an implementation of a dynamic weighted index backed
+//! by a Fenwick tree (binary indexed tree).
+
+use super::{Error, Weight};
+use crate::Rng;
+use crate::distr::uniform::{SampleUniform, UniformSampler};
+use crate::distr::{Distribution, TryDistribution};
+use alloc::vec::Vec;
+use core::ops::SubAssign;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A distribution using weighted sampling to pick a discretely selected item,
+/// supporting efficient modification of weights.
+///
+/// Sampling a [`WeightedTreeIndex<W>`] distribution returns the index of a
+/// randomly selected element. The chance of a given element being picked is
+/// proportional to its weight. Elements with zero weight are never picked.
+///
+/// Unlike [`WeightedIndex`], weights may be appended, removed and updated
+/// cheaply. This makes the type suitable for simulations which repeatedly
+/// sample an item and then adjust its weight (e.g. preferential attachment).
+///
+/// # Performance
+///
+/// Internally the partial sums of weights are kept in a
+/// [Fenwick tree](https://en.wikipedia.org/wiki/Fenwick_tree). Given that `n`
+/// is the number of weights:
+///
+/// -   construction takes `O(n)` time,
+/// -   [`push`], [`pop`] and [`swap_remove`] take `O(log n)` time (or better),
+/// -   [`update_weight`] takes `O(log n)` time,
+/// -   sampling takes `O(log n)` time and a single call to
+///     `Uniform<W>::sample_single`.
+///
+/// The structure stores `2n` weights of type `W`.
+///
+/// Updates are applied as differences to the stored partial sums. For
+/// floating-point weights, rounding errors may therefore accumulate over many
+/// updates; usage of a fixed-point weight type may be preferred.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::weighted::WeightedTreeIndex;
+/// use rand::prelude::*;
+///
+/// let mut rng = rand::rng();
+/// let mut degrees = WeightedTreeIndex::new([1u32, 1]).unwrap();
+/// // Preferential attachment: each new node connects to an existing node with
+/// // probability proportional to that node's degree.
+/// for _ in 0..100 {
+///     let target = degrees.sample(&mut rng);
+///     let degree = degrees.get(target).unwrap();
+///     degrees.update_weight(target, degree + 1).unwrap();
+///     degrees.push(1).unwrap();
+/// }
+/// assert_eq!(degrees.len(), 102);
+/// ```
+///
+/// [`WeightedIndex`]: super::WeightedIndex
+/// [`push`]: WeightedTreeIndex::push
+/// [`pop`]: WeightedTreeIndex::pop
+/// [`swap_remove`]: WeightedTreeIndex::swap_remove
+/// [`update_weight`]: WeightedTreeIndex::update_weight
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeightedTreeIndex<W: Weight> {
+    weights: Vec<W>,
+    // Fenwick tree in 0-based layout: `tree[i]` is the sum of
+    // `weights[(i & (i + 1))..=i]`.
+    tree: Vec<W>,
+}
+
+impl<W> WeightedTreeIndex<W>
+where
+    W: Weight + PartialOrd + SampleUniform + SubAssign<W>,
+{
+    /// Creates a new [`WeightedTreeIndex`] from a sequence of weights.
+    ///
+    /// Unlike [`WeightedIndex::new`], an empty sequence or one summing to zero
+    /// is accepted; such a distribution cannot be sampled until weights are
+    /// added (see [`Self::is_valid`]).
+    ///
+    /// Error cases:
+    /// -   [`Error::InvalidWeight`] when a weight is not-a-number or negative.
+    /// -   [`Error::Overflow`] when the sum of all weights overflows.
+    ///
+    /// [`WeightedIndex::new`]: super::WeightedIndex::new
+    pub fn new<I>(weights: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = W>,
+    {
+        let weights: Vec<W> = weights.into_iter().collect();
+        let mut total = W::ZERO;
+        for w in &weights {
+            if !(*w >= W::ZERO) {
+                return Err(Error::InvalidWeight);
+            }
+            total.checked_add_assign(w).map_err(|()| Error::Overflow)?;
+        }
+
+        // Linear-time construction: each node adds itself to its parent.
+        // Since no partial sum exceeds the total, this cannot overflow.
+        let mut tree = weights.clone();
+        for i in 0..tree.len() {
+            let parent = i | (i + 1);
+            if parent < tree.len() {
+                let node = tree[i].clone();
+                tree[parent].checked_add_assign(&node).unwrap();
+            }
+        }
+
+        Ok(WeightedTreeIndex { weights, tree })
+    }
+
+    /// Returns the number of weights.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Returns `true` if there are no weights.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// Returns `true` if the distribution can be sampled, i.e. if the sum of
+    /// weights is non-zero.
+    pub fn is_valid(&self) -> bool {
+        self.total_weight() > W::ZERO
+    }
+
+    /// Returns the weight at the given index, if it exists.
+    pub fn get(&self, index: usize) -> Option<W> {
+        self.weights.get(index).cloned()
+    }
+
+    /// Returns the sum of all weights.
+    pub fn total_weight(&self) -> W {
+        self.prefix_sum(self.len())
+    }
+
+    /// Appends a new weight, which receives index `self.len()`.
+    ///
+    /// Error cases:
+    /// -   [`Error::InvalidWeight`] when `weight` is not-a-number or negative.
+    /// -   [`Error::Overflow`] when the sum of all weights overflows.
+    ///
+    /// In case of error, `self` is not modified.
+    pub fn push(&mut self, weight: W) -> Result<(), Error> {
+        if !(weight >= W::ZERO) {
+            return Err(Error::InvalidWeight);
+        }
+        let mut total = self.total_weight();
+        total
+            .checked_add_assign(&weight)
+            .map_err(|()| Error::Overflow)?;
+
+        // The new node covers `weights[(i & (i + 1))..=i]`; its value is the
+        // new weight plus the nodes covering the remainder of that range.
+        let i = self.len();
+        let low = i & (i + 1);
+        let mut node = weight.clone();
+        let mut j = i;
+        while j > low {
+            let child = j - 1;
+            node.checked_add_assign(&self.tree[child]).unwrap();
+            j = child & (child + 1);
+        }
+
+        self.weights.push(weight);
+        self.tree.push(node);
+        Ok(())
+    }
+
+    /// Removes the last weight and returns it, or `None` if empty.
+    pub fn pop(&mut self) -> Option<W> {
+        // No other node depends on the last node, hence no update is required.
+        self.tree.pop();
+        self.weights.pop()
+    }
+
+    /// Removes the weight at `index` and returns it.
+    ///
+    /// The removed weight is replaced by the last weight (same as
+    /// [`Vec::swap_remove`]), hence the item previously at index
+    /// `self.len() - 1` will afterwards be found at `index`.
+    ///
+    /// Returns `None` (and does not modify `self`) if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> Option<W> {
+        if index >= self.len() {
+            return None;
+        }
+        let last = self.pop().unwrap();
+        if index == self.len() {
+            return Some(last);
+        }
+        let removed = self.weights[index].clone();
+        // `last` was previously part of the total, so this cannot overflow.
+        self.update_weight(index, last).unwrap();
+        Some(removed)
+    }
+
+    /// Updates the weight at `index`.
+    ///
+    /// Error cases:
+    /// -   [`Error::InvalidInput`] when `index` is out of bounds.
+    /// -   [`Error::InvalidWeight`] when `weight` is not-a-number or negative.
+    /// -   [`Error::Overflow`] when the sum of all weights overflows.
+    ///
+    /// In case of error, `self` is not modified.
+    pub fn update_weight(&mut self, index: usize, weight: W) -> Result<(), Error> {
+        if index >= self.len() {
+            return Err(Error::InvalidInput);
+        }
+        if !(weight >= W::ZERO) {
+            return Err(Error::InvalidWeight);
+        }
+
+        let old = &self.weights[index];
+        if weight >= *old {
+            let mut delta = weight.clone();
+            delta -= old.clone();
+            let mut total = self.total_weight();
+            total
+                .checked_add_assign(&delta)
+                .map_err(|()| Error::Overflow)?;
+
+            let mut i = index;
+            while i < self.tree.len() {
+                // No node exceeds the total, so this cannot overflow.
+                self.tree[i].checked_add_assign(&delta).unwrap();
+                i |= i + 1;
+            }
+        } else {
+            let mut delta = old.clone();
+            delta -= weight.clone();
+
+            let mut i = index;
+            while i < self.tree.len() {
+                self.tree[i] -= delta.clone();
+                i |= i + 1;
+            }
+        }
+
+        self.weights[index] = weight;
+        Ok(())
+    }
+
+    /// Samples a randomly selected index, returning an error if the
+    /// distribution is not [valid](Self::is_valid).
+    ///
+    /// Error cases:
+    /// -   [`Error::InsufficientNonZero`] when the sum of all weights is zero.
+    pub fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<usize, Error> {
+        let total = self.total_weight();
+        if !(total > W::ZERO) {
+            return Err(Error::InsufficientNonZero);
+        }
+        let len = self.len();
+        let mut step = 1 << (usize::BITS - 1 - len.leading_zeros());
+        loop {
+            let mut target = W::Sampler::sample_single(W::ZERO, total.clone(), rng)
+                .map_err(|_| Error::InvalidWeight)?;
+
+            // Find the first index whose prefix sum exceeds `target`.
+            // `pos` is the number of weights known to be <= target.
+            let mut pos = 0;
+            while step > 0 {
+                let next = pos + step;
+                if next <= len && self.tree[next - 1] <= target {
+                    target -= self.tree[next - 1].clone();
+                    pos = next;
+                }
+                step /= 2;
+            }
+
+            // Floating-point sampling may yield `total` itself due to rounding.
+            if pos < len {
+                return Ok(pos);
+            }
+            step = 1 << (usize::BITS - 1 - len.leading_zeros());
+        }
+    }
+
+    /// Sum of the first `n` weights
+    fn prefix_sum(&self, mut n: usize) -> W {
+        let mut sum = W::ZERO;
+        while n > 0 {
+            // No partial sum exceeds the total, so this cannot overflow.
+            sum.checked_add_assign(&self.tree[n - 1]).unwrap();
+            n &= n - 1;
+        }
+        sum
+    }
+}
+
+impl<W> TryDistribution<usize> for WeightedTreeIndex<W>
+where
+    W: Weight + PartialOrd + SampleUniform + SubAssign<W>,
+{
+    type Error = Error;
+
+    fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<usize, Error> {
+        WeightedTreeIndex::try_sample(self, rng)
+    }
+}
+
+impl<W> Distribution<usize> for WeightedTreeIndex<W>
+where
+    W: Weight + PartialOrd + SampleUniform + SubAssign<W>,
+{
+    /// Samples a randomly selected index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the distribution is not [valid](WeightedTreeIndex::is_valid).
+    #[track_caller]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        self.try_sample(rng).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_no_item_error() {
+        let mut rng = crate::test::rng(0x9c9fa0b0580a7031);
+        let mut tree = WeightedTreeIndex::<f64>::new([]).unwrap();
+        assert_eq!(tree.total_weight(), 0.0);
+        assert!(!tree.is_valid());
+        assert_eq!(
+            tree.try_sample(&mut rng).unwrap_err(),
+            Error::InsufficientNonZero
+        );
+        tree.push(0.0).unwrap();
+        assert_eq!(
+            tree.try_sample(&mut rng).unwrap_err(),
+            Error::InsufficientNonZero
+        );
+    }
+
+    #[test]
+    fn test_overflow_error() {
+        assert_eq!(WeightedTreeIndex::new([i32::MAX, 2]), Err(Error::Overflow));
+        let mut tree = WeightedTreeIndex::new([i32::MAX, 0]).unwrap();
+        assert_eq!(tree.push(1), Err(Error::Overflow));
+        assert_eq!(tree.update_weight(1, 1), Err(Error::Overflow));
+        tree.update_weight(0, 1).unwrap();
+        tree.push(i32::MAX - 1).unwrap();
+        assert_eq!(tree.total_weight(), i32::MAX);
+    }
+
+    #[test]
+    fn test_invalid_weight_error() {
+        assert_eq!(
+            WeightedTreeIndex::<i32>::new([1, -1]).unwrap_err(),
+            Error::InvalidWeight
+        );
+        assert_eq!(
+            WeightedTreeIndex::<f64>::new([1.0, f64::NAN]).unwrap_err(),
+            Error::InvalidWeight
+        );
+        let mut tree = WeightedTreeIndex::<i32>::new([]).unwrap();
+        assert_eq!(tree.push(-1).unwrap_err(), Error::InvalidWeight);
+        tree.push(1).unwrap();
+        assert_eq!(tree.update_weight(0, -1).unwrap_err(), Error::InvalidWeight);
+        assert_eq!(tree.update_weight(1, 1).unwrap_err(), Error::InvalidInput);
+    }
+
+    #[test]
+    fn test_tree_modifications() {
+        let mut tree = WeightedTreeIndex::new([9, 1, 2]).unwrap();
+        tree.push(3).unwrap();
+        tree.push(5).unwrap();
+        tree.update_weight(0, 0).unwrap();
+        tree.update_weight(1, 4).unwrap();
+        assert_eq!(tree.swap_remove(1), Some(4));
+        assert_eq!(tree.swap_remove(9), None);
+        assert_eq!(tree.pop(), Some(3));
+        assert_eq!(tree.len(), 3);
+
+        let expected = WeightedTreeIndex::new([0, 5, 2]).unwrap();
+        assert_eq!(tree, expected);
+        assert_eq!(tree.total_weight(), 7);
+        for (i, w) in [0, 5, 2].into_iter().enumerate() {
+            assert_eq!(tree.get(i), Some(w));
+        }
+        assert_eq!(tree.get(3), None);
+    }
+
+    #[test]
+    fn test_push_matches_new() {
+        let weights: Vec<u32> = (0..37).map(|x| x * 7 % 11).collect();
+        let mut tree = WeightedTreeIndex::new([]).unwrap();
+        for w in weights.iter() {
+            tree.push(*w).unwrap();
+        }
+        assert_eq!(
+            tree,
+            WeightedTreeIndex::new(weights.iter().cloned()).unwrap()
+        );
+        for n in 0..=weights.len() {
+            assert_eq!(tree.prefix_sum(n), weights[..n].iter().sum::<u32>());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_sample_counts_match_probabilities() {
+        const N_REPS: usize = 10000;
+        let mut rng = crate::test::rng(0x9c9fa0b0580a7031);
+        let mut tree = WeightedTreeIndex::new([5.0f64; 10]).unwrap();
+        let mut weights = [0.0; 10];
+        for (i, w) in weights.iter_mut().enumerate() {
+            *w = if i % 4 == 0 { 0.0 } else { i as f64 };
+            tree.update_weight(i, *w).unwrap();
+        }
+        let total_weight: f64 = weights.iter().sum();
+
+        let mut counts = [0_usize; 10];
+        for _ in 0..N_REPS {
+            counts[tree.sample(&mut rng)] += 1;
+        }
+        for (count, weight) in counts.iter().zip(weights.iter()) {
+            if *weight == 0.0 {
+                assert_eq!(*count, 0);
+            }
+            let diff = *count as f64 / N_REPS as f64 - weight / total_weight;
+            assert!(diff.abs() < 0.02, "{:?}", counts);
+        }
+    }
+}
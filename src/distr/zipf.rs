@@ -0,0 +1,308 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Zipf distribution `Zipf(n, s)` and the zeta distribution `Zeta(s)`.
+
+use crate::Rng;
+use crate::distr::{Distribution, OpenClosed01, StandardUniform};
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [Zipf distribution](https://en.wikipedia.org/wiki/Zipf%27s_law) `Zipf(n, s)`.
+///
+/// Samples integers `k` from `1..=n` with probability proportional to
+/// `k^-s`, i.e. the rank of an element of a population of size `n` whose
+/// frequency follows Zipf's law with exponent `s`. For `s = 0` this is the
+/// uniform distribution over `1..=n`. See also [`Zeta`] for `n = ∞`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Zipf};
+///
+/// // Rank of a requested key among 10000, with exponent 1.1
+/// let zipf = Zipf::new(10_000, 1.1).unwrap();
+/// let rank = zipf.sample(&mut rand::rng());
+/// assert!((1..=10_000).contains(&rank));
+/// ```
+///
+/// # Implementation
+///
+/// Samples are generated by rejection sampling from a continuous envelope
+/// `h(x) = min(1, x^-s)` for `x` in `[0, n)`: a variate `x` of the envelope is
+/// sampled by inversion, then `k = floor(x) + 1` is accepted with probability
+/// `k^-s / h(x)`. The expected number of iterations is below 2.
+///
+/// Note: this is feature-gated on `std` due to usage of `f64::powf`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Zipf {
+    n: u64,
+    s: f64,
+    /// The integral of the envelope over `[0, n)`
+    t: f64,
+    /// `1 / (1 - s)`
+    q: f64,
+}
+
+/// Error type returned from [`Zipf::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZipfError {
+    /// `s < 0` or `s` is NaN.
+    STooSmall,
+    /// `s` is infinite.
+    NonFinite,
+    /// `n < 1`.
+    NTooSmall,
+}
+
+impl fmt::Display for ZipfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ZipfError::STooSmall => "s < 0 or is NaN in Zipf distribution",
+            ZipfError::NonFinite => "s is infinite in Zipf distribution",
+            ZipfError::NTooSmall => "n < 1 in Zipf distribution",
+        })
+    }
+}
+
+impl std::error::Error for ZipfError {}
+
+impl Zipf {
+    /// Construct a new `Zipf` over `1..=n` with exponent `s`.
+    pub fn new(n: u64, s: f64) -> Result<Zipf, ZipfError> {
+        if !(s >= 0.0) {
+            return Err(ZipfError::STooSmall);
+        }
+        if !s.is_finite() {
+            return Err(ZipfError::NonFinite);
+        }
+        if n < 1 {
+            return Err(ZipfError::NTooSmall);
+        }
+        let q = if s != 1.0 { 1.0 / (1.0 - s) } else { 1.0 };
+        let t = if s != 1.0 {
+            ((n as f64).powf(1.0 - s) - s) * q
+        } else {
+            1.0 + (n as f64).ln()
+        };
+        Ok(Zipf { n, s, t, q })
+    }
+
+    /// Returns the number of elements `n` of the distribution.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the exponent `s` of the distribution.
+    pub fn s(&self) -> f64 {
+        self.s
+    }
+
+    /// The inverse of the integral of the envelope, for `p` in `[0, 1)`
+    #[inline]
+    fn inv_cdf(&self, p: f64) -> f64 {
+        let pt = p * self.t;
+        if pt <= 1.0 {
+            pt
+        } else if self.s != 1.0 {
+            (pt * (1.0 - self.s) + self.s).powf(self.q)
+        } else {
+            (pt - 1.0).exp()
+        }
+    }
+}
+
+impl Distribution<u64> for Zipf {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        loop {
+            let inv_b = self.inv_cdf(rng.sample(StandardUniform));
+            let x = (inv_b + 1.0).floor();
+            // Accept with probability x^-s / h(inv_b), where inv_b < x
+            let mut ratio = x.powf(-self.s);
+            if x > 1.0 {
+                ratio *= inv_b.powf(self.s);
+            }
+            let y: f64 = rng.sample(StandardUniform);
+            // Rounding must not exceed the support
+            if y < ratio && x <= self.n as f64 {
+                return (x as u64).min(self.n);
+            }
+        }
+    }
+}
+
+/// The [zeta distribution](https://en.wikipedia.org/wiki/Zeta_distribution) `Zeta(s)`.
+///
+/// Samples integers `k >= 1` with probability proportional to `k^-s`, for
+/// `s > 1`. This is the limit of [`Zipf`] as `n` tends to infinity. Samples
+/// too large for a `u64`, which may occur for `s` close to 1, are saturated
+/// to `u64::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Zeta};
+///
+/// let zeta = Zeta::new(1.5).unwrap();
+/// let k = zeta.sample(&mut rand::rng());
+/// assert!(k >= 1);
+/// ```
+///
+/// # Implementation
+///
+/// This uses the rejection algorithm of L. Devroye, "Non-Uniform Random
+/// Variate Generation", 1986, p. 551, with an expected number of iterations
+/// below `2^(s - 1) / (2^(s - 1) - 1)` (at most 1.72 for `s >= 2`).
+///
+/// Note: this is feature-gated on `std` due to usage of `f64::powf`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Zeta {
+    s: f64,
+    /// `s - 1`
+    s_minus_1: f64,
+    /// `2^(s - 1)`
+    b: f64,
+}
+
+/// Error type returned from [`Zeta::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZetaError {
+    /// `s <= 1` or `s` is NaN.
+    STooSmall,
+    /// `s` is infinite.
+    NonFinite,
+}
+
+impl fmt::Display for ZetaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ZetaError::STooSmall => "s <= 1 or is NaN in zeta distribution",
+            ZetaError::NonFinite => "s is infinite in zeta distribution",
+        })
+    }
+}
+
+impl std::error::Error for ZetaError {}
+
+impl Zeta {
+    /// Construct a new `Zeta` with exponent `s`.
+    pub fn new(s: f64) -> Result<Zeta, ZetaError> {
+        if !(s > 1.0) {
+            return Err(ZetaError::STooSmall);
+        }
+        if !s.is_finite() {
+            return Err(ZetaError::NonFinite);
+        }
+        let s_minus_1 = s - 1.0;
+        Ok(Zeta {
+            s,
+            s_minus_1,
+            b: s_minus_1.exp2(),
+        })
+    }
+
+    /// Returns the exponent `s` of the distribution.
+    pub fn s(&self) -> f64 {
+        self.s
+    }
+}
+
+impl Distribution<u64> for Zeta {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        loop {
+            let u: f64 = rng.sample(OpenClosed01);
+            let x = u.powf(-1.0 / self.s_minus_1).floor();
+            if x >= u64::MAX as f64 {
+                // Acceptance is certain in the limit
+                return u64::MAX;
+            }
+            let t = (1.0 + 1.0 / x).powf(self.s_minus_1);
+            let v: f64 = rng.sample(StandardUniform);
+            if v * x * (t - 1.0) * self.b <= t * (self.b - 1.0) {
+                return x as u64;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Compare frequencies of the values `1..=counts.len()` to `k^-s / norm`
+    fn test_frequencies<D: Distribution<u64>>(distr: D, s: f64, norm: f64, seed: u64) {
+        let mut rng = crate::test::rng(seed);
+        let mut counts = [0u32; 20];
+        let n = 100_000;
+        for _ in 0..n {
+            let k = distr.sample(&mut rng);
+            assert!(k >= 1);
+            if let Some(count) = counts.get_mut(k as usize - 1) {
+                *count += 1;
+            }
+        }
+        for (k, &count) in counts.iter().enumerate() {
+            let expected = ((k + 1) as f64).powf(-s) / norm * n as f64;
+            assert!((count as f64 - expected).abs() < 5.0 * expected.sqrt() + 1.0);
+        }
+    }
+
+    fn harmonic(n: u64, s: f64) -> f64 {
+        (1..=n).map(|k| (k as f64).powf(-s)).sum()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_zipf() {
+        for (i, &(n, s)) in [(20, 0.0), (20, 0.5), (20, 1.0), (20, 2.5), (1000, 1.1)]
+            .iter()
+            .enumerate()
+        {
+            test_frequencies(Zipf::new(n, s).unwrap(), s, harmonic(n, s), 370 + i as u64);
+        }
+
+        let mut rng = crate::test::rng(375);
+        let zipf = Zipf::new(1, 1.5).unwrap();
+        assert_eq!(zipf.sample(&mut rng), 1);
+        let zipf = Zipf::new(u64::MAX, 1.0).unwrap();
+        for _ in 0..100 {
+            zipf.sample(&mut rng);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_zeta() {
+        // ζ(2) = π² / 6 and ζ(3) ≈ 1.2020569
+        let pi = core::f64::consts::PI;
+        test_frequencies(Zeta::new(2.0).unwrap(), 2.0, pi * pi / 6.0, 376);
+        test_frequencies(Zeta::new(3.0).unwrap(), 3.0, 1.2020569031595942, 377);
+
+        let mut rng = crate::test::rng(378);
+        let zeta = Zeta::new(1.0 + 1e-9).unwrap();
+        for _ in 0..100 {
+            assert!(zeta.sample(&mut rng) >= 1);
+        }
+        assert_eq!(Zeta::new(1e300).unwrap().sample(&mut rng), 1);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Zipf::new(10, -1.0), Err(ZipfError::STooSmall));
+        assert_eq!(Zipf::new(10, f64::NAN), Err(ZipfError::STooSmall));
+        assert_eq!(Zipf::new(10, f64::INFINITY), Err(ZipfError::NonFinite));
+        assert_eq!(Zipf::new(0, 1.0), Err(ZipfError::NTooSmall));
+        assert_eq!(Zeta::new(1.0), Err(ZetaError::STooSmall));
+        assert_eq!(Zeta::new(f64::NAN), Err(ZetaError::STooSmall));
+        assert_eq!(Zeta::new(f64::INFINITY), Err(ZetaError::NonFinite));
+    }
+}
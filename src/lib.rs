@@ -49,6 +49,7 @@
 #![doc(test(attr(allow(unused_variables), deny(warnings))))]
 #![no_std]
 #![cfg_attr(feature = "simd_support", feature(portable_simd))]
+#![cfg_attr(feature = "f16", feature(f16))]
 #![cfg_attr(
     all(feature = "simd_support", target_feature = "avx512bw"),
     feature(stdarch_x86_avx512)
@@ -124,7 +125,7 @@ pub fn thread_rng() -> crate::rngs::ThreadRng {
     rng()
 }
 
-pub use rng::{Fill, Rng};
+pub use rng::{Fill, Rng, TryRng};
 
 #[cfg(feature = "thread_rng")]
 use crate::distr::{Distribution, StandardUniform};
@@ -165,7 +166,7 @@ use crate::distr::{Distribution, StandardUniform};
 /// }
 /// ```
 ///
-/// [`StandardUniform`]: distr::StandardUniform
+/// [`StandardUniform`]: struct@distr::StandardUniform
 /// [`ThreadRng`]: rngs::ThreadRng
 #[cfg(feature = "thread_rng")]
 #[inline]
@@ -179,7 +180,8 @@ where
 /// Return an iterator over [`random()`] variates
 ///
 /// This function is shorthand for
-/// <code>[rng()].[random_iter](Rng::random_iter)()</code>.
+/// <code>[rng()].[sample_iter](Rng::sample_iter)(StandardUniform)</code>; the
+/// iterator owns its [`ThreadRng`](rngs::ThreadRng).
 ///
 /// # Example
 ///
@@ -193,7 +195,7 @@ pub fn random_iter<T>() -> distr::Iter<StandardUniform, rngs::ThreadRng, T>
 where
     StandardUniform: Distribution<T>,
 {
-    rng().random_iter()
+    StandardUniform.sample_iter(rng())
 }
 
 /// Generate a random value in the given range using the thread-local random number generator.
@@ -30,6 +30,6 @@ pub use crate::rngs::StdRng;
 #[cfg(feature = "thread_rng")]
 pub use crate::rngs::ThreadRng;
 #[doc(no_inline)]
-pub use crate::seq::{IndexedMutRandom, IndexedRandom, IteratorRandom, SliceRandom};
+pub use crate::seq::{ArrayRandom, IndexedMutRandom, IndexedRandom, IteratorRandom, SliceRandom};
 #[doc(no_inline)]
-pub use crate::{CryptoRng, Rng, RngCore, SeedableRng};
+pub use crate::{CryptoRng, Rng, RngCore, SeedableRng, TryRng};
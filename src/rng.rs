@@ -7,13 +7,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! [`Rng`] trait
+//! [`Rng`] and [`TryRng`] traits
 
-use crate::distr::uniform::{SampleRange, SampleUniform};
+use crate::distr::uniform::{SampleRange, SampleRangeStep, SampleUniform};
 use crate::distr::{self, Distribution, StandardUniform};
+use crate::rngs;
+use core::mem::{self, MaybeUninit};
 use core::num::Wrapping;
-use core::{mem, slice};
-use rand_core::RngCore;
+use core::slice;
+use core::time::Duration;
+use rand_core::{RngCore, TryRngCore};
 
 /// User-level interface for RNGs
 ///
@@ -55,6 +58,40 @@ use rand_core::RngCore;
 ///
 /// # let v = foo(&mut rand::rng());
 /// ```
+///
+/// # Trait objects
+///
+/// `Rng` itself is not `dyn`-safe, but since it is implemented for
+/// `dyn RngCore`, all of its methods are usable through `&mut dyn RngCore`.
+/// This allows passing an RNG across a trait-object boundary, e.g. in a plugin
+/// interface, without making the interface generic:
+///
+/// ```
+/// use rand::{Rng, RngCore};
+///
+/// trait Plugin {
+///     fn step(&mut self, rng: &mut dyn RngCore);
+/// }
+///
+/// struct Walker(i32);
+///
+/// impl Plugin for Walker {
+///     fn step(&mut self, rng: &mut dyn RngCore) {
+///         if rng.random_bool(0.9) {
+///             self.0 += rng.random_range(-1..=1);
+///         }
+///     }
+/// }
+///
+/// let mut plugins: Vec<Box<dyn Plugin>> = vec![Box::new(Walker(0))];
+/// let mut rng = rand::rng();
+/// for plugin in &mut plugins {
+///     plugin.step(&mut rng);
+/// }
+/// ```
+///
+/// Each call through `dyn RngCore` is dynamically dispatched; where this is
+/// significant, prefer bulk methods such as [`Rng::fill`].
 pub trait Rng: RngCore {
     /// Return a random value via the [`StandardUniform`] distribution.
     ///
@@ -90,7 +127,7 @@ pub trait Rng: RngCore {
     /// rng.fill(&mut arr2);                    // array fill
     /// ```
     ///
-    /// [`StandardUniform`]: distr::StandardUniform
+    /// [`StandardUniform`]: struct@distr::StandardUniform
     #[inline]
     fn random<T>(&mut self) -> T
     where
@@ -102,29 +139,55 @@ pub trait Rng: RngCore {
     /// Return an iterator over [`random`](Self::random) variates
     ///
     /// This is a just a wrapper over [`Rng::sample_iter`] using
-    /// [`distr::StandardUniform`].
+    /// [`distr::StandardUniform`](struct@distr::StandardUniform).
     ///
-    /// Note: this method consumes its argument. Use
-    /// `(&mut rng).random_iter()` to avoid consuming the RNG.
+    /// The iterator borrows the RNG, which may be used again once the iterator
+    /// is dropped. To create an iterator owning the RNG, use
+    /// <code>rng.[sample_iter](Rng::sample_iter)(StandardUniform)</code>.
     ///
     /// # Example
     ///
     /// ```
     /// use rand::{rngs::SmallRng, Rng, SeedableRng};
     ///
-    /// let rng = SmallRng::seed_from_u64(0);
+    /// let mut rng = SmallRng::seed_from_u64(0);
     /// let v: Vec<i32> = rng.random_iter().take(5).collect();
     /// assert_eq!(v.len(), 5);
+    /// let x: f64 = rng.random();
     /// ```
     #[inline]
-    fn random_iter<T>(self) -> distr::Iter<StandardUniform, Self, T>
+    fn random_iter<T>(&mut self) -> distr::Iter<StandardUniform, &mut Self, T>
     where
-        Self: Sized,
         StandardUniform: Distribution<T>,
     {
         StandardUniform.sample_iter(self)
     }
 
+    /// Return an infinite iterator over random bytes
+    ///
+    /// Unlike <code>self.[random_iter](Self::random_iter)::&lt;u8&gt;()</code>,
+    /// which consumes a `u32` for each byte, the iterator takes eight bytes
+    /// from each `u64`. See [`Bytes`] for details.
+    ///
+    /// The iterator borrows the RNG, which may be used again once the iterator
+    /// is dropped; buffered bytes are then discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::Rng;
+    ///
+    /// let mut rng = rand::rng();
+    /// let payload: Vec<u8> = rng.bytes().take(1000).collect();
+    /// assert_eq!(payload.len(), 1000);
+    /// ```
+    ///
+    /// [`Bytes`]: crate::rngs::Bytes
+    #[inline]
+    fn bytes(&mut self) -> rngs::Bytes<&mut Self> {
+        rngs::Bytes::new(self)
+    }
+
     /// Generate a random value in the given range.
     ///
     /// This function is optimised for the case that only a single sample is
@@ -133,6 +196,8 @@ pub trait Rng: RngCore {
     ///
     /// All types support `low..high_exclusive` and `low..=high` range syntax.
     /// Unsigned integer types also support `..high_exclusive` and `..=high` syntax.
+    /// Ranges of `char` are sampled uniformly over the valid `char`s within
+    /// the range, thus never yield surrogate code points `U+D800..=U+DFFF`.
     ///
     /// # Panics
     ///
@@ -154,6 +219,10 @@ pub trait Rng: RngCore {
     /// // Inclusive range
     /// let n: u32 = rng.random_range(..=10);
     /// println!("{}", n);
+    ///
+    /// // Ranges of `char` exclude the surrogate code points
+    /// let c = rng.random_range('a'..='z');
+    /// assert!(c.is_ascii_lowercase());
     /// ```
     ///
     /// [`Uniform`]: distr::uniform::Uniform
@@ -167,6 +236,114 @@ pub trait Rng: RngCore {
         range.sample_single(self).unwrap()
     }
 
+    /// Generate a random multiple of `step` past the start of the given range.
+    ///
+    /// This samples uniformly from `start`, `start + step`, `start + 2 * step`,
+    /// ... within the range, e.g. to choose grid-aligned positions. Unlike
+    /// `random_range(..) / step * step`, this is unbiased for any range and
+    /// does not sample past the end of the range.
+    ///
+    /// All integer types support `low..high_exclusive` and `low..=high` range
+    /// syntax.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty or if `step <= 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::Rng;
+    ///
+    /// let mut rng = rand::rng();
+    ///
+    /// // One of 0, 5, 10, ..., 995
+    /// let x: u32 = rng.random_range_step(0..1000, 5);
+    /// assert_eq!(x % 5, 0);
+    ///
+    /// // One of -10, -7, ..., 8
+    /// let y: i32 = rng.random_range_step(-10..=10, 3);
+    /// assert_eq!((y + 10) % 3, 0);
+    /// ```
+    #[track_caller]
+    fn random_range_step<T, R>(&mut self, range: R, step: T) -> T
+    where
+        R: SampleRangeStep<T>,
+    {
+        range
+            .sample_single_step(step, self)
+            .expect("cannot sample empty range")
+    }
+
+    /// Randomize a duration by up to `± fraction` of its length.
+    ///
+    /// This samples uniformly from `base - base * fraction` to
+    /// `base + base * fraction` (inclusive), as used to spread out the retries
+    /// of many clients with the same backoff schedule. With `fraction = 0`,
+    /// this returns `base`.
+    ///
+    /// # Panics
+    ///
+    /// If `fraction` is not in `[0, 1]`, or if `base + base * fraction`
+    /// overflows `Duration`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use rand::Rng;
+    ///
+    /// let mut rng = rand::rng();
+    /// for attempt in 0..5 {
+    ///     // Exponential backoff with ±20% jitter
+    ///     let backoff = Duration::from_millis(100) * 2u32.pow(attempt);
+    ///     let delay = rng.jitter(backoff, 0.2);
+    ///     assert!(delay >= backoff * 4 / 5 && delay <= backoff * 6 / 5);
+    /// }
+    /// ```
+    #[track_caller]
+    fn jitter(&mut self, base: Duration, fraction: f64) -> Duration {
+        assert!(
+            (0.0..=1.0).contains(&fraction),
+            "jitter fraction {} is outside range [0.0, 1.0]",
+            fraction
+        );
+        let delta = base.mul_f64(fraction);
+        let low = base.saturating_sub(delta);
+        let high = base.checked_add(delta).expect("jitter overflows Duration");
+        self.random_range(low..=high)
+    }
+
+    /// Return a float sampled uniformly from the given unit interval.
+    ///
+    /// This is equivalent to sampling from [`StandardUniform`],
+    /// [`OpenClosed01`] or [`Open01`], according to `interval`; see
+    /// [`UnitInterval`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::Rng;
+    /// use rand::distr::UnitInterval;
+    ///
+    /// let mut rng = rand::rng();
+    /// let u: f64 = rng.random_float_in(UnitInterval::OpenClosed);
+    /// let exp = -u.ln();
+    /// assert!(exp >= 0.0);
+    /// ```
+    ///
+    /// [`StandardUniform`]: struct@distr::StandardUniform
+    /// [`OpenClosed01`]: distr::OpenClosed01
+    /// [`Open01`]: distr::Open01
+    /// [`UnitInterval`]: distr::UnitInterval
+    #[inline]
+    fn random_float_in<T>(&mut self, interval: distr::UnitInterval) -> T
+    where
+        distr::UnitInterval: Distribution<T>,
+    {
+        interval.sample(self)
+    }
+
     /// Return a bool with a probability `p` of being true.
     ///
     /// See also the [`Bernoulli`] distribution, which may be faster if
@@ -205,6 +382,7 @@ pub trait Rng: RngCore {
     ///
     /// See also the [`Bernoulli`] distribution, which may be faster if
     /// sampling from the same `numerator` and `denominator` repeatedly.
+    /// For 64-bit values, use [`Rng::random_ratio_u64`].
     ///
     /// # Panics
     ///
@@ -232,6 +410,44 @@ pub trait Rng: RngCore {
         }
     }
 
+    /// Return a bool with a probability of `numerator/denominator` of being
+    /// true, for 64-bit `numerator` and `denominator`.
+    ///
+    /// This is the 64-bit equivalent of [`Rng::random_ratio`], e.g. for
+    /// probabilities from 64-bit counters. The probability is exact, using an
+    /// unbiased sample of `0..denominator`.
+    ///
+    /// Only integer arithmetic is used (a widening multiply, as by
+    /// [`Rng::random_range`]); unlike [`Rng::random_bool`], no conversion to
+    /// `f64` is involved, thus the probability is not limited by the precision
+    /// of `f64` and this is suitable where floating-point arithmetic is
+    /// unavailable or undesired.
+    ///
+    /// # Panics
+    ///
+    /// If `denominator == 0` or `numerator > denominator`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::Rng;
+    ///
+    /// let (hits, total) = (3_000_000_000_u64, 7_000_000_000_u64);
+    /// let mut rng = rand::rng();
+    /// println!("{}", rng.random_ratio_u64(hits, total));
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn random_ratio_u64(&mut self, numerator: u64, denominator: u64) -> bool {
+        if denominator == 0 || numerator > denominator {
+            panic!(
+                "p={}/{} is outside range [0.0, 1.0]",
+                numerator, denominator
+            );
+        }
+        numerator == denominator || self.random_range(0..denominator) < numerator
+    }
+
     /// Sample a new value, using the given distribution.
     ///
     /// ### Example
@@ -291,12 +507,48 @@ pub trait Rng: RngCore {
         distr.sample_iter(self)
     }
 
+    /// Sample an array of values, using the given distribution
+    ///
+    /// This is equivalent to [`Distribution::sample_fill`] on an array, which
+    /// allows distributions to amortize setup such as the rejection threshold
+    /// of [`Uniform`] over all samples. Values are thus not guaranteed to
+    /// equal those of repeated calls to [`Rng::sample`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::Rng;
+    /// use rand::distr::Uniform;
+    ///
+    /// let mut rng = rand::rng();
+    /// let die = Uniform::new_inclusive(1u8, 6).unwrap();
+    /// let rolls: [u8; 64] = rng.sample_batch(&die);
+    /// assert!(rolls.iter().all(|x| (1..=6).contains(x)));
+    /// ```
+    ///
+    /// [`Uniform`]: distr::Uniform
+    #[inline]
+    fn sample_batch<T, D, const N: usize>(&mut self, distr: &D) -> [T; N]
+    where
+        T: Default,
+        D: Distribution<T>,
+    {
+        let mut array: [T; N] = core::array::from_fn(|_| T::default());
+        distr.sample_fill(self, &mut array);
+        array
+    }
+
     /// Fill any type implementing [`Fill`] with random data
     ///
     /// This method is implemented for types which may be safely reinterpreted
     /// as an (aligned) `[u8]` slice then filled with random data. It is often
     /// faster than using [`Rng::random`] but not value-equivalent.
     ///
+    /// It is also implemented for `f32` and `f64`, generating random integers
+    /// in bulk as for `u32` and `u64`, then converting each to a value in
+    /// `[0, 1)` exactly as [`StandardUniform`] does. The results thus have the
+    /// same distribution as [`Rng::random`], but are not value-equivalent.
+    ///
     /// The distribution is expected to be uniform with portable results, but
     /// this cannot be guaranteed for third-party implementations.
     ///
@@ -315,6 +567,62 @@ pub trait Rng: RngCore {
         Fill::fill_slice(dest, self)
     }
 
+    /// Fill possibly uninitialized memory with random data
+    ///
+    /// Each element is written once, via [`Fill::fill_uninit_slice`], and the
+    /// initialized memory is returned. It allows filling large buffers in
+    /// place, e.g. within a `Box`, without first constructing a copy on the
+    /// stack or initializing the memory twice.
+    ///
+    /// The values generated for the types implementing [`Fill`] in this crate
+    /// are those of [`Rng::fill`], provided that the output of [`fill_bytes`]
+    /// does not depend on how it is split into calls of whole `u64` words (as
+    /// is the case for all RNGs in this crate).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use rand::Rng;
+    ///
+    /// let mut buf = [MaybeUninit::<u8>::uninit(); 4096];
+    /// let bytes: &mut [u8] = rand::rng().fill_uninit(&mut buf);
+    /// assert_eq!(bytes.len(), 4096);
+    /// ```
+    ///
+    /// [`fill_bytes`]: RngCore::fill_bytes
+    #[track_caller]
+    fn fill_uninit<'a, T: Fill>(&mut self, dest: &'a mut [MaybeUninit<T>]) -> &'a mut [T] {
+        Fill::fill_uninit_slice(dest, self)
+    }
+
+    /// Return an array of random data via [`Fill`]
+    ///
+    /// This is equivalent to [`Rng::fill`] on an array: byte arrays are filled
+    /// by a single call to [`fill_bytes`], which makes this the recommended
+    /// way to generate keys and nonces. Note that generated values differ from
+    /// those of [`Rng::random`] for arrays, which samples each element
+    /// separately.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::Rng;
+    ///
+    /// let mut rng = rand::rng();
+    /// let key = rng.random_array::<u8, 32>();
+    /// let nonce: [u8; 12] = rng.random_array();
+    /// # let _ = (key, nonce);
+    /// ```
+    ///
+    /// [`fill_bytes`]: RngCore::fill_bytes
+    #[inline]
+    fn random_array<T: Fill + Default, const N: usize>(&mut self) -> [T; N] {
+        let mut array: [T; N] = core::array::from_fn(|_| T::default());
+        self.fill(&mut array);
+        array
+    }
+
     /// Alias for [`Rng::random`].
     #[inline]
     #[deprecated(
@@ -356,6 +664,200 @@ pub trait Rng: RngCore {
 
 impl<R: RngCore + ?Sized> Rng for R {}
 
+/// User-level interface for fallible RNGs
+///
+/// This trait mirrors [`Rng`] for [`TryRngCore`], returning an error where
+/// the source of randomness fails instead of panicking. It is implemented
+/// automatically for any `R: TryRngCore`, thus also for any `R: RngCore` (with
+/// error type [`Infallible`](core::convert::Infallible)).
+///
+/// This is useful with sources such as [`OsRng`] where a failure should be
+/// propagated, e.g. by a service which should not abort when the operating
+/// system's source of entropy is unavailable. Use [`Rng`] over an infallible
+/// RNG where possible, since these methods have some overhead.
+///
+/// Values are sampled exactly as by the corresponding methods of [`Rng`].
+/// Where the source fails, sampling continues on placeholder output until
+/// complete, whereupon the sampled value is discarded and the first error is
+/// returned; the source is not used again for this sample. For
+/// [`TryRng::try_fill`], the contents of `dest` are then unspecified.
+///
+/// # Example
+///
+/// ```
+/// use rand::TryRng;
+/// # #[cfg(feature = "os_rng")] {
+/// use rand::rngs::OsRng;
+///
+/// fn session_id() -> Result<u128, rand::rand_core::OsError> {
+///     OsRng.try_random()
+/// }
+///
+/// let id = session_id().expect("OS entropy source failed");
+/// # }
+/// ```
+///
+/// [`OsRng`]: crate::rngs::OsRng
+pub trait TryRng: TryRngCore {
+    /// Return a random value via the [`StandardUniform`] distribution, or an
+    /// error if the source fails
+    ///
+    /// See [`Rng::random`].
+    ///
+    /// [`StandardUniform`]: struct@distr::StandardUniform
+    #[inline]
+    fn try_random<T>(&mut self) -> Result<T, Self::Error>
+    where
+        StandardUniform: Distribution<T>,
+    {
+        let mut rng = TryAdapter::new(self);
+        let x = rng.random();
+        rng.finish(x)
+    }
+
+    /// Generate a random value in the given range, or an error if the source
+    /// fails
+    ///
+    /// See [`Rng::random_range`].
+    ///
+    /// # Panics
+    ///
+    /// When the range is empty (`low >= high` or `low > high` if inclusive).
+    #[track_caller]
+    fn try_random_range<T, R>(&mut self, range: R) -> Result<T, Self::Error>
+    where
+        T: SampleUniform,
+        R: SampleRange<T>,
+    {
+        let mut rng = TryAdapter::new(self);
+        let x = rng.random_range(range);
+        rng.finish(x)
+    }
+
+    /// Return a bool with a probability `p` of being true, or an error if the
+    /// source fails
+    ///
+    /// See [`Rng::random_bool`].
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0` or `p > 1`.
+    #[inline]
+    #[track_caller]
+    fn try_random_bool(&mut self, p: f64) -> Result<bool, Self::Error> {
+        let mut rng = TryAdapter::new(self);
+        let x = rng.random_bool(p);
+        rng.finish(x)
+    }
+
+    /// Sample a new value using the given distribution, or an error if the
+    /// source fails
+    ///
+    /// See [`Rng::sample`].
+    fn try_sample<T, D: Distribution<T>>(&mut self, distr: D) -> Result<T, Self::Error> {
+        let mut rng = TryAdapter::new(self);
+        let x = rng.sample(distr);
+        rng.finish(x)
+    }
+
+    /// Fill any type implementing [`Fill`] with random data, or return an
+    /// error if the source fails
+    ///
+    /// See [`Rng::fill`].
+    #[track_caller]
+    fn try_fill<T: Fill>(&mut self, dest: &mut [T]) -> Result<(), Self::Error> {
+        let mut rng = TryAdapter::new(self);
+        rng.fill(dest);
+        rng.finish(())
+    }
+}
+
+impl<R: TryRngCore + ?Sized> TryRng for R {}
+
+/// An [`RngCore`] over a [`TryRngCore`], recording the first error
+///
+/// After an error, output is generated by SplitMix64 over a counter such that
+/// rejection sampling still terminates.
+struct TryAdapter<'a, R: TryRngCore + ?Sized> {
+    rng: &'a mut R,
+    error: Option<R::Error>,
+    state: u64,
+}
+
+impl<'a, R: TryRngCore + ?Sized> TryAdapter<'a, R> {
+    #[inline]
+    fn new(rng: &'a mut R) -> Self {
+        TryAdapter {
+            rng,
+            error: None,
+            state: 0,
+        }
+    }
+
+    /// Return `x`, or the first error if any
+    #[inline]
+    fn finish<T>(self, x: T) -> Result<T, R::Error> {
+        match self.error {
+            None => Ok(x),
+            Some(err) => Err(err),
+        }
+    }
+
+    #[inline]
+    fn check<T>(&mut self, result: Result<T, R::Error>) -> Option<T> {
+        match result {
+            Ok(x) => Some(x),
+            Err(err) => {
+                self.error = Some(err);
+                None
+            }
+        }
+    }
+
+    fn placeholder(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl<R: TryRngCore + ?Sized> RngCore for TryAdapter<'_, R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        if self.error.is_none() {
+            let result = self.rng.try_next_u32();
+            if let Some(x) = self.check(result) {
+                return x;
+            }
+        }
+        self.placeholder() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        if self.error.is_none() {
+            let result = self.rng.try_next_u64();
+            if let Some(x) = self.check(result) {
+                return x;
+            }
+        }
+        self.placeholder()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        if self.error.is_none() {
+            let result = self.rng.try_fill_bytes(dst);
+            if self.check(result).is_some() {
+                return;
+            }
+        }
+        rand_core::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
 /// Support filling a slice with random data
 ///
 /// This trait allows slices of "plain data" types to be efficiently filled
@@ -369,12 +871,51 @@ impl<R: RngCore + ?Sized> Rng for R {}
 pub trait Fill: Sized {
     /// Fill this with random data
     fn fill_slice<R: Rng + ?Sized>(this: &mut [Self], rng: &mut R);
+
+    /// Fill possibly uninitialized memory with random data
+    ///
+    /// Implementations must initialize every element of `this`, writing each
+    /// once, and return it as an initialized slice. See [`Rng::fill_uninit`].
+    ///
+    /// There is no default implementation: initializing `this` before
+    /// calling [`Fill::fill_slice`], e.g. with zero bytes, would not be sound
+    /// for every implementing type.
+    fn fill_uninit_slice<'a, R: Rng + ?Sized>(
+        this: &'a mut [MaybeUninit<Self>],
+        rng: &mut R,
+    ) -> &'a mut [Self];
+}
+
+/// Fill `dest` via [`RngCore::fill_bytes`], through a 256-byte buffer on the
+/// stack
+fn fill_bytes_uninit<'a, R: Rng + ?Sized>(
+    rng: &mut R,
+    dest: &'a mut [MaybeUninit<u8>],
+) -> &'a mut [u8] {
+    let mut buf = [0u8; 256];
+    for chunk in dest.chunks_mut(buf.len()) {
+        let buf = &mut buf[..chunk.len()];
+        rng.fill_bytes(buf);
+        for (x, &b) in chunk.iter_mut().zip(buf.iter()) {
+            x.write(b);
+        }
+    }
+    // SAFETY: all elements of `dest` are initialized above, and
+    // `MaybeUninit<u8>` has the same layout as `u8`.
+    unsafe { slice::from_raw_parts_mut(dest.as_mut_ptr() as *mut u8, dest.len()) }
 }
 
 impl Fill for u8 {
     fn fill_slice<R: Rng + ?Sized>(this: &mut [Self], rng: &mut R) {
         rng.fill_bytes(this)
     }
+
+    fn fill_uninit_slice<'a, R: Rng + ?Sized>(
+        this: &'a mut [MaybeUninit<Self>],
+        rng: &mut R,
+    ) -> &'a mut [Self] {
+        fill_bytes_uninit(rng, this)
+    }
 }
 
 /// Call target for unsafe macros
@@ -414,6 +955,28 @@ macro_rules! impl_fill {
                 }
             }
         }
+
+        fn fill_uninit_slice<'a, R: Rng + ?Sized>(
+            this: &'a mut [MaybeUninit<Self>],
+            rng: &mut R,
+        ) -> &'a mut [Self] {
+            let len = this.len();
+            let size = mem::size_of_val(this);
+            // SAFETY: `this` is valid for reads and writes within its `size` bytes, and
+            // `MaybeUninit<u8>` has no alignment or validity requirements.
+            let bytes = unsafe {
+                slice::from_raw_parts_mut(this.as_mut_ptr() as *mut MaybeUninit<u8>, size)
+            };
+            fill_bytes_uninit(rng, bytes);
+            // SAFETY: all bytes of `this` are initialized above, and `$t` is valid for
+            // all bit-patterns of its contents (note the SAFETY requirement on callers of
+            // this macro).
+            let this = unsafe { slice::from_raw_parts_mut(this.as_mut_ptr() as *mut Self, len) };
+            for x in this.iter_mut() {
+                *x = impl_fill!(to_le! $to_le x);
+            }
+            this
+        }
     };
     ($t:ty) => {{
         // Force caller to wrap with an `unsafe` block
@@ -491,6 +1054,101 @@ mod test {
         assert_eq!(array[1], warray[1].0);
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_jitter() {
+        let mut r = rng(112);
+        let base = Duration::from_millis(500);
+        assert_eq!(r.jitter(base, 0.0), base);
+        let mut sum = Duration::ZERO;
+        let (mut min, mut max) = (base, base);
+        for _ in 0..1000 {
+            let d = r.jitter(base, 0.5);
+            sum += d;
+            min = min.min(d);
+            max = max.max(d);
+        }
+        assert!(min >= base / 2 && min < base * 11 / 20);
+        assert!(max <= base * 3 / 2 && max > base * 29 / 20);
+        let mean = sum / 1000;
+        assert!(mean > base * 24 / 25 && mean < base * 26 / 25);
+
+        let d = r.jitter(Duration::MAX / 2, 1.0);
+        assert!(d <= Duration::MAX);
+        assert!(r.jitter(Duration::ZERO, 1.0).is_zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_jitter_invalid() {
+        rng(113).jitter(Duration::from_secs(1), 1.5);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_random_ratio_u64() {
+        let mut r = rng(114);
+        assert!(r.random_ratio_u64(u64::MAX, u64::MAX));
+        assert!(!r.random_ratio_u64(0, u64::MAX));
+        // The probability is not representable as a ratio of `u32`s
+        let (num, denom) = (u64::MAX / 3, u64::MAX / 3 * 2 + 1);
+        let count = (0..10_000)
+            .filter(|_| r.random_ratio_u64(num, denom))
+            .count();
+        assert!((4800..=5200).contains(&count));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_ratio_u64_invalid() {
+        rng(115).random_ratio_u64(2, 1);
+    }
+
+    #[test]
+    fn test_random_iter() {
+        let mut r = rng(110);
+        let mut iter = r.random_iter();
+        let v: [u32; 3] = core::array::from_fn(|_| iter.next().unwrap());
+        // The RNG is borrowed rather than moved
+        let next: u32 = r.random();
+
+        let mut r = rng(110);
+        let expected: [u32; 4] = core::array::from_fn(|_| r.random());
+        assert_eq!(v, expected[..3]);
+        assert_eq!(next, expected[3]);
+    }
+
+    #[test]
+    fn test_fill_uninit() {
+        let mut array = [0u32; 5];
+        rng(2).fill(&mut array);
+        let mut uninit = [MaybeUninit::<u32>::uninit(); 5];
+        assert_eq!(rng(2).fill_uninit(&mut uninit), array);
+        assert!(rng(2).fill_uninit::<u8>(&mut []).is_empty());
+
+        // Longer than the internal buffer of 256 bytes
+        let mut array = [0u8; 1001];
+        rng(3).fill(&mut array);
+        let mut uninit = [MaybeUninit::<u8>::uninit(); 1001];
+        assert_eq!(rng(3).fill_uninit(&mut uninit), array);
+
+        let mut array = [Wrapping(0i64); 77];
+        rng(4).fill(&mut array);
+        let mut uninit = [MaybeUninit::<Wrapping<i64>>::uninit(); 77];
+        assert_eq!(rng(4).fill_uninit(&mut uninit), array);
+
+        let mut array = [0f64; 77];
+        rng(5).fill(&mut array);
+        let mut uninit = [MaybeUninit::<f64>::uninit(); 77];
+        assert_eq!(rng(5).fill_uninit(&mut uninit), array);
+    }
+
+    #[test]
+    fn test_random_large_array() {
+        let a: [u16; 1000] = rng(3).random();
+        assert!(a.iter().any(|&x| x != a[0]));
+    }
+
     #[test]
     fn test_fill_empty() {
         let mut array = [0u32; 0];
@@ -551,6 +1209,48 @@ mod test {
         r.random_range(5..2);
     }
 
+    #[test]
+    fn test_random_range_step() {
+        let mut r = rng(106);
+        let mut counts = [0; 4];
+        for _ in 0..4000 {
+            // 0, 3, 6 and 9, without bias towards 9 (the range has 11 values)
+            let a: u8 = r.random_range_step(0..11, 3);
+            assert_eq!(a % 3, 0);
+            counts[a as usize / 3] += 1;
+
+            let a: i32 = r.random_range_step(-10..=10, 4);
+            assert!((-10..=10).contains(&a) && (a + 10) % 4 == 0);
+            let a: i8 = r.random_range_step(-128..=127, 100);
+            assert!([-128, -28, 72].contains(&a));
+            let a: u128 = r.random_range_step(u128::MAX - 5..=u128::MAX, 5);
+            assert!(a == u128::MAX - 5 || a == u128::MAX);
+
+            assert_eq!(r.random_range_step(7usize..8, 1000), 7);
+        }
+        for count in counts {
+            assert!((900..=1100).contains(&count), "{:?}", counts);
+        }
+
+        // The full range of values
+        let _: u64 = r.random_range_step(0..=u64::MAX, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_range_step_panic_zero() {
+        let mut r = rng(107);
+        r.random_range_step(0..10, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_random_range_step_panic_empty() {
+        let mut r = rng(108);
+        r.random_range_step(5..5, 1);
+    }
+
     #[test]
     #[allow(clippy::bool_assert_comparison)]
     fn test_random_bool() {
@@ -579,6 +1279,9 @@ mod test {
         r.next_u32();
         r.random::<i32>();
         assert_eq!(r.random_range(0..1), 0);
+        assert!(r.random_bool(1.0));
+        let mut buf = [0u16; 4];
+        r.fill(&mut buf);
         let _c: u8 = StandardUniform.sample(&mut r);
     }
 
@@ -612,4 +1315,91 @@ mod test {
         let expected = (NUM * N) / DENOM; // exact integer
         assert!(((sum - expected) as i32).abs() < 500);
     }
+
+    #[test]
+    fn test_random_array() {
+        let mut a = rng(951);
+        let mut b = rng(951);
+        let key: [u8; 32] = a.random_array();
+        let mut expected = [0u8; 32];
+        b.fill_bytes(&mut expected);
+        assert_eq!(key, expected);
+
+        let x = a.random_array::<u16, 5>();
+        let mut y = [0u16; 5];
+        b.fill(&mut y);
+        assert_eq!(x, y);
+        assert_eq!(a.random_array::<u64, 0>(), [0u64; 0]);
+    }
+
+    #[test]
+    fn test_sample_batch() {
+        let mut a = rng(961);
+        let mut b = rng(961);
+        let d = distr::Uniform::new(10u8, 210).unwrap();
+        let x: [u8; 100] = a.sample_batch(&d);
+        let mut y = [0u8; 100];
+        d.sample_fill(&mut b, &mut y);
+        assert_eq!(x, y);
+        assert!(x.iter().all(|x| (10..210).contains(x)));
+
+        let x: [f64; 3] = a.sample_batch(&StandardUniform);
+        assert_eq!(x, [b.random::<f64>(), b.random(), b.random()]);
+    }
+
+    #[test]
+    fn test_try_rng() {
+        // An RNG which fails after `n` calls
+        struct Failing<R> {
+            rng: R,
+            n: usize,
+        }
+        impl<R: RngCore> TryRngCore for Failing<R> {
+            type Error = &'static str;
+
+            fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+                self.try_next_u64().map(|x| x as u32)
+            }
+            fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+                self.n = self.n.checked_sub(1).ok_or("failure")?;
+                Ok(self.rng.next_u64())
+            }
+            fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+                self.try_next_u64()?;
+                rand_core::impls::fill_bytes_via_next(&mut self.rng, dst);
+                Ok(())
+            }
+        }
+
+        // Values equal those of `Rng`
+        let mut a = rng(941);
+        let mut b = rng(941);
+        assert_eq!(a.try_random::<[u32; 3]>(), Ok(b.random()));
+        assert_eq!(a.try_random_range(0..1000), Ok(b.random_range(0..1000)));
+        assert_eq!(a.try_random_bool(0.5), Ok(b.random_bool(0.5)));
+        let d = distr::Uniform::new(0.0, 1.0).unwrap();
+        assert_eq!(a.try_sample(d), Ok(b.sample(d)));
+        let (mut x, mut y) = ([0u16; 9], [0u16; 9]);
+        assert_eq!(a.try_fill(&mut x), Ok(()));
+        b.fill(&mut y);
+        assert_eq!(x, y);
+
+        let mut r = Failing {
+            rng: rng(942),
+            n: 2,
+        };
+        assert!(r.try_random::<(u64, u64)>().is_ok());
+        assert_eq!(r.try_random::<u64>(), Err("failure"));
+        assert_eq!(r.try_fill(&mut x), Err("failure"));
+
+        // Rejection sampling terminates on placeholder output
+        let mut r = Failing {
+            rng: rng(943),
+            n: 0,
+        };
+        let range = 0..(u64::MAX / 2 + 2);
+        assert_eq!(r.try_random_range(range.clone()), Err("failure"));
+        let d = distr::Uniform::try_from(range).unwrap();
+        assert_eq!(r.try_sample(d), Err("failure"));
+    }
 }
@@ -0,0 +1,169 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A wrapper buffering random bits of another RNG.
+
+use rand_core::{CryptoRng, RngCore};
+
+/// A wrapper around an RNG, buffering random bits to sample few bits at a
+/// time efficiently.
+///
+/// [`BitRng::random_bits`] takes bits from a buffered `u64`, generating a new
+/// `u64` only once the buffer is exhausted; e.g. sixteen 4-bit samples
+/// consume a single `u64` of the wrapped RNG. In contrast, sampling bits via
+/// e.g. <code>rng.[next_u64](RngCore::next_u64)() >> (64 - n)</code> consumes
+/// a whole `u64` for each sample.
+///
+/// `BitRng` also implements [`RngCore`] by forwarding to the wrapped RNG,
+/// leaving the buffered bits unchanged.
+///
+/// The output is portable: bits are taken from each `u64` in order from the
+/// most significant bit.
+///
+/// # Example
+///
+/// ```
+/// use rand::rngs::BitRng;
+///
+/// let mut rng = BitRng::new(rand::rng());
+/// // Sample the direction of 64 steps from a single `u64`
+/// let steps: Vec<u64> = (0..64).map(|_| rng.random_bits(1)).collect();
+/// assert!(steps.iter().all(|&b| b <= 1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BitRng<R> {
+    rng: R,
+    /// The buffered bits, in the most significant bits
+    bits: u64,
+    /// The number of buffered bits
+    len: u32,
+}
+
+impl<R: RngCore> BitRng<R> {
+    /// Wrap `rng`, with an empty buffer
+    pub fn new(rng: R) -> Self {
+        BitRng {
+            rng,
+            bits: 0,
+            len: 0,
+        }
+    }
+
+    /// Return a `u64` with `n` random low bits and all other bits zero.
+    ///
+    /// Bits are taken from the buffer; if it holds fewer than `n` bits, these
+    /// are used as the most significant bits of the result, and the buffer is
+    /// refilled with a new `u64` from the wrapped RNG.
+    ///
+    /// # Panics
+    ///
+    /// If `n > 64`.
+    #[inline]
+    #[track_caller]
+    pub fn random_bits(&mut self, n: u32) -> u64 {
+        assert!(n <= 64, "cannot sample more than 64 bits");
+        if n <= self.len {
+            let x = take(&mut self.bits, n);
+            self.len -= n;
+            return x;
+        }
+
+        let need = n - self.len;
+        let hi = take(&mut self.bits, self.len);
+        self.bits = self.rng.next_u64();
+        self.len = 64 - need;
+        // `hi << need` is a no-op if the buffer was empty
+        hi.checked_shl(need).unwrap_or(0) | take(&mut self.bits, need)
+    }
+
+    /// Returns the number of buffered bits
+    #[inline]
+    pub fn buffered_bits(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns a reference to the wrapped RNG
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.rng
+    }
+
+    /// Returns the wrapped RNG, discarding buffered bits
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+/// Remove and return the `n` most significant bits of `bits`
+#[inline]
+fn take(bits: &mut u64, n: u32) -> u64 {
+    let x = bits.checked_shr(64 - n).unwrap_or(0);
+    *bits = bits.checked_shl(n).unwrap_or(0);
+    x
+}
+
+impl<R: RngCore> RngCore for BitRng<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.rng.fill_bytes(dst)
+    }
+}
+
+impl<R: CryptoRng> CryptoRng for BitRng<R> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_random_bits() {
+        let x = 0x8123_4567_89ab_cdef;
+        let mut rng = BitRng::new(crate::test::step_rng(x, 1));
+        assert_eq!(rng.random_bits(0), 0);
+        assert_eq!(rng.buffered_bits(), 0);
+        assert_eq!(rng.random_bits(1), 1);
+        assert_eq!(rng.random_bits(15), 0x0123);
+        assert_eq!(rng.buffered_bits(), 48);
+        assert_eq!(rng.random_bits(44), 0x0456_789a_bcde);
+        // Spans two words
+        assert_eq!(rng.random_bits(8), 0xf8);
+        assert_eq!(rng.buffered_bits(), 60);
+        assert_eq!(rng.random_bits(60), x + 1 - (0x8 << 60));
+        assert_eq!(rng.random_bits(64), x + 2);
+        assert_eq!(rng.buffered_bits(), 0);
+
+        // Forwarding leaves the buffer unchanged
+        assert_eq!(rng.random_bits(4), 0x8);
+        assert_eq!(rng.next_u64(), x + 4);
+        assert_eq!(rng.random_bits(4), 0x1);
+        assert_eq!(rng.get_ref().clone().next_u64(), x + 5);
+    }
+
+    #[test]
+    fn test_random_bits_uniform() {
+        let mut rng = BitRng::new(crate::test::rng(111));
+        let mut counts = [0; 8];
+        for _ in 0..8000 {
+            counts[rng.random_bits(3) as usize] += 1;
+        }
+        for count in counts {
+            assert!((900..=1100).contains(&count), "{:?}", counts);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! An infinite iterator over random bytes.
+
+use core::iter::FusedIterator;
+use rand_core::RngCore;
+
+/// An infinite iterator over random bytes of an RNG
+///
+/// Bytes are taken from a buffered `u64`, generating a new `u64` only once
+/// the buffer is exhausted; e.g. eight bytes consume a single `u64` of the
+/// wrapped RNG. In contrast, <code>rng.[random_iter](crate::Rng::random_iter)::&lt;u8&gt;()</code>
+/// consumes a whole `u32` for each byte.
+///
+/// Use [`Bytes::fill`] or [`Bytes::next_array`] to take many bytes at once;
+/// these yield the same bytes as repeated calls to [`Iterator::next`] but
+/// copy whole `u64` values where possible.
+///
+/// The output is portable: bytes are taken from each `u64` in little-endian
+/// order, as by [`rand_core::impls::fill_bytes_via_next`].
+///
+/// This is usually constructed by [`Rng::bytes`](crate::Rng::bytes), which
+/// borrows the RNG; use [`Bytes::new`] to take an RNG by value.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+///
+/// let mut rng = rand::rng();
+/// let packet: Vec<u8> = rng.bytes().take(100).collect();
+/// assert_eq!(packet.len(), 100);
+/// let header: [u8; 4] = rng.bytes().next_array();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Bytes<R> {
+    rng: R,
+    /// The buffered bytes, in the least significant bytes
+    buf: u64,
+    /// The number of buffered bytes
+    len: u32,
+}
+
+impl<R: RngCore> Bytes<R> {
+    /// Wrap `rng`, with an empty buffer
+    pub fn new(rng: R) -> Self {
+        Bytes {
+            rng,
+            buf: 0,
+            len: 0,
+        }
+    }
+
+    /// Fill `dest` with the next bytes
+    ///
+    /// This is equivalent to assigning [`Iterator::next`] to each byte in
+    /// order, but faster for long slices.
+    pub fn fill(&mut self, dest: &mut [u8]) {
+        let buffered = dest.len().min(self.len as usize);
+        let (head, tail) = dest.split_at_mut(buffered);
+        for b in head {
+            *b = self.pop_byte();
+        }
+
+        let mut chunks = tail.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.rng.next_u64().to_le_bytes());
+        }
+        for b in chunks.into_remainder() {
+            *b = self.next_byte();
+        }
+    }
+
+    /// Return an array of the next `N` bytes
+    ///
+    /// This is equivalent to [`Bytes::fill`] on an array.
+    pub fn next_array<const N: usize>(&mut self) -> [u8; N] {
+        let mut array = [0; N];
+        self.fill(&mut array);
+        array
+    }
+
+    /// Returns the number of buffered bytes
+    #[inline]
+    pub fn buffered_bytes(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns the wrapped RNG, discarding buffered bytes
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+
+    #[inline]
+    fn next_byte(&mut self) -> u8 {
+        if self.len == 0 {
+            self.buf = self.rng.next_u64();
+            self.len = 8;
+        }
+        self.pop_byte()
+    }
+
+    /// Remove and return the least significant buffered byte
+    #[inline]
+    fn pop_byte(&mut self) -> u8 {
+        let b = self.buf as u8;
+        self.buf >>= 8;
+        self.len -= 1;
+        b
+    }
+}
+
+impl<R: RngCore> Iterator for Bytes<R> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        Some(self.next_byte())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<R: RngCore> FusedIterator for Bytes<R> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Rng;
+
+    #[test]
+    fn test_bytes() {
+        let x = 0x0807_0605_0403_0201;
+        let mut rng = crate::test::step_rng(x, 0x0808_0808_0808_0808);
+        let mut bytes = rng.bytes();
+        assert_eq!(bytes.next(), Some(1));
+        assert_eq!(bytes.buffered_bytes(), 7);
+        assert!(bytes.by_ref().take(10).eq(2..12));
+        assert_eq!(bytes.next_array(), [12, 13, 14, 15, 16]);
+        assert_eq!(bytes.buffered_bytes(), 0);
+        assert_eq!(bytes.next(), Some(17));
+        // Dropping the iterator discards buffered bytes
+        assert_eq!(rng.next_u64(), x + 3 * 0x0808_0808_0808_0808);
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut a = Bytes::new(crate::test::rng(931));
+        let mut b = Bytes::new(crate::test::rng(931));
+        for len in [0, 3, 8, 13, 1, 24, 7] {
+            let mut buf = [0; 24];
+            a.fill(&mut buf[..len]);
+            assert!(buf[..len].iter().copied().eq(b.by_ref().take(len)));
+            assert_eq!(a.buffered_bytes(), b.buffered_bytes());
+        }
+    }
+}
@@ -73,6 +73,10 @@
 //!
 //! Secure RNGs may additionally implement the [`CryptoRng`] trait.
 //!
+//! [`ReseedingRng`] and [`BitRng`] are adapters adding functionality to
+//! another generator: periodic reseeding, and buffering of random bits.
+//! [`Bytes`] is an iterator over random bytes of a generator.
+//!
 //! Use the [`rand_core`] crate when implementing your own RNGs.
 //!
 //! [^1]: D. J. Bernstein, [*ChaCha, a variant of Salsa20*](https://cr.yp.to/chacha.html)
@@ -95,7 +99,11 @@
 //! [PCG]: https://www.pcg-random.org/
 //! [xoshiro]: https://prng.di.unimi.it/
 
+mod bits;
+mod bytes;
 mod reseeding;
+pub use bits::BitRng;
+pub use bytes::Bytes;
 pub use reseeding::ReseedingRng;
 
 #[deprecated(since = "0.9.2")]
@@ -54,6 +54,23 @@ use chacha20::ChaCha12Rng as Rng;
 /// Any [`SeedableRng`] method may be used, but note that `seed_from_u64` is not
 /// suitable where security is required. See also [Seeding RNGs] in the book.
 ///
+/// To derive a generator for each of several tasks from one generator, use
+/// [`SeedableRng::fork`]:
+/// ```
+/// use rand::{Rng, SeedableRng, rngs::StdRng};
+///
+/// let mut parent = StdRng::seed_from_u64(7);
+/// let tasks: Vec<_> = (0..4)
+///     .map(|_| {
+///         let mut rng = parent.fork();
+///         std::thread::spawn(move || rng.random::<u64>())
+///     })
+///     .collect();
+/// for task in tasks {
+///     println!("{}", task.join().unwrap());
+/// }
+/// ```
+///
 /// ## Generation
 ///
 /// The generators implements [`RngCore`] and thus also [`Rng`][crate::Rng].
@@ -11,7 +11,6 @@ use alloc::vec::{self, Vec};
 use core::slice;
 use core::{hash::Hash, ops::AddAssign};
 // BTreeMap is not as fast in tests, but better than nothing.
-#[cfg(feature = "std")]
 use super::WeightError;
 use crate::Rng;
 use crate::distr::uniform::SampleUniform;
@@ -214,6 +213,173 @@ impl Iterator for IndexVecIntoIter {
 
 impl ExactSizeIterator for IndexVecIntoIter {}
 
+/// A vector of `u64` indices, as returned by [`sample_u64`].
+///
+/// Like [`IndexVec`], multiple internal representations are possible; unlike
+/// it, indices are `u64` values on all platforms.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IndexVecU64 {
+    #[doc(hidden)]
+    U32(Vec<u32>),
+    #[doc(hidden)]
+    U64(Vec<u64>),
+}
+
+impl IndexVecU64 {
+    /// Returns the number of indices
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            IndexVecU64::U32(v) => v.len(),
+            IndexVecU64::U64(v) => v.len(),
+        }
+    }
+
+    /// Returns `true` if the length is 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            IndexVecU64::U32(v) => v.is_empty(),
+            IndexVecU64::U64(v) => v.is_empty(),
+        }
+    }
+
+    /// Return the value at the given `index`.
+    #[inline]
+    pub fn index(&self, index: usize) -> u64 {
+        match self {
+            IndexVecU64::U32(v) => u64::from(v[index]),
+            IndexVecU64::U64(v) => v[index],
+        }
+    }
+
+    /// Return result as a `Vec<u64>`. Conversion may or may not be trivial.
+    #[inline]
+    pub fn into_vec(self) -> Vec<u64> {
+        match self {
+            IndexVecU64::U32(v) => v.into_iter().map(u64::from).collect(),
+            IndexVecU64::U64(v) => v,
+        }
+    }
+
+    /// Iterate over the indices as a sequence of `u64` values
+    #[inline]
+    pub fn iter(&self) -> IndexVecU64Iter<'_> {
+        match self {
+            IndexVecU64::U32(v) => IndexVecU64Iter::U32(v.iter()),
+            IndexVecU64::U64(v) => IndexVecU64Iter::U64(v.iter()),
+        }
+    }
+}
+
+impl IntoIterator for IndexVecU64 {
+    type IntoIter = IndexVecU64IntoIter;
+    type Item = u64;
+
+    /// Convert into an iterator over the indices as a sequence of `u64` values
+    #[inline]
+    fn into_iter(self) -> IndexVecU64IntoIter {
+        match self {
+            IndexVecU64::U32(v) => IndexVecU64IntoIter::U32(v.into_iter()),
+            IndexVecU64::U64(v) => IndexVecU64IntoIter::U64(v.into_iter()),
+        }
+    }
+}
+
+impl PartialEq for IndexVecU64 {
+    fn eq(&self, other: &IndexVecU64) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl From<Vec<u32>> for IndexVecU64 {
+    #[inline]
+    fn from(v: Vec<u32>) -> Self {
+        IndexVecU64::U32(v)
+    }
+}
+
+impl From<Vec<u64>> for IndexVecU64 {
+    #[inline]
+    fn from(v: Vec<u64>) -> Self {
+        IndexVecU64::U64(v)
+    }
+}
+
+impl From<IndexVec> for IndexVecU64 {
+    #[inline]
+    fn from(v: IndexVec) -> Self {
+        match v {
+            IndexVec::U32(v) => IndexVecU64::U32(v),
+            #[cfg(target_pointer_width = "64")]
+            IndexVec::U64(v) => IndexVecU64::U64(v),
+        }
+    }
+}
+
+/// Return type of `IndexVecU64::iter`.
+#[derive(Debug)]
+pub enum IndexVecU64Iter<'a> {
+    #[doc(hidden)]
+    U32(slice::Iter<'a, u32>),
+    #[doc(hidden)]
+    U64(slice::Iter<'a, u64>),
+}
+
+impl Iterator for IndexVecU64Iter<'_> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        match self {
+            IndexVecU64Iter::U32(iter) => iter.next().map(|&i| u64::from(i)),
+            IndexVecU64Iter::U64(iter) => iter.next().copied(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            IndexVecU64Iter::U32(v) => v.size_hint(),
+            IndexVecU64Iter::U64(v) => v.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for IndexVecU64Iter<'_> {}
+
+/// Return type of `IndexVecU64::into_iter`.
+#[derive(Clone, Debug)]
+pub enum IndexVecU64IntoIter {
+    #[doc(hidden)]
+    U32(vec::IntoIter<u32>),
+    #[doc(hidden)]
+    U64(vec::IntoIter<u64>),
+}
+
+impl Iterator for IndexVecU64IntoIter {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        match self {
+            IndexVecU64IntoIter::U32(v) => v.next().map(u64::from),
+            IndexVecU64IntoIter::U64(v) => v.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            IndexVecU64IntoIter::U32(v) => v.size_hint(),
+            IndexVecU64IntoIter::U64(v) => v.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for IndexVecU64IntoIter {}
+
 /// Randomly sample exactly `amount` distinct indices from `0..length`, and
 /// return them in random order (fully shuffled).
 ///
@@ -232,8 +398,11 @@ impl ExactSizeIterator for IndexVecIntoIter {}
 /// `u64` indices. Because of this we hide the underlying type behind an
 /// abstraction, `IndexVec`.
 ///
-/// If an allocation-free `no_std` function is required, it is suggested
-/// to adapt the internal `sample_floyd` implementation.
+/// If an allocation-free `no_std` function is required, see [`sample_iter`]
+/// (yielding indices in increasing order) or [`sample_array`].
+///
+/// [`sample_iter`]: super::index::sample_iter
+/// [`sample_array`]: super::index::sample_array
 ///
 /// Panics if `amount > length`.
 #[track_caller]
@@ -272,16 +441,205 @@ where
             sample_floyd(rng, length, amount)
         }
     } else {
-        const C: [f32; 2] = [270.0, 330.0 / 9.0];
-        let j = usize::from(length >= 500_000);
-        if (length as f32) < C[j] * (amount as f32) {
+        // Rejection sampling is fast when using a bitset to detect duplicates,
+        // unless the bitset is large relative to `amount`. For dense sampling
+        // from a small `length`, the inplace method is faster.
+        //
+        // The bitset is limited to 32 bytes per sampled index, at most a few
+        // times the memory of the hash set used otherwise. For small samples
+        // the bitset would be faster beyond this limit, but for large samples
+        // (where the bitset exceeds the CPU caches) its advantage vanishes at
+        // about this limit (see the `seq_index` benchmarks).
+        const C: [f32; 3] = [12.0, 3.0, 2.0];
+        let j = usize::from(length >= 500_000) + usize::from(length >= 5_000_000);
+        let ratio = length as f32 / amount as f32;
+        if ratio < C[j] {
             sample_inplace(rng, length, amount)
+        } else if length / 256 < amount {
+            sample_bitset(rng, length, amount)
         } else {
             sample_rejection(rng, length, amount)
         }
     }
 }
 
+/// Randomly sample exactly `amount` distinct indices from `0..length`, and
+/// return them in increasing order.
+///
+/// This samples a uniformly random `amount`-combination of `0..length`,
+/// sorted to improve the locality of subsequent accesses.
+///
+/// Where `amount` is not too small relative to `length`, indices are selected
+/// via a bitset which is then scanned in order, requiring `O(length)` bits of
+/// memory and roughly `O(amount + length / 64)` time. Otherwise, complexity is
+/// that of [`sample`] plus `O(amount log amount)` for sorting.
+///
+/// See also [`Combinations`] for repeated independent draws.
+///
+/// Panics if `amount > length`.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index;
+///
+/// let mut rng = rand::rng();
+/// let indices = index::sample_sorted(&mut rng, 100, 10).into_vec();
+/// assert!(indices.windows(2).all(|w| w[0] < w[1]));
+/// ```
+#[track_caller]
+pub fn sample_sorted<R>(rng: &mut R, length: usize, amount: usize) -> IndexVec
+where
+    R: Rng + ?Sized,
+{
+    assert!(
+        amount <= length,
+        "`amount` of samples must be less than or equal to `length`"
+    );
+    if length <= u32::MAX as usize && length / 256 < amount {
+        return sample_bitset_sorted(rng, length as u32, amount as u32);
+    }
+
+    let mut indices = sample(rng, length, amount);
+    match &mut indices {
+        IndexVec::U32(v) => v.sort_unstable(),
+        #[cfg(target_pointer_width = "64")]
+        IndexVec::U64(v) => v.sort_unstable(),
+    }
+    indices
+}
+
+/// A distribution of uniformly random `amount`-combinations of `0..length`
+///
+/// Each sample is an [`IndexVec`] of `amount` distinct indices in increasing
+/// order, as produced by [`sample_sorted`]. This is convenient for drawing
+/// many independent subsets, e.g. for bootstrap or ensemble methods.
+///
+/// # Example
+///
+/// ```
+/// use rand::prelude::*;
+/// use rand::seq::index::Combinations;
+///
+/// let mut rng = rand::rng();
+/// let combinations = Combinations::new(100, 10).unwrap();
+/// for subset in (&mut rng).sample_iter(combinations).take(5) {
+///     assert_eq!(subset.len(), 10);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Combinations {
+    length: usize,
+    amount: usize,
+}
+
+impl Combinations {
+    /// Construct a distribution of `amount`-combinations of `0..length`
+    ///
+    /// Returns `None` if (and only if) `amount > length`.
+    pub fn new(length: usize, amount: usize) -> Option<Self> {
+        (amount <= length).then_some(Combinations { length, amount })
+    }
+}
+
+impl Distribution<IndexVec> for Combinations {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IndexVec {
+        sample_sorted(rng, self.length, self.amount)
+    }
+}
+
+/// Randomly sample exactly `amount` distinct indices from `0..length` using
+/// `u64` indices, and return them in random order (fully shuffled).
+///
+/// This is equivalent to [`sample`], but supports `length > u32::MAX` on all
+/// platforms, e.g. for sampling offsets into large files. Results are
+/// portable: they do not depend on the platform's pointer width, and on 64-bit
+/// platforms they equal those of [`sample`] given the same inputs and RNG
+/// state.
+///
+/// Panics if `amount > length` or if `amount > usize::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index;
+///
+/// let mut rng = rand::rng();
+/// let file_size: u64 = 6 << 30;
+/// let offsets = index::sample_u64(&mut rng, file_size, 4);
+/// assert_eq!(offsets.len(), 4);
+/// assert!(offsets.iter().all(|x| x < file_size));
+/// ```
+#[track_caller]
+pub fn sample_u64<R>(rng: &mut R, length: u64, amount: u64) -> IndexVecU64
+where
+    R: Rng + ?Sized,
+{
+    if amount > length {
+        panic!("`amount` of samples must be less than or equal to `length`");
+    }
+    if amount > usize::MAX as u64 {
+        panic!("`amount` of samples must be less than or equal to `usize::MAX`");
+    }
+    if length > u32::MAX as u64 {
+        // Matches the choice of algorithm in `sample`
+        IndexVecU64::U64(sample_rejection_vec(rng, length, amount))
+    } else {
+        sample(rng, length as usize, amount as usize).into()
+    }
+}
+
+/// Randomly sample `amount` distinct coordinates from a grid of shape `dims`
+///
+/// Coordinates are sampled uniformly without replacement from the
+/// `D`-dimensional grid `0..dims[0]` × … × `0..dims[D - 1]`, without
+/// materializing the grid. Each point is returned as an array of per-axis
+/// indices; results are in random order.
+///
+/// Points are sampled via [`sample`] over the flattened (row-major) grid,
+/// with the last axis varying fastest; to obtain flat indices instead,
+/// call [`sample`] with the product of `dims` directly.
+///
+/// Panics if the product of `dims` overflows `usize` or if `amount` exceeds
+/// it.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index;
+///
+/// let mut rng = rand::rng();
+/// let (width, height) = (1920, 1080);
+/// for [y, x] in index::sample_grid(&mut rng, [height, width], 8) {
+///     assert!(y < height && x < width);
+/// }
+/// ```
+#[track_caller]
+pub fn sample_grid<R, const D: usize>(
+    rng: &mut R,
+    dims: [usize; D],
+    amount: usize,
+) -> Vec<[usize; D]>
+where
+    R: Rng + ?Sized,
+{
+    let length = dims
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .expect("grid size must not overflow `usize`");
+    sample(rng, length, amount)
+        .into_iter()
+        .map(|mut flat| {
+            let mut point = [0; D];
+            for (x, &d) in point.iter_mut().zip(dims.iter()).rev() {
+                *x = flat % d;
+                flat /= d;
+            }
+            point
+        })
+        .collect()
+}
+
 /// Randomly sample `amount` distinct indices from `0..length`
 ///
 /// The result may contain less than `amount` indices if insufficient non-zero
@@ -297,7 +655,11 @@ where
 /// Error cases:
 /// -   [`WeightError::InvalidWeight`] when a weight is not-a-number or negative.
 ///
-/// This implementation uses `O(length + amount)` space and `O(length)` time.
+/// This implementation is based on the algorithm A-ExpJ as found in
+/// [Efraimidis and Spirakis, 2005](https://doi.org/10.1016/j.ipl.2005.11.003).
+/// It makes a single pass over `0..length`, uses `O(amount)` space and
+/// `O(length + amount * log(amount) * log(length / amount))` time, thus it is
+/// suitable for very large `length` where `amount` is small.
 #[cfg(feature = "std")]
 pub fn sample_weighted<R, F, X>(
     rng: &mut R,
@@ -338,7 +700,7 @@ where
 ///
 /// This implementation is based on the algorithm A-ExpJ as found in
 /// [Efraimidis and Spirakis, 2005](https://doi.org/10.1016/j.ipl.2005.11.003).
-/// It uses `O(length + amount)` space and `O(length)` time.
+/// It uses `O(amount)` space and `O(length)` time.
 ///
 /// Error cases:
 /// -   [`WeightError::InvalidWeight`] when a weight is not-a-number or negative.
@@ -389,7 +751,8 @@ where
 
     impl<N> Eq for Element<N> {}
 
-    let mut candidates = BinaryHeap::with_capacity(amount.as_usize());
+    // The result never exceeds `length` indices
+    let mut candidates = BinaryHeap::with_capacity(amount.min(length).as_usize());
     let mut index = N::zero();
     while index < length && candidates.len() < amount.as_usize() {
         let weight = weight(index.as_usize()).into();
@@ -432,6 +795,198 @@ where
     ))
 }
 
+/// Randomly sample exactly `amount` distinct indices by systematic
+/// probability-proportional-to-size (PPS) sampling
+///
+/// Each index `i` is included with probability proportional to `weights[i]`,
+/// capped at 1: indices whose weight is at least the sampling interval
+/// (the total weight divided by the number of indices left to select) are
+/// included with certainty, repeatedly until no such index remains; the
+/// remaining indices are then selected by a single random start within the
+/// sampling interval, followed by fixed steps of that interval along the
+/// cumulative weights.
+///
+/// Indices are returned in increasing order. Zero-weighted indices are never
+/// returned. Note that unlike [`sample_weighted`], inclusion of indices is not
+/// independent: the result depends on the order of `weights`.
+///
+/// This implementation uses `O(weights.len())` space and
+/// `O(weights.len() * c)` time, where `c` is one more than the number of
+/// rounds of certainty selection (typically small).
+///
+/// Error cases:
+/// -   [`WeightError::InvalidWeight`] when a weight is not-a-number, negative
+///     or infinite.
+/// -   [`WeightError::InsufficientNonZero`] when fewer than `amount` weights
+///     are positive.
+/// -   [`WeightError::Overflow`] when the sum of weights is not finite.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index;
+///
+/// let mut rng = rand::rng();
+/// // The last unit is selected with certainty
+/// let sizes = [120.0, 40.0, 300.0, 80.0, 900.0];
+/// let units = index::sample_systematic_pps(&mut rng, &sizes, 2).unwrap();
+/// assert_eq!(units.len(), 2);
+/// assert_eq!(units[1], 4);
+/// ```
+pub fn sample_systematic_pps<R, X>(
+    rng: &mut R,
+    weights: &[X],
+    amount: usize,
+) -> Result<Vec<usize>, WeightError>
+where
+    R: Rng + ?Sized,
+    X: Into<f64> + Copy,
+{
+    let mut positive = 0;
+    for &w in weights {
+        let w: f64 = w.into();
+        if !(w >= 0.0 && w.is_finite()) {
+            return Err(WeightError::InvalidWeight);
+        }
+        positive += usize::from(w > 0.0);
+    }
+    if positive < amount {
+        return Err(WeightError::InsufficientNonZero);
+    }
+
+    // Select indices with certainty while any weight reaches the interval.
+    // Since the interval cannot increase as such indices are removed, all
+    // may be selected in a single pass over a fixed interval.
+    let mut certain = alloc::vec![false; weights.len()];
+    let mut remaining = amount;
+    let interval = loop {
+        let total: f64 = weights
+            .iter()
+            .zip(certain.iter())
+            .filter(|(_, certain)| !**certain)
+            .map(|(&w, _)| w.into())
+            .sum();
+        if !total.is_finite() {
+            return Err(WeightError::Overflow);
+        }
+        if remaining == 0 {
+            // No further points are placed
+            break total;
+        }
+        let interval = total / remaining as f64;
+        let mut changed = false;
+        for (&w, certain) in weights.iter().zip(certain.iter_mut()) {
+            if !*certain && w.into() >= interval && remaining > 0 {
+                *certain = true;
+                remaining -= 1;
+                changed = true;
+            }
+        }
+        if !changed {
+            break interval;
+        }
+    };
+
+    let last = weights
+        .iter()
+        .zip(certain.iter())
+        .rposition(|(&w, &certain)| !certain && w.into() > 0.0);
+    let start = rng.random::<f64>() * interval;
+    let mut point = start;
+    let mut selected = 0;
+    let mut cumulative = 0.0;
+    let mut indices = Vec::with_capacity(amount);
+    for (i, (&w, &certain)) in weights.iter().zip(certain.iter()).enumerate() {
+        if certain {
+            indices.push(i);
+            continue;
+        }
+        let w = w.into();
+        cumulative += w;
+        // Each index holds at most one point since its weight is less than
+        // the interval; the last index with positive weight absorbs any
+        // point lost to rounding.
+        if selected < remaining && w > 0.0 && (point < cumulative || Some(i) == last) {
+            indices.push(i);
+            selected += 1;
+            point = start + selected as f64 * interval;
+        }
+    }
+    debug_assert_eq!(indices.len(), amount);
+    Ok(indices)
+}
+
+/// Randomly sample exactly `amount` distinct indices from `0..length` by
+/// stratified sampling with proportional allocation
+///
+/// Indices are grouped into strata by `key`. Each stratum receives a share of
+/// `amount` proportional to its size, rounded either down or up such that
+/// the shares sum to `amount`; rounding is randomized such that the expected
+/// share of each stratum is exactly proportional. Indices are then sampled
+/// uniformly without replacement within each stratum.
+///
+/// Indices are returned in increasing order. Function `key` is called once
+/// for each index.
+///
+/// This implementation uses `O(length)` space and `O(length * log(s))` time,
+/// where `s` is the number of strata.
+///
+/// Panics if `amount > length`.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index;
+///
+/// let mut rng = rand::rng();
+/// let labels = ["cat", "dog", "dog", "cat", "bird", "dog", "cat", "dog"];
+/// let test_set = index::sample_stratified(&mut rng, labels.len(), |i| labels[i], 4);
+/// let dogs = test_set.iter().filter(|&&i| labels[i] == "dog").count();
+/// assert_eq!(dogs, 2);
+/// ```
+#[track_caller]
+pub fn sample_stratified<R, F, K>(rng: &mut R, length: usize, key: F, amount: usize) -> Vec<usize>
+where
+    R: Rng + ?Sized,
+    F: Fn(usize) -> K,
+    K: Ord,
+{
+    use alloc::collections::BTreeMap;
+
+    assert!(
+        amount <= length,
+        "`amount` of samples must be less than or equal to `length`"
+    );
+    let mut strata: BTreeMap<K, Vec<usize>> = BTreeMap::new();
+    for i in 0..length {
+        strata.entry(key(i)).or_default().push(i);
+    }
+    let strata: Vec<Vec<usize>> = strata.into_values().collect();
+
+    // The exact share of each stratum is `amount * size / length`; the
+    // fractional parts sum to the number of remaining seats.
+    let share = |size: usize| (amount as u128 * size as u128) / length as u128;
+    let fraction = |size: usize| ((amount as u128 * size as u128) % length as u128) as f64;
+    let remainder = amount
+        - strata
+            .iter()
+            .map(|s| share(s.len()) as usize)
+            .sum::<usize>();
+    let fractions: Vec<f64> = strata.iter().map(|s| fraction(s.len())).collect();
+    let mut rounded_up = alloc::vec![false; strata.len()];
+    for i in sample_systematic_pps(rng, &fractions, remainder).unwrap() {
+        rounded_up[i] = true;
+    }
+
+    let mut indices = Vec::with_capacity(amount);
+    for (members, up) in strata.iter().zip(rounded_up) {
+        let n = share(members.len()) as usize + usize::from(up);
+        indices.extend(sample(rng, members.len(), n).iter().map(|i| members[i]));
+    }
+    indices.sort_unstable();
+    indices
+}
+
 /// Randomly sample exactly `amount` indices from `0..length`, using Floyd's
 /// combination algorithm.
 ///
@@ -485,6 +1040,78 @@ where
     IndexVec::from(indices)
 }
 
+/// Randomly sample exactly `amount` indices from `0..length`, using rejection
+/// sampling with a bitset to detect duplicates.
+///
+/// Results are identical to those of [`sample_rejection`], but this is much
+/// faster unless `length` is very large relative to `amount`. This uses
+/// `length / 8` bytes of memory and, for `amount <= length / 2`, `O(amount)`
+/// expected time plus `O(length)` set-up.
+fn sample_bitset<R>(rng: &mut R, length: u32, amount: u32) -> IndexVec
+where
+    R: Rng + ?Sized,
+{
+    debug_assert!(amount <= length);
+    let mut bits = alloc::vec![0u64; (length as usize).div_ceil(64)];
+    let distr = Uniform::new(0, length).unwrap();
+    let mut indices = Vec::with_capacity(amount as usize);
+    while indices.len() < amount as usize {
+        let pos = distr.sample(rng);
+        let (word, mask) = ((pos / 64) as usize, 1 << (pos % 64));
+        if bits[word] & mask == 0 {
+            bits[word] |= mask;
+            indices.push(pos);
+        }
+    }
+    IndexVec::from(indices)
+}
+
+/// Randomly sample exactly `amount` indices from `0..length` in increasing
+/// order, using a bitset.
+///
+/// Selected indices are marked in a bitset via rejection sampling, which is
+/// then scanned in order. If `amount > length / 2` then the complement is
+/// sampled instead. This uses `O(length)` bits of memory and
+/// `O(amount + length / 64)` expected time.
+fn sample_bitset_sorted<R>(rng: &mut R, length: u32, amount: u32) -> IndexVec
+where
+    R: Rng + ?Sized,
+{
+    debug_assert!(amount <= length);
+    let complement = amount > length / 2;
+    let draws = if complement { length - amount } else { amount };
+
+    let mut bits = alloc::vec![0u64; (length as usize).div_ceil(64)];
+    if draws > 0 {
+        let distr = Uniform::new(0, length).unwrap();
+        let mut marked = 0;
+        while marked < draws {
+            let pos = distr.sample(rng);
+            let (word, mask) = ((pos / 64) as usize, 1 << (pos % 64));
+            if bits[word] & mask == 0 {
+                bits[word] |= mask;
+                marked += 1;
+            }
+        }
+    }
+
+    let mut indices = Vec::with_capacity(amount as usize);
+    for (i, &word) in bits.iter().enumerate() {
+        let mut word = if complement { !word } else { word };
+        let base = i as u32 * 64;
+        if length - base < 64 {
+            // Mask out bits beyond `length`
+            word &= (1 << (length - base)) - 1;
+        }
+        while word != 0 {
+            indices.push(base + word.trailing_zeros());
+            word &= word - 1;
+        }
+    }
+    debug_assert_eq!(indices.len(), amount as usize);
+    IndexVec::from(indices)
+}
+
 trait UInt: Copy + PartialOrd + Ord + PartialEq + Eq + SampleUniform + Hash + AddAssign {
     fn zero() -> Self;
     #[cfg_attr(feature = "alloc", allow(dead_code))]
@@ -509,7 +1136,6 @@ impl UInt for u32 {
     }
 }
 
-#[cfg(target_pointer_width = "64")]
 impl UInt for u64 {
     #[inline]
     fn zero() -> Self {
@@ -541,7 +1167,15 @@ where
     R: Rng + ?Sized,
     IndexVec: From<Vec<X>>,
 {
-    debug_assert!(amount < length);
+    IndexVec::from(sample_rejection_vec(rng, length, amount))
+}
+
+/// Implementation of [`sample_rejection`], returning a `Vec` of the index type.
+fn sample_rejection_vec<X: UInt, R>(rng: &mut R, length: X, amount: X) -> Vec<X>
+where
+    R: Rng + ?Sized,
+{
+    debug_assert!(amount <= length);
     #[cfg(feature = "std")]
     let mut cache = HashSet::with_capacity(amount.as_usize());
     #[cfg(not(feature = "std"))]
@@ -557,7 +1191,7 @@ where
     }
 
     debug_assert_eq!(indices.len(), amount.as_usize());
-    IndexVec::from(indices)
+    indices
 }
 
 #[cfg(test)]
@@ -588,6 +1222,12 @@ mod test {
         assert_eq!(sample_floyd(&mut r, 1, 0).len(), 0);
         assert_eq!(sample_floyd(&mut r, 1, 1).into_vec(), vec![0]);
 
+        assert_eq!(sample_bitset(&mut r, 1, 0).len(), 0);
+        assert_eq!(sample_bitset(&mut r, 1, 1).into_vec(), vec![0]);
+        let mut v = sample_bitset(&mut r, 130, 130).into_vec();
+        v.sort_unstable();
+        assert_eq!(v, (0..130).collect::<Vec<_>>());
+
         // These algorithms should be fast with big numbers. Test average.
         let sum: usize = sample_rejection(&mut r, 1 << 25, 10u32).into_iter().sum();
         assert!(1 << 25 < sum && sum < (1 << 25) * 25);
@@ -623,10 +1263,20 @@ mod test {
         assert!(v1.iter().all(|e| e < length));
         assert_eq!(v1, v2);
 
-        // A large length and larger amount should use cache
+        // A large length and larger amount should use bitset, which yields
+        // the same results as rejection with a cache
         let (length, amount): (usize, usize) = (1 << 20, 600);
         let v1 = sample(&mut seed_rng(422), length, amount);
-        let v2 = sample_rejection(&mut seed_rng(422), length as u32, amount as u32);
+        let v2 = sample_bitset(&mut seed_rng(422), length as u32, amount as u32);
+        let v3 = sample_rejection(&mut seed_rng(422), length as u32, amount as u32);
+        assert!(v1.iter().all(|e| e < length));
+        assert_eq!(v1, v2);
+        assert_eq!(v1, v3);
+
+        // A very large length and small amount should use cache
+        let (length, amount): (usize, usize) = (1 << 30, 200);
+        let v1 = sample(&mut seed_rng(423), length, amount);
+        let v2 = sample_rejection(&mut seed_rng(423), length as u32, amount as u32);
         assert!(v1.iter().all(|e| e < length));
         assert_eq!(v1, v2);
     }
@@ -654,6 +1304,249 @@ mod test {
 
         let r = sample_weighted(&mut seed_rng(423), 10, |i| i as f64, 10);
         assert_eq!(r.unwrap().len(), 9);
+
+        // Memory use is bounded by `length`, not `amount`
+        let r = sample_weighted(&mut seed_rng(424), 10, |_| 1.0, u32::MAX as usize);
+        assert_eq!(r.unwrap().len(), 10);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_sample_systematic_pps() {
+        let mut r = crate::test::rng(425);
+        assert_eq!(sample_systematic_pps::<_, f64>(&mut r, &[], 0), Ok(vec![]));
+        assert_eq!(sample_systematic_pps(&mut r, &[0.0, 1.0], 1), Ok(vec![1]));
+        assert_eq!(
+            sample_systematic_pps(&mut r, &[3u32, 0, 1], 2),
+            Ok(vec![0, 2])
+        );
+        assert_eq!(
+            sample_systematic_pps(&mut r, &[1.0, 0.0], 2),
+            Err(WeightError::InsufficientNonZero)
+        );
+        assert_eq!(
+            sample_systematic_pps(&mut r, &[1.0, -1.0], 1),
+            Err(WeightError::InvalidWeight)
+        );
+        assert_eq!(
+            sample_systematic_pps(&mut r, &[1.0, f64::INFINITY], 1),
+            Err(WeightError::InvalidWeight)
+        );
+        assert_eq!(
+            sample_systematic_pps(&mut r, &[f64::MAX, f64::MAX], 1),
+            Err(WeightError::Overflow)
+        );
+
+        // Index 4 is included with certainty (10 >= 20 / 2), leaving one
+        // index to be sampled with probability proportional to weight
+        let weights = [1.0, 2.0, 3.0, 4.0, 10.0];
+        let mut counts = [0i32; 5];
+        for _ in 0..10000 {
+            let v = sample_systematic_pps(&mut r, &weights, 2).unwrap();
+            assert_eq!(v.len(), 2);
+            assert!(v[0] < v[1]);
+            for i in v {
+                counts[i] += 1;
+            }
+        }
+        assert_eq!(counts[4], 10000);
+        for (count, expected) in counts.iter().zip([1000, 2000, 3000, 4000]) {
+            assert!(
+                (expected - 250..=expected + 250).contains(count),
+                "counts: {:?}",
+                counts
+            );
+        }
+
+        // Repeated certainty selection: 8 >= 12 / 3, then 2 >= 4 / 2
+        let weights = [8.0, 2.0, 1.0, 1.0];
+        let mut counts = [0i32; 4];
+        for _ in 0..4000 {
+            for i in sample_systematic_pps(&mut r, &weights, 3).unwrap() {
+                counts[i] += 1;
+            }
+        }
+        assert_eq!(counts[..2], [4000, 4000]);
+        assert!((1850..=2150).contains(&counts[2]), "counts: {:?}", counts);
+        assert_eq!(counts[2] + counts[3], 4000);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_sample_stratified() {
+        let mut r = crate::test::rng(426);
+        assert!(sample_stratified(&mut r, 0, |i| i, 0).is_empty());
+        assert_eq!(sample_stratified(&mut r, 3, |i| i, 3), [0, 1, 2]);
+
+        // Exactly proportional allocation: 5, 3 and 2 from strata 0, 1 and 2
+        let label = |i: usize| match i {
+            0..50 => 0,
+            50..80 => 1,
+            _ => 2,
+        };
+        for _ in 0..100 {
+            let v = sample_stratified(&mut r, 100, label, 10);
+            assert!(v.windows(2).all(|w| w[0] < w[1]));
+            let mut counts = [0; 3];
+            for i in v {
+                counts[label(i)] += 1;
+            }
+            assert_eq!(counts, [5, 3, 2]);
+        }
+
+        // Three strata of equal size share 4 seats; one is rounded up
+        let mut counts = [0i32; 12];
+        let mut up = [0i32; 3];
+        for _ in 0..3000 {
+            let v = sample_stratified(&mut r, 12, |i| i % 3, 4);
+            assert_eq!(v.len(), 4);
+            let mut per_stratum = [0; 3];
+            for i in v {
+                counts[i] += 1;
+                per_stratum[i % 3] += 1;
+            }
+            let mut sizes = per_stratum;
+            sizes.sort_unstable();
+            assert_eq!(sizes, [1, 1, 2]);
+            up[per_stratum.iter().position(|&n| n == 2).unwrap()] += 1;
+        }
+        for count in up.iter() {
+            // Binomial(3000, 1/3) with average 1000
+            assert!((880..=1120).contains(count), "up: {:?}", up);
+        }
+        for count in counts.iter() {
+            // Binomial(3000, 1/3) with average 1000
+            assert!((880..=1120).contains(count), "counts: {:?}", counts);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_sample_sorted() {
+        let mut r = crate::test::rng(433);
+        for &(length, amount) in &[
+            (0, 0),
+            (10, 10),
+            (64, 40),
+            (100, 5),
+            (1000, 300),
+            (1000, 900),
+            (1 << 20, 600),
+            (1 << 20, 10000),
+        ] {
+            let v = sample_sorted(&mut r, length, amount).into_vec();
+            assert_eq!(v.len(), amount);
+            assert!(v.windows(2).all(|w| w[0] < w[1]));
+            assert!(v.iter().all(|&x| x < length));
+        }
+
+        // Sampling the complement: each index is excluded equally often
+        let mut excluded = [0i32; 5];
+        for _ in 0..5000 {
+            let v = sample_sorted(&mut r, 5, 4).into_vec();
+            excluded[(0..5).find(|i| !v.contains(i)).unwrap()] += 1;
+        }
+        for count in excluded.iter() {
+            // Binomial(5000, 0.2) with average 1000
+            assert!((880..=1120).contains(count), "excluded: {:?}", excluded);
+        }
+
+        assert_eq!(Combinations::new(3, 4), None);
+        let distr = Combinations::new(5, 2).unwrap();
+        // All 10 combinations are equally likely
+        let mut counts = [[0i32; 5]; 5];
+        for subset in (&mut r).sample_iter(distr).take(10000) {
+            counts[subset.index(0)][subset.index(1)] += 1;
+        }
+        for (a, row) in counts.iter().enumerate() {
+            for (b, count) in row.iter().enumerate() {
+                if a < b {
+                    // Binomial(10000, 0.1) with average 1000
+                    assert!((880..=1120).contains(count), "counts: {:?}", counts);
+                } else {
+                    assert_eq!(*count, 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_sorted_amount_too_large() {
+        sample_sorted(&mut crate::test::rng(433), 10, 20);
+    }
+
+    #[test]
+    fn test_sample_u64() {
+        let seed_rng = crate::test::rng;
+
+        assert!(sample_u64(&mut seed_rng(430), 0, 0).is_empty());
+        assert_eq!(sample_u64(&mut seed_rng(430), 1, 1).into_vec(), vec![0]);
+
+        for &(length, amount) in &[(10, 6), (1_000_000, 180), (1 << 40, 10), (u64::MAX, 3)] {
+            let mut v = sample_u64(&mut seed_rng(431), length, amount).into_vec();
+            assert_eq!(v.len() as u64, amount);
+            assert!(v.iter().all(|&x| x < length));
+            v.sort_unstable();
+            v.dedup();
+            assert_eq!(v.len() as u64, amount);
+
+            #[cfg(target_pointer_width = "64")]
+            {
+                let w = sample(&mut seed_rng(431), length as usize, amount as usize);
+                assert_eq!(
+                    sample_u64(&mut seed_rng(431), length, amount),
+                    IndexVecU64::from(w)
+                );
+            }
+        }
+
+        let v = sample_u64(&mut seed_rng(432), 1 << 40, 4);
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.index(1), 986125263514);
+        assert_eq!(
+            v.into_vec(),
+            [1017416012343, 986125263514, 833448597734, 350350292964]
+        );
+        let v = sample_u64(&mut seed_rng(432), 1 << 20, 4);
+        assert!(matches!(v, IndexVecU64::U32(_)));
+        assert!(v.clone().into_iter().eq(v.iter()));
+    }
+
+    #[test]
+    fn test_sample_grid() {
+        let mut r = crate::test::rng(433);
+
+        assert!(sample_grid(&mut r, [3, 0], 0).is_empty());
+        assert_eq!(sample_grid(&mut r, [], 1), [[0usize; 0]]);
+        assert_eq!(sample_grid(&mut r, [1, 1, 1], 1), [[0, 0, 0]]);
+
+        let dims = [3, 4, 5];
+        let mut v = sample_grid(&mut r, dims, 60);
+        v.sort_unstable();
+        let mut expected = Vec::new();
+        for a in 0..3 {
+            for b in 0..4 {
+                for c in 0..5 {
+                    expected.push([a, b, c]);
+                }
+            }
+        }
+        assert_eq!(v, expected);
+
+        // Points equal the decoded results of `sample`
+        let v = sample_grid(&mut crate::test::rng(434), [1 << 10, 5000], 10);
+        let flat = sample(&mut crate::test::rng(434), 5000 << 10, 10);
+        assert_eq!(v.len(), 10);
+        for (point, i) in v.iter().zip(flat) {
+            assert_eq!(*point, [i / 5000, i % 5000]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_grid_overflow() {
+        sample_grid(&mut crate::test::rng(435), [usize::MAX, 2], 1);
     }
 
     #[test]
@@ -682,6 +1575,9 @@ mod test {
         do_test(300, 80, &[31, 289, 248, 154, 221, 243, 7, 192]); // inplace
         do_test(300, 180, &[31, 289, 248, 154, 221, 243, 7, 192]); // inplace
 
+        do_test(300, 200, &[31, 289, 248, 154, 221, 243, 7, 192]); // inplace
+        do_test(10_000, 500, &[1037, 9634, 8264, 5091, 7363, 8070, 53, 6325]); // bitset
+
         do_test(
             1_000_000,
             8,
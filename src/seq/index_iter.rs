@@ -0,0 +1,426 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Allocation-free streaming index sampling
+
+use crate::Rng;
+use core::iter::FusedIterator;
+
+/// Randomly sample exactly `amount` distinct indices from `0..length`,
+/// yielding them lazily in increasing order.
+///
+/// Unlike [`sample`](super::index::sample), this does not allocate and requires only
+/// `O(1)` auxiliary memory, thus it is available in `no_std` contexts.
+///
+/// This is implemented via sequential random sampling (based on Vitter's
+/// "Method A"): each yielded index costs one random number, plus the cost of
+/// computing the gap to the previous index. This gap is computed either
+/// by a linear scan or by binary search over its distribution, whichever is
+/// estimated to be cheaper, so that sampling a few indices from a very large
+/// `length` is fast.
+///
+/// If random order is required, the caller must shuffle the output.
+///
+/// Panics if `amount > length`.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index;
+///
+/// let mut rng = rand::rng();
+/// let mut prev = None;
+/// for i in index::sample_iter(&mut rng, usize::MAX, 5) {
+///     assert!(prev < Some(i));
+///     prev = Some(i);
+/// }
+/// ```
+#[track_caller]
+pub fn sample_iter<R>(rng: &mut R, length: usize, amount: usize) -> SampleIter<'_, R>
+where
+    R: Rng + ?Sized,
+{
+    assert!(
+        amount <= length,
+        "`amount` of samples must be less than or equal to `length`"
+    );
+    SampleIter {
+        rng,
+        next: 0,
+        length,
+        amount,
+    }
+}
+
+/// An iterator over distinct indices in increasing order
+///
+/// This struct is created by [`sample_iter`].
+#[derive(Debug)]
+pub struct SampleIter<'a, R: ?Sized> {
+    rng: &'a mut R,
+    /// First candidate index
+    next: usize,
+    /// Number of remaining candidates, starting at `next`
+    length: usize,
+    /// Number of indices remaining to be yielded
+    amount: usize,
+}
+
+impl<R: Rng + ?Sized> SampleIter<'_, R> {
+    /// Sample the number of candidates to skip before the next selection
+    fn skip(&mut self) -> usize {
+        let (n, len) = (self.amount, self.length);
+        debug_assert!(0 < n && n <= len);
+        if n == len {
+            return 0;
+        }
+        if n == 1 {
+            return self.rng.random_range(..len);
+        }
+
+        // The skip `s` is the least value satisfying `V >= P(S > s)`, where
+        // P(S > s) = Π_{k=0}^{n-1} (len - s - 1 - k) / (len - k).
+        let v: f64 = self.rng.random();
+
+        if n.saturating_mul(n).saturating_mul(64) < len {
+            // Binary search: cost is O(n log len)
+            let survival = |s: usize| -> f64 {
+                let mut p = 1.0;
+                for k in 0..n {
+                    p *= (len - s - 1 - k) as f64 / (len - k) as f64;
+                }
+                p
+            };
+            let (mut lo, mut hi) = (0, len - n);
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if survival(mid) > v {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        } else {
+            // Linear scan: expected cost is O(len / n)
+            let mut s = 0;
+            let mut top = (len - n) as f64;
+            let mut total = len as f64;
+            let mut quot = top / total;
+            while quot > v {
+                s += 1;
+                top -= 1.0;
+                total -= 1.0;
+                quot *= top / total;
+            }
+            s
+        }
+    }
+}
+
+impl<R: Rng + ?Sized> Iterator for SampleIter<'_, R> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.amount == 0 {
+            return None;
+        }
+        let s = self.skip();
+        let index = self.next + s;
+        self.next = index + 1;
+        self.length -= s + 1;
+        self.amount -= 1;
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.amount, Some(self.amount))
+    }
+}
+
+impl<R: Rng + ?Sized> ExactSizeIterator for SampleIter<'_, R> {}
+
+impl<R: Rng + ?Sized> FusedIterator for SampleIter<'_, R> {}
+
+/// Randomly choose one set bit from a bitmap
+///
+/// The bitmap `mask` is interpreted as a sequence of bits where the bit at
+/// index `i` is `(mask[i / 64] >> (i % 64)) & 1`. Returns the index of a
+/// uniformly sampled set bit, or `None` if no bit is set.
+///
+/// This does not allocate; complexity is `O(mask.len())`.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index;
+///
+/// let mut rng = rand::rng();
+/// let alive: u64 = 0b1011_0000;
+/// let i = index::choose_from_mask(&mut rng, &[alive]).unwrap();
+/// assert!([4, 5, 7].contains(&i));
+/// assert_eq!(index::choose_from_mask(&mut rng, &[0, 0]), None);
+/// ```
+pub fn choose_from_mask<R>(rng: &mut R, mask: &[u64]) -> Option<usize>
+where
+    R: Rng + ?Sized,
+{
+    let count = count_ones(mask);
+    if count == 0 {
+        return None;
+    }
+    let mut rank = rng.random_range(..count);
+    for (i, &word) in mask.iter().enumerate() {
+        let ones = word.count_ones() as usize;
+        if rank < ones {
+            return Some(i * 64 + select_bit(word, rank));
+        }
+        rank -= ones;
+    }
+    unreachable!()
+}
+
+/// Randomly sample exactly `amount` distinct set bits from a bitmap,
+/// yielding their indices lazily in increasing order.
+///
+/// The bitmap `mask` is interpreted as for [`choose_from_mask`]. Set bits are
+/// selected via [`sample_iter`], thus this does not allocate; complexity is
+/// `O(mask.len())` plus the cost of [`sample_iter`].
+///
+/// If random order is required, the caller must shuffle the output.
+///
+/// Panics if `amount` exceeds the number of set bits.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::index;
+///
+/// let mut rng = rand::rng();
+/// let alive = [u64::MAX, 0, 0b1111];
+/// for i in index::sample_from_mask(&mut rng, &alive, 3) {
+///     assert!(i < 64 || (128..132).contains(&i));
+/// }
+/// ```
+#[track_caller]
+pub fn sample_from_mask<'a, R>(
+    rng: &'a mut R,
+    mask: &'a [u64],
+    amount: usize,
+) -> SampleMaskIter<'a, R>
+where
+    R: Rng + ?Sized,
+{
+    let count = count_ones(mask);
+    assert!(
+        amount <= count,
+        "`amount` of samples must be less than or equal to the number of set bits"
+    );
+    SampleMaskIter {
+        ranks: sample_iter(rng, count, amount),
+        mask,
+        word: 0,
+        skipped: 0,
+    }
+}
+
+/// An iterator over distinct set bit indices in increasing order
+///
+/// This struct is created by [`sample_from_mask`].
+#[derive(Debug)]
+pub struct SampleMaskIter<'a, R: ?Sized> {
+    ranks: SampleIter<'a, R>,
+    mask: &'a [u64],
+    /// Index of the current word
+    word: usize,
+    /// Number of set bits in words before the current word
+    skipped: usize,
+}
+
+impl<R: Rng + ?Sized> Iterator for SampleMaskIter<'_, R> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let rank = self.ranks.next()?;
+        loop {
+            let word = self.mask[self.word];
+            let ones = word.count_ones() as usize;
+            if rank < self.skipped + ones {
+                return Some(self.word * 64 + select_bit(word, rank - self.skipped));
+            }
+            self.skipped += ones;
+            self.word += 1;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ranks.size_hint()
+    }
+}
+
+impl<R: Rng + ?Sized> ExactSizeIterator for SampleMaskIter<'_, R> {}
+
+impl<R: Rng + ?Sized> FusedIterator for SampleMaskIter<'_, R> {}
+
+fn count_ones(mask: &[u64]) -> usize {
+    mask.iter().map(|word| word.count_ones() as usize).sum()
+}
+
+/// Index of the set bit of `word` with the given `rank` (counting from zero)
+fn select_bit(mut word: u64, rank: usize) -> usize {
+    debug_assert!(rank < word.count_ones() as usize);
+    for _ in 0..rank {
+        word &= word - 1;
+    }
+    word.trailing_zeros() as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sample_iter_boundaries() {
+        let mut r = crate::test::rng(500);
+
+        assert_eq!(sample_iter(&mut r, 0, 0).next(), None);
+        assert_eq!(sample_iter(&mut r, 1, 0).next(), None);
+
+        let mut iter = sample_iter(&mut r, 1, 1);
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = sample_iter(&mut r, 10, 10);
+        for i in 0..10 {
+            assert_eq!(iter.next(), Some(i));
+        }
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_iter_too_many() {
+        let mut r = crate::test::rng(501);
+        sample_iter(&mut r, 5, 6);
+    }
+
+    #[test]
+    fn test_sample_iter_sorted_distinct() {
+        let mut r = crate::test::rng(502);
+        for &(length, amount) in &[
+            (10, 3),
+            (100, 50),
+            (1000, 999),
+            (1 << 20, 2),
+            (1 << 20, 100),
+            (usize::MAX, 10),
+        ] {
+            let mut count = 0;
+            let mut prev = None;
+            for i in sample_iter(&mut r, length, amount) {
+                assert!(i < length);
+                assert!(prev < Some(i));
+                prev = Some(i);
+                count += 1;
+            }
+            assert_eq!(count, amount);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_sample_iter_uniform() {
+        let mut r = crate::test::rng(503);
+        // Both the linear and binary search strategies are exercised
+        for &(length, amount) in &[(5, 2), (400, 2)] {
+            let mut counts = [0i32; 5];
+            for _ in 0..5000 {
+                for i in sample_iter(&mut r, length, amount) {
+                    counts[i * 5 / length] += 1;
+                }
+            }
+            // Each fifth of the range has 2000 hits on average
+            for count in counts.iter() {
+                assert!(1850 <= *count && *count <= 2150, "counts: {:?}", counts);
+            }
+        }
+
+        // All 10 subsets of size 2 from 0..5 are seen equally often
+        let mut counts = [[0i32; 5]; 5];
+        for _ in 0..10000 {
+            let mut iter = sample_iter(&mut r, 5, 2);
+            let (a, b) = (iter.next().unwrap(), iter.next().unwrap());
+            counts[a][b] += 1;
+        }
+        for (a, row) in counts.iter().enumerate() {
+            for count in &row[a + 1..] {
+                // Binomial(10000, 0.1) with average 1000
+                assert!(880 <= *count && *count <= 1120, "counts: {:?}", counts);
+            }
+        }
+    }
+
+    #[test]
+    fn test_choose_from_mask() {
+        let mut r = crate::test::rng(505);
+        assert_eq!(choose_from_mask(&mut r, &[]), None);
+        assert_eq!(choose_from_mask(&mut r, &[0, 0]), None);
+        assert_eq!(choose_from_mask(&mut r, &[0, 1 << 63]), Some(127));
+
+        // Each of the 4 set bits is chosen equally often
+        let mask = [1 << 3, 0, (1 << 10) | (1 << 63), 1];
+        let mut counts = [0i32; 4];
+        for _ in 0..4000 {
+            let i = choose_from_mask(&mut r, &mask).unwrap();
+            let pos = [3, 138, 191, 192].iter().position(|&x| x == i).unwrap();
+            counts[pos] += 1;
+        }
+        for count in counts.iter() {
+            // Binomial(4000, 0.25) with average 1000
+            assert!((910..=1090).contains(count), "counts: {:?}", counts);
+        }
+    }
+
+    #[test]
+    fn test_sample_from_mask() {
+        let mut r = crate::test::rng(506);
+        assert_eq!(sample_from_mask(&mut r, &[], 0).next(), None);
+
+        let mask = [0b1010, 0, u64::MAX, 1];
+        let expected = || [1, 3].into_iter().chain(128..192).chain([192]);
+        assert!(sample_from_mask(&mut r, &mask, 67).eq(expected()));
+
+        let mut iter = sample_from_mask(&mut r, &mask, 5);
+        assert_eq!(iter.len(), 5);
+        let mut prev = None;
+        for i in &mut iter {
+            assert!(expected().any(|x| x == i));
+            assert!(prev < Some(i));
+            prev = Some(i);
+        }
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_from_mask_too_many() {
+        let mut r = crate::test::rng(507);
+        sample_from_mask(&mut r, &[0b111], 4);
+    }
+
+    #[test]
+    fn value_stability() {
+        let mut r = crate::test::rng(504);
+        let v: [usize; 4] = {
+            let mut iter = sample_iter(&mut r, 100, 4);
+            core::array::from_fn(|_| iter.next().unwrap())
+        };
+        assert_eq!(v, [38, 53, 66, 69]);
+    }
+}
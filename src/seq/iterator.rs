@@ -10,8 +10,17 @@
 
 #[allow(unused)]
 use super::IndexedRandom;
+#[cfg(feature = "alloc")]
+use super::WeightError;
 use super::coin_flipper::CoinFlipper;
 use crate::Rng;
+#[cfg(feature = "std")]
+use crate::distr::{Distribution, Geometric};
+#[cfg(feature = "alloc")]
+use crate::distr::{
+    uniform::{SampleUniform, UniformSampler},
+    weighted::Weight,
+};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
@@ -185,6 +194,87 @@ pub trait IteratorRandom: Iterator + Sized {
         }
     }
 
+    /// Biased sampling for one element
+    ///
+    /// Returns one element of the iterator, sampled according to the provided
+    /// weights.
+    ///
+    /// The specified function `weight` maps each item `x` to a relative
+    /// likelihood `weight(x)`. The probability of each item being selected is
+    /// therefore `weight(x) / s`, where `s` is the sum of all `weight(x)`.
+    ///
+    /// The iterator is consumed in a single pass, without collecting it first,
+    /// using weighted reservoir sampling of size one. This calls `weight` once
+    /// and samples a random number once for each item with positive weight
+    /// (excluding the first). Complexity is `O(n)` where `n` is the length of
+    /// the iterator. For slices, prefer [`IndexedRandom::choose_weighted`].
+    ///
+    /// Error cases:
+    /// -   [`WeightError::InvalidInput`] when the iterator is empty.
+    /// -   [`WeightError::InvalidWeight`] when a weight is not-a-number or negative.
+    /// -   [`WeightError::InsufficientNonZero`] when all weights are zero.
+    /// -   [`WeightError::Overflow`] when the sum of weights overflows or is not
+    ///     finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::IteratorRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let words = "the quick brown fox jumps over the lazy dog".split(' ');
+    /// // Longer words are more likely to be picked
+    /// let word = words.choose_weighted(&mut rng, |w| w.len()).unwrap();
+    /// println!("{}", word);
+    /// ```
+    ///
+    /// [`WeightError::InvalidInput`]: crate::seq::WeightError::InvalidInput
+    /// [`WeightError::InvalidWeight`]: crate::seq::WeightError::InvalidWeight
+    /// [`WeightError::InsufficientNonZero`]: crate::seq::WeightError::InsufficientNonZero
+    /// [`WeightError::Overflow`]: crate::seq::WeightError::Overflow
+    #[cfg(feature = "alloc")]
+    fn choose_weighted<R, F, X>(self, rng: &mut R, mut weight: F) -> Result<Self::Item, WeightError>
+    where
+        R: Rng + ?Sized,
+        F: FnMut(&Self::Item) -> X,
+        X: SampleUniform + Weight + PartialOrd<X>,
+    {
+        let zero = X::ZERO;
+        let mut is_empty = true;
+        let mut result = None;
+        let mut total = X::ZERO;
+
+        for item in self {
+            is_empty = false;
+            let w = weight(&item);
+            if !(w >= zero) {
+                return Err(WeightError::InvalidWeight);
+            } else if w == zero {
+                continue;
+            }
+
+            total
+                .checked_add_assign(&w)
+                .map_err(|()| WeightError::Overflow)?;
+            // Replace the selected item with probability `w / total`. The
+            // sampler rejects a total which is not finite, e.g. for floats.
+            let replace = if result.is_none() {
+                X::Sampler::new(zero.clone(), total.clone()).map(|_| true)
+            } else {
+                X::Sampler::sample_single(zero.clone(), total.clone(), rng).map(|x| x < w)
+            };
+            if replace.map_err(|_| WeightError::Overflow)? {
+                result = Some(item);
+            }
+        }
+
+        match result {
+            Some(item) => Ok(item),
+            None if is_empty => Err(WeightError::InvalidInput),
+            None => Err(WeightError::InsufficientNonZero),
+        }
+    }
+
     /// Uniformly sample `amount` distinct elements into a buffer
     ///
     /// Collects values at random from the iterator into a supplied buffer
@@ -267,6 +357,204 @@ pub trait IteratorRandom: Iterator + Sized {
         reservoir
     }
 
+    /// Biased sampling of `amount` distinct elements into a [`Vec`]
+    ///
+    /// Similar to [`sample`](IteratorRandom::sample), but where the likelihood
+    /// of each element's inclusion in the output may be specified.
+    /// Zero-weighted elements are never returned; the result may therefore
+    /// contain fewer elements than `amount` even when the iterator has at least
+    /// `amount` elements. The elements are returned in an arbitrary,
+    /// unspecified order.
+    ///
+    /// The specified function `weight` maps each item `x` to a relative
+    /// likelihood `weight(x)`. The probability of each item being selected is
+    /// therefore `weight(x) / s`, where `s` is the sum of all `weight(x)`.
+    ///
+    /// The iterator is consumed in a single pass, without collecting it first.
+    /// This implementation is based on the algorithm A-ExpJ as found in
+    /// [Efraimidis and Spirakis, 2005](https://doi.org/10.1016/j.ipl.2005.11.003).
+    /// It uses `O(amount)` space and `O(n)` time where `n` is the length of
+    /// the iterator. For slices, prefer [`IndexedRandom::sample_weighted`].
+    ///
+    /// Error cases:
+    /// -   [`WeightError::InvalidWeight`] when a weight is not-a-number or negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::IteratorRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let sample = (1..100).sample_weighted(&mut rng, 3, |x| *x as f64).unwrap();
+    /// assert_eq!(sample.len(), 3);
+    /// ```
+    ///
+    /// [`WeightError::InvalidWeight`]: crate::seq::WeightError::InvalidWeight
+    // Note: this is feature-gated on std due to usage of f64::ln.
+    #[cfg(feature = "std")]
+    fn sample_weighted<R, F, X>(
+        mut self,
+        rng: &mut R,
+        amount: usize,
+        mut weight: F,
+    ) -> Result<Vec<Self::Item>, WeightError>
+    where
+        R: Rng + ?Sized,
+        F: FnMut(&Self::Item) -> X,
+        X: Into<f64>,
+    {
+        use std::{cmp::Ordering, collections::BinaryHeap};
+
+        if amount == 0 {
+            return Ok(Vec::new());
+        }
+
+        struct Element<T> {
+            item: T,
+            key: f64,
+        }
+
+        impl<T> PartialOrd for Element<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<T> Ord for Element<T> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                // unwrap() should not panic since weights should not be NaN
+                // We reverse so that BinaryHeap::peek shows the smallest item
+                self.key.partial_cmp(&other.key).unwrap().reverse()
+            }
+        }
+
+        impl<T> PartialEq for Element<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.key == other.key
+            }
+        }
+
+        impl<T> Eq for Element<T> {}
+
+        let mut candidates = BinaryHeap::with_capacity(amount);
+        while candidates.len() < amount {
+            let Some(item) = self.next() else {
+                break;
+            };
+            let weight = weight(&item).into();
+            if weight > 0.0 {
+                // We use the log of the key used in A-ExpJ to improve precision
+                // for small weights:
+                let key = rng.random::<f64>().ln() / weight;
+                candidates.push(Element { item, key });
+            } else if !(weight >= 0.0) {
+                return Err(WeightError::InvalidWeight);
+            }
+        }
+
+        // Continue unless the iterator was exhausted
+        if candidates.len() == amount {
+            let mut x = rng.random::<f64>().ln() / candidates.peek().unwrap().key;
+            for item in self {
+                let weight = weight(&item).into();
+                if weight > 0.0 {
+                    x -= weight;
+                    if x <= 0.0 {
+                        let min_candidate = candidates.pop().unwrap();
+                        let t = (min_candidate.key * weight).exp();
+                        let key = rng.random_range(t..1.0).ln() / weight;
+                        candidates.push(Element { item, key });
+
+                        x = rng.random::<f64>().ln() / candidates.peek().unwrap().key;
+                    }
+                } else if !(weight >= 0.0) {
+                    return Err(WeightError::InvalidWeight);
+                }
+            }
+        }
+
+        Ok(candidates.into_iter().map(|elt| elt.item).collect())
+    }
+
+    /// Lazily keep each element independently with probability `p`
+    ///
+    /// Returns an iterator adaptor yielding a Bernoulli subsample of `self`,
+    /// in the original order. The number of elements to skip before the next
+    /// kept element is sampled from the [`Geometric`] distribution. For
+    /// `p <= 1/3` this costs one random number per *kept* element, so low
+    /// rates `p` are cheap; for `1/3 < p < 1` [`Geometric`] samples one
+    /// [`Bernoulli`](crate::distr::Bernoulli) trial per input element instead,
+    /// and for `p == 1` no random numbers are used.
+    ///
+    /// Skipped elements are passed over via [`Iterator::nth`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p < 0` or `p > 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::IteratorRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let log = (0..1_000_000).map(|i| format!("request {i}"));
+    /// for line in log.bernoulli_sample(&mut rng, 0.0001) {
+    ///     println!("{line}");
+    /// }
+    /// ```
+    // Note: this is feature-gated on std due to usage of f64::ln.
+    #[cfg(feature = "std")]
+    #[track_caller]
+    fn bernoulli_sample<R>(self, rng: &mut R, p: f64) -> BernoulliSample<'_, Self, R>
+    where
+        R: Rng + ?Sized,
+    {
+        let Ok(skip) = Geometric::new(p) else {
+            panic!("probability must be in [0, 1]");
+        };
+        BernoulliSample {
+            iter: self,
+            rng,
+            skip,
+        }
+    }
+
+    /// Lazily pair each element with a Poisson(1)-distributed count
+    ///
+    /// Returns an iterator adaptor yielding `(item, count)` for each element
+    /// of `self`, where each `count` is sampled independently from the
+    /// Poisson distribution with mean 1. This is the *Poisson bootstrap*: a
+    /// streaming approximation of resampling `n` elements with replacement,
+    /// which does not require the length to be known nor the elements to be
+    /// collected.
+    ///
+    /// Roughly 37% of counts are zero; use [`Iterator::filter`] to drop these,
+    /// or [`core::iter::repeat_n`] to expand each element `count` times.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::IteratorRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let data = [2.0, 3.5, 1.0, 4.0, 2.5];
+    /// // One bootstrap replicate of the mean
+    /// let (sum, n) = data
+    ///     .iter()
+    ///     .poisson_bootstrap(&mut rng)
+    ///     .fold((0.0, 0), |(sum, n), (x, count)| (sum + x * count as f64, n + count));
+    /// if n > 0 {
+    ///     println!("Replicate mean: {}", sum / n as f64);
+    /// }
+    /// ```
+    fn poisson_bootstrap<R>(self, rng: &mut R) -> PoissonBootstrap<'_, Self, R>
+    where
+        R: Rng + ?Sized,
+    {
+        PoissonBootstrap { iter: self, rng }
+    }
+
     /// Deprecated: use [`Self::sample_fill`] instead
     #[deprecated(since = "0.9.2", note = "Renamed to `sample_fill`")]
     fn choose_multiple_fill<R>(self, rng: &mut R, buf: &mut [Self::Item]) -> usize
@@ -289,6 +577,79 @@ pub trait IteratorRandom: Iterator + Sized {
 
 impl<I> IteratorRandom for I where I: Iterator + Sized {}
 
+/// An iterator adaptor pairing each element with a Poisson(1) count
+///
+/// This struct is created by [`IteratorRandom::poisson_bootstrap`].
+#[derive(Debug)]
+pub struct PoissonBootstrap<'a, I, R: ?Sized> {
+    iter: I,
+    rng: &'a mut R,
+}
+
+impl<I: Iterator, R: Rng + ?Sized> Iterator for PoissonBootstrap<'_, I, R> {
+    type Item = (I::Item, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        // Sample Poisson(1) by inversion; P(k) = e^-1 / k!
+        //
+        // Counts beyond 32 have far smaller probability than the resolution
+        // of `u`, thus the bound has no effect.
+        let mut u: f64 = self.rng.random();
+        let mut p = 1.0 / core::f64::consts::E;
+        let mut count = 0;
+        while u >= p && count < 32 {
+            u -= p;
+            count += 1;
+            p /= f64::from(count);
+        }
+        Some((item, count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator, R: Rng + ?Sized> ExactSizeIterator for PoissonBootstrap<'_, I, R> {}
+
+/// An iterator adaptor keeping each element with fixed probability
+///
+/// This struct is created by [`IteratorRandom::bernoulli_sample`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct BernoulliSample<'a, I, R: ?Sized> {
+    iter: I,
+    rng: &'a mut R,
+    /// The distribution of the number of elements skipped
+    skip: Geometric,
+}
+
+#[cfg(feature = "std")]
+impl<I: Iterator, R: Rng + ?Sized> Iterator for BernoulliSample<'_, I, R> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        // Skipping u64::MAX elements is as good as skipping all
+        let skip = self.skip.sample(self.rng);
+        if skip == u64::MAX {
+            return None;
+        }
+        self.iter.nth(usize::try_from(skip).unwrap_or(usize::MAX))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.skip.p() == 1.0 {
+            self.iter.size_hint()
+        } else if self.skip.p() == 0.0 {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -576,6 +937,65 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_bernoulli_sample() {
+        let mut r = crate::test::rng(612);
+        assert_eq!((0..100).bernoulli_sample(&mut r, 0.0).next(), None);
+        assert!((0..100).bernoulli_sample(&mut r, 1.0).eq(0..100));
+
+        // Each element is kept with probability 0.1
+        let mut counts = [0i32; 10];
+        let mut total = 0;
+        for _ in 0..1000 {
+            let mut prev = None;
+            for i in (0..100).bernoulli_sample(&mut r, 0.1) {
+                assert!(prev < Some(i));
+                prev = Some(i);
+                counts[i / 10] += 1;
+                total += 1;
+            }
+        }
+        // Binomial(100000, 0.1) with average 10000
+        assert!((9600..=10400).contains(&total), "total: {}", total);
+        for count in counts.iter() {
+            // Binomial(10000, 0.1) with average 1000
+            assert!((880..=1120).contains(count), "counts: {:?}", counts);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[should_panic]
+    fn test_bernoulli_sample_invalid_p() {
+        let _ = (0..10).bernoulli_sample(&mut crate::test::rng(613), 1.5);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_poisson_bootstrap() {
+        let mut r = crate::test::rng(614);
+        let mut iter = (0..3).poisson_bootstrap(&mut r);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next().map(|(x, _)| x), Some(0));
+        assert_eq!(iter.by_ref().count(), 2);
+        assert_eq!(iter.next(), None);
+
+        // Frequencies of counts match Poisson(1)
+        let mut freq = [0i32; 4];
+        for (_, count) in (0..10000).poisson_bootstrap(&mut r) {
+            freq[(count as usize).min(3)] += 1;
+        }
+        // Expected: 3679, 3679, 1839 and 803 (counts of 3 or more)
+        for (count, (lo, hi)) in
+            freq.iter()
+                .zip([(3480, 3880), (3480, 3880), (1680, 2000), (690, 920)])
+        {
+            assert!((lo..=hi).contains(count), "freq: {:?}", freq);
+        }
+    }
+
     #[test]
     fn value_stability_choose() {
         fn choose<I: Iterator<Item = u32>>(iter: I) -> Option<u32> {
@@ -687,4 +1107,132 @@ mod test {
         do_test(0..8, &[0, 1, 2, 3, 4, 5, 6, 7]);
         do_test(0..100, &[77, 95, 38, 23, 25, 8, 58, 40]);
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_choose_weighted_iter() {
+        let mut r = crate::test::rng(418);
+        let choices = [('a', 2), ('b', 1), ('c', 0), ('d', 1)];
+        let mut counts = [0i32; 4];
+        for _ in 0..4000 {
+            let (c, _) = choices
+                .iter()
+                .choose_weighted(&mut r, |item| item.1)
+                .unwrap();
+            counts[(*c as u8 - b'a') as usize] += 1;
+        }
+        // Expected counts are 2000, 1000, 0, 1000
+        assert!(1880 <= counts[0] && counts[0] <= 2120, "{:?}", counts);
+        assert!(880 <= counts[1] && counts[1] <= 1120, "{:?}", counts);
+        assert_eq!(counts[2], 0);
+        assert!(880 <= counts[3] && counts[3] <= 1120, "{:?}", counts);
+
+        let mut counts = [0i32; 3];
+        for _ in 0..3000 {
+            let x = [0.5f64, 1.0, 1.5]
+                .into_iter()
+                .choose_weighted(&mut r, |x| *x)
+                .unwrap();
+            counts[(x * 2.0) as usize - 1] += 1;
+        }
+        // Expected counts are 500, 1000, 1500
+        assert!(420 <= counts[0] && counts[0] <= 580, "{:?}", counts);
+        assert!(900 <= counts[1] && counts[1] <= 1100, "{:?}", counts);
+        assert!(1380 <= counts[2] && counts[2] <= 1620, "{:?}", counts);
+
+        assert_eq!(
+            core::iter::empty::<u32>().choose_weighted(&mut r, |_| 1),
+            Err(WeightError::InvalidInput)
+        );
+        assert_eq!(
+            (0..5).choose_weighted(&mut r, |_| 0),
+            Err(WeightError::InsufficientNonZero)
+        );
+        assert_eq!(
+            (0..5).choose_weighted(&mut r, |x| if *x == 3 { -1 } else { 1 }),
+            Err(WeightError::InvalidWeight)
+        );
+        assert_eq!(
+            (0..5).choose_weighted(&mut r, |_| f64::NAN),
+            Err(WeightError::InvalidWeight)
+        );
+        assert_eq!(
+            (0..5).choose_weighted(&mut r, |_| u8::MAX),
+            Err(WeightError::Overflow)
+        );
+        assert_eq!(
+            (0..5).choose_weighted(&mut r, |_| f64::INFINITY),
+            Err(WeightError::Overflow)
+        );
+        assert_eq!(
+            [1.0, f64::INFINITY].iter().choose_weighted(&mut r, |x| **x),
+            Err(WeightError::Overflow)
+        );
+        assert_eq!(
+            [f64::MAX, f64::MAX].iter().choose_weighted(&mut r, |x| **x),
+            Err(WeightError::Overflow)
+        );
+        assert_eq!((0..5).choose_weighted(&mut r, |x| (*x == 2) as u8), Ok(2));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sample_weighted_iter() {
+        let mut r = crate::test::rng(415);
+
+        // Zero weights are never selected, and may shorten the result
+        for _ in 0..100 {
+            let result = [('a', 2), ('b', 1), ('c', 0)]
+                .into_iter()
+                .sample_weighted(&mut r, 2, |item| item.1)
+                .unwrap();
+            assert_eq!(result.len(), 2);
+            assert!(!result.iter().any(|item| item.0 == 'c'));
+        }
+        let result = [0, 0, 3].into_iter().sample_weighted(&mut r, 2, |x| *x);
+        assert_eq!(result.unwrap(), [3]);
+        assert_eq!(
+            (0..10).sample_weighted(&mut r, 0, |_| 1).unwrap(),
+            Vec::<i32>::new()
+        );
+        assert_eq!(
+            UnhintedIterator { iter: 0..5 }
+                .sample_weighted(&mut r, 10, |_| 1.0)
+                .unwrap()
+                .len(),
+            5
+        );
+
+        // Invalid weights are detected in both phases
+        let r1 = [1.0, -1.0, 1.0]
+            .into_iter()
+            .sample_weighted(&mut r, 2, |x| *x);
+        assert_eq!(r1.unwrap_err(), WeightError::InvalidWeight);
+        let r2 = [1.0, 1.0, f64::NAN]
+            .into_iter()
+            .sample_weighted(&mut r, 2, |x| *x);
+        assert_eq!(r2.unwrap_err(), WeightError::InvalidWeight);
+
+        // See test_multiple_weighted_distributions in slice.rs
+        let mut results = [0i32; 3];
+        let expected_results = [5833, 2667, 1500];
+        for _ in 0..10000 {
+            let mut result = UnhintedIterator {
+                iter: [('a', 3), ('b', 2), ('c', 1)].into_iter(),
+            }
+            .sample_weighted(&mut r, 2, |item| item.1)
+            .unwrap();
+            result.sort_unstable();
+            match (result[0].0, result[1].0) {
+                ('a', 'b') => results[0] += 1,
+                ('a', 'c') => results[1] += 1,
+                ('b', 'c') => results[2] += 1,
+                (_, _) => panic!("unexpected result"),
+            }
+        }
+        for (a, b) in results.iter().zip(&expected_results) {
+            assert!((a - b).abs() <= 150, "{:?}", results);
+        }
+    }
 }
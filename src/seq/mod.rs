@@ -13,9 +13,15 @@
 //! *   [`IndexedRandom`] for sampling slices and other indexable lists
 //! *   [`IndexedMutRandom`] for sampling slices and other mutably indexable lists
 //! *   [`SliceRandom`] for mutating slices
+//! *   [`SwapRandom`] for shuffling other containers supporting swaps
+//! *   [`ArrayRandom`] for sampling non-empty arrays
 //! *   [`IteratorRandom`] for sampling iterators
+//! *   [`Permutation`] for storing, inverting and composing random
+//!     permutations
 //! *   [`index::sample`] low-level API to choose multiple indices from
 //!     `0..length`
+//! *   [`index::sample_iter`] allocation-free API to lazily choose multiple
+//!     indices from `0..length`
 //!
 //! Also see:
 //!
@@ -28,31 +34,47 @@
 
 mod coin_flipper;
 mod increasing_uniform;
+mod index_iter;
 mod iterator;
+#[cfg(feature = "alloc")]
+mod permutation;
 mod slice;
 
 #[cfg(feature = "alloc")]
 #[path = "index.rs"]
 mod index_;
 
-#[cfg(feature = "alloc")]
 #[doc(no_inline)]
 pub use crate::distr::weighted::Error as WeightError;
-pub use iterator::IteratorRandom;
+#[cfg(feature = "std")]
+pub use iterator::BernoulliSample;
+pub use iterator::{IteratorRandom, PoissonBootstrap};
+#[cfg(feature = "alloc")]
+pub use permutation::Permutation;
 #[cfg(feature = "alloc")]
 pub use slice::IndexedSamples;
 #[allow(deprecated)]
 #[cfg(feature = "alloc")]
 pub use slice::SliceChooseIter;
-pub use slice::{IndexedMutRandom, IndexedRandom, SliceRandom};
+pub use slice::{
+    ArrayRandom, IndexedMutRandom, IndexedRandom, ShuffledIter, SliceRandom, SwapRandom,
+};
 
 /// Low-level API for sampling indices
 pub mod index {
+    use super::WeightError;
     use crate::Rng;
+    use crate::distr::{
+        uniform::{SampleUniform, UniformSampler},
+        weighted::Weight,
+    };
 
     #[cfg(feature = "alloc")]
     #[doc(inline)]
     pub use super::index_::*;
+    pub use super::index_iter::{
+        SampleIter, SampleMaskIter, choose_from_mask, sample_from_mask, sample_iter,
+    };
 
     /// Randomly sample exactly `N` distinct indices from `0..len`, and
     /// return them in random order (fully shuffled).
@@ -80,4 +102,154 @@ pub mod index {
         }
         Some(indices)
     }
+
+    /// Randomly sample exactly `N` distinct indices from `0..len` with
+    /// probability proportional to their weights, without allocation.
+    ///
+    /// Indices are selected by successive sampling: the first index is chosen
+    /// with probability proportional to its weight, the next proportionally
+    /// to its weight among the remaining indices, and so on. Output is in
+    /// order of selection. Zero-weighted indices are never selected.
+    ///
+    /// Function `weight` is called up to `2 N` times for each index and
+    /// must return the same result each time. Time complexity is
+    /// `O(N^2 len)` and memory complexity is `O(N)`, which is suitable for
+    /// small `N`.
+    ///
+    /// Error cases:
+    /// -   [`WeightError::InvalidWeight`] when a weight is not-a-number or negative.
+    /// -   [`WeightError::InsufficientNonZero`] when fewer than `N` weights are
+    ///     positive.
+    /// -   [`WeightError::Overflow`] when the sum of weights overflows or is not
+    ///     finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::index;
+    ///
+    /// let mut rng = rand::rng();
+    /// let channel_weights = [4u32, 0, 1, 2, 8, 1, 0, 3];
+    /// let channels: [usize; 3] =
+    ///     index::sample_array_weighted(&mut rng, 8, |i| channel_weights[i]).unwrap();
+    /// assert!(channels.iter().all(|&i| channel_weights[i] > 0));
+    /// ```
+    pub fn sample_array_weighted<R, F, X, const N: usize>(
+        rng: &mut R,
+        len: usize,
+        weight: F,
+    ) -> Result<[usize; N], WeightError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(usize) -> X,
+        X: SampleUniform + Weight + PartialOrd<X>,
+    {
+        let mut indices = [0; N];
+        for k in 0..N {
+            let chosen = &indices[..k];
+            let mut total = X::ZERO;
+            for i in (0..len).filter(|i| !chosen.contains(i)) {
+                let w = weight(i);
+                if !(w >= X::ZERO) {
+                    return Err(WeightError::InvalidWeight);
+                }
+                total
+                    .checked_add_assign(&w)
+                    .map_err(|()| WeightError::Overflow)?;
+            }
+            if !(total > X::ZERO) {
+                return Err(WeightError::InsufficientNonZero);
+            }
+
+            // The sampler rejects a total which is not finite, e.g. for floats
+            let target = X::Sampler::sample_single(X::ZERO, total, rng)
+                .map_err(|_| WeightError::Overflow)?;
+            // Summation is repeated in the same order, thus target < sum for
+            // some index with positive weight. Each `sum` is a partial sum of
+            // `total`, hence overflow is only possible if `weight` returns
+            // inconsistent results.
+            let mut sum = X::ZERO;
+            let mut last = 0;
+            for i in (0..len).filter(|i| !chosen.contains(i)) {
+                let w = weight(i);
+                if w > X::ZERO {
+                    sum.checked_add_assign(&w)
+                        .map_err(|()| WeightError::Overflow)?;
+                    last = i;
+                    if target < sum {
+                        break;
+                    }
+                }
+            }
+            indices[k] = last;
+        }
+        Ok(indices)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_sample_array_weighted() {
+        let mut r = crate::test::rng(436);
+        let weights = [1u32, 0, 2, 0, 3, 4];
+
+        let none: [usize; 0] = index::sample_array_weighted(&mut r, 6, |i| weights[i]).unwrap();
+        assert!(none.is_empty());
+
+        let all: [usize; 4] = index::sample_array_weighted(&mut r, 6, |i| weights[i]).unwrap();
+        let mut sorted = all;
+        sorted.sort_unstable();
+        assert_eq!(sorted, [0, 2, 4, 5]);
+
+        assert_eq!(
+            index::sample_array_weighted::<_, _, _, 5>(&mut r, 6, |i| weights[i]),
+            Err(WeightError::InsufficientNonZero)
+        );
+        assert_eq!(
+            index::sample_array_weighted::<_, _, _, 1>(&mut r, 0, |_| 1),
+            Err(WeightError::InsufficientNonZero)
+        );
+        assert_eq!(
+            index::sample_array_weighted::<_, _, _, 1>(&mut r, 3, |i| i as f64 - 1.0),
+            Err(WeightError::InvalidWeight)
+        );
+        assert_eq!(
+            index::sample_array_weighted::<_, _, _, 1>(&mut r, 3, |_| u8::MAX),
+            Err(WeightError::Overflow)
+        );
+        let inf_weights = [1.0, f64::INFINITY, 2.0];
+        assert_eq!(
+            index::sample_array_weighted::<_, _, _, 1>(&mut r, 3, |i| inf_weights[i]),
+            Err(WeightError::Overflow)
+        );
+        assert_eq!(
+            index::sample_array_weighted::<_, _, _, 1>(&mut r, 2, |_| f64::MAX),
+            Err(WeightError::Overflow)
+        );
+
+        // First selection is proportional to weight; the second is
+        // proportional among the remaining: P(first = 2, second = 0) =
+        // 2/10 * 1/8
+        let mut first = [0i32; 6];
+        let mut pair = 0;
+        for _ in 0..10000 {
+            let [a, b] = index::sample_array_weighted(&mut r, 6, |i| weights[i] as f64).unwrap();
+            assert_ne!(a, b);
+            first[a] += 1;
+            pair += (a == 2 && b == 0) as i32;
+        }
+        for (w, count) in weights.iter().zip(first.iter()) {
+            let expected = *w as i32 * 1000;
+            assert!(
+                (expected - 150..=expected + 150).contains(count),
+                "{:?}",
+                first
+            );
+        }
+        assert!((200..=300).contains(&pair), "{}", pair);
+    }
 }
@@ -0,0 +1,221 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `Permutation`
+
+use super::SliceRandom;
+use crate::Rng;
+use alloc::vec::Vec;
+
+/// A permutation of `0..n`
+///
+/// A `Permutation` describes a reordering of a sequence of length `n`:
+/// applying it to a sequence `x` yields the sequence `y` where
+/// `y[i] = x[p[i]]`, with `p` being the [slice](Self::as_slice) of source
+/// indices.
+///
+/// Unlike [`SliceRandom::shuffle`], a `Permutation` may be stored, applied to
+/// multiple sequences, and [inverted](Self::inverse), e.g. to restore results
+/// computed on shuffled data to their original order.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::Permutation;
+///
+/// let mut rng = rand::rng();
+/// let data = ["a", "b", "c", "d"];
+/// let perm = Permutation::random(&mut rng, data.len());
+/// let shuffled = perm.apply(&data);
+///
+/// // Un-shuffle the results
+/// let results: Vec<String> = shuffled.iter().map(|x| x.to_uppercase()).collect();
+/// assert_eq!(perm.inverse().apply(&results), ["A", "B", "C", "D"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    indices: Vec<usize>,
+}
+
+impl Permutation {
+    /// Construct the identity permutation of `0..n`
+    pub fn identity(n: usize) -> Self {
+        Permutation {
+            indices: (0..n).collect(),
+        }
+    }
+
+    /// Sample a uniformly random permutation of `0..n`
+    ///
+    /// Given equal RNG state, applying the result to a slice is equivalent to
+    /// calling [`SliceRandom::shuffle`] on that slice.
+    ///
+    /// Complexity is `O(n)`.
+    pub fn random<R>(rng: &mut R, n: usize) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let mut perm = Permutation::identity(n);
+        perm.indices.shuffle(rng);
+        perm
+    }
+
+    /// Construct from a `Vec` of source indices
+    ///
+    /// Returns `None` unless `indices` is a permutation of `0..indices.len()`.
+    pub fn from_indices(indices: Vec<usize>) -> Option<Self> {
+        let mut seen = alloc::vec![false; indices.len()];
+        for &i in &indices {
+            let seen = seen.get_mut(i)?;
+            if *seen {
+                return None;
+            }
+            *seen = true;
+        }
+        Some(Permutation { indices })
+    }
+
+    /// The length `n` of sequences this permutation is applicable to
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// True when the length is zero
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// The source index of each position
+    #[inline]
+    pub fn as_slice(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Apply the permutation, returning a new `Vec`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice.len() != self.len()`.
+    #[track_caller]
+    pub fn apply<T: Clone>(&self, slice: &[T]) -> Vec<T> {
+        assert_eq!(slice.len(), self.len(), "length mismatch");
+        self.indices.iter().map(|&i| slice[i].clone()).collect()
+    }
+
+    /// Apply the permutation in place
+    ///
+    /// This requires `O(n)` temporary memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice.len() != self.len()`.
+    #[track_caller]
+    pub fn apply_in_place<T>(&self, slice: &mut [T]) {
+        assert_eq!(slice.len(), self.len(), "length mismatch");
+        permute(slice, &mut self.indices.clone());
+    }
+
+    /// The inverse permutation
+    ///
+    /// Applying `self` then `self.inverse()` restores the original order.
+    pub fn inverse(&self) -> Self {
+        let mut indices = alloc::vec![0; self.len()];
+        for (i, &j) in self.indices.iter().enumerate() {
+            indices[j] = i;
+        }
+        Permutation { indices }
+    }
+
+    /// Compose with `other`
+    ///
+    /// Applying the result is equivalent to applying `self`, then `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other.len() != self.len()`.
+    #[track_caller]
+    pub fn compose(&self, other: &Permutation) -> Self {
+        assert_eq!(other.len(), self.len(), "length mismatch");
+        Permutation {
+            indices: other.indices.iter().map(|&i| self.indices[i]).collect(),
+        }
+    }
+}
+
+/// Apply a permutation in place, moving the element at `order[i]` to `i`.
+///
+/// Cycles are followed such that each element is swapped into place exactly
+/// once. Completed positions are marked by setting `order[i] = i`.
+pub(crate) fn permute<T>(slice: &mut [T], order: &mut [usize]) {
+    debug_assert_eq!(slice.len(), order.len());
+    for start in 0..order.len() {
+        let mut i = start;
+        loop {
+            let src = order[i];
+            order[i] = i;
+            if src == start {
+                break;
+            }
+            slice.swap(i, src);
+            i = src;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_permutation() {
+        let mut r = crate::test::rng(140);
+        let x: Vec<u32> = (10..20).collect();
+
+        let id = Permutation::identity(10);
+        assert_eq!(id.apply(&x), x);
+        assert!(Permutation::identity(0).is_empty());
+
+        let p = Permutation::random(&mut r, 10);
+        let q = Permutation::random(&mut r, 10);
+        assert_eq!(p.len(), 10);
+
+        let mut y = x.clone();
+        p.apply_in_place(&mut y);
+        assert_eq!(y, p.apply(&x));
+
+        assert_eq!(p.inverse().apply(&p.apply(&x)), x);
+        assert_eq!(p.apply(&p.inverse().apply(&x)), x);
+        assert_eq!(p.compose(&p.inverse()), id);
+        assert_eq!(p.compose(&q).apply(&x), q.apply(&p.apply(&x)));
+
+        assert_eq!(
+            Permutation::from_indices(p.as_slice().to_vec()),
+            Some(p.clone())
+        );
+        assert_eq!(Permutation::from_indices(vec![0, 2]), None);
+        assert_eq!(Permutation::from_indices(vec![1, 1]), None);
+    }
+
+    #[test]
+    fn test_random_matches_shuffle() {
+        let x: Vec<u32> = (0..100).collect();
+        let p = Permutation::random(&mut crate::test::rng(141), x.len());
+        let mut y = x.clone();
+        y.shuffle(&mut crate::test::rng(141));
+        assert_eq!(p.apply(&x), y);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_apply_length_mismatch() {
+        Permutation::identity(3).apply(&[1, 2]);
+    }
+}
@@ -10,18 +10,24 @@
 
 use super::increasing_uniform::IncreasingUniform;
 use super::index;
+#[cfg(feature = "alloc")]
+use super::permutation::permute;
 use crate::Rng;
 #[cfg(feature = "alloc")]
 use crate::distr::uniform::{SampleBorrow, SampleUniform};
 #[cfg(feature = "alloc")]
 use crate::distr::weighted::{Error as WeightError, Weight};
-use core::ops::{Index, IndexMut};
+use crate::distr::{Bernoulli, Distribution};
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec::Vec};
+use core::ops::{Index, IndexMut, RangeBounds};
 
 /// Extension trait on indexable lists, providing random sampling methods.
 ///
-/// This trait is implemented on `[T]` slice types. Other types supporting
-/// [`std::ops::Index<usize>`] may implement this (only [`Self::len`] must be
-/// specified).
+/// This trait is implemented on `[T]` slice types and (with the `alloc`
+/// feature) on [`VecDeque<T>`](alloc::collections::VecDeque). Other types
+/// supporting [`std::ops::Index<usize>`] may implement this (only
+/// [`Self::len`] must be specified).
 pub trait IndexedRandom: Index<usize> {
     /// The length
     fn len(&self) -> usize;
@@ -62,8 +68,15 @@ pub trait IndexedRandom: Index<usize> {
 
     /// Return an iterator which samples from `self` with replacement
     ///
+    /// The iterator yields an unbounded sequence of independent, uniformly
+    /// sampled elements; use [`Iterator::take`] to draw `amount` elements.
+    /// The underlying [`Uniform`] distribution is constructed only once, thus
+    /// this is faster than repeated calls to [`IndexedRandom::choose`].
+    ///
     /// Returns `None` if and only if `self.is_empty()`.
     ///
+    /// For sampling without replacement, see [`IndexedRandom::sample`].
+    ///
     /// # Example
     ///
     /// ```
@@ -74,7 +87,19 @@ pub trait IndexedRandom: Index<usize> {
     /// for choice in choices.choose_iter(&mut rng).unwrap().take(3) {
     ///     println!("{:?}", choice);
     /// }
+    ///
+    /// // Bootstrap estimate of the mean
+    /// let resample: Vec<f64> = choices
+    ///     .choose_iter(&mut rng)
+    ///     .unwrap()
+    ///     .take(choices.len())
+    ///     .map(|&x| x as f64)
+    ///     .collect();
+    /// let mean = resample.iter().sum::<f64>() / resample.len() as f64;
+    /// assert!((1.0..=32.0).contains(&mean));
     /// ```
+    ///
+    /// [`Uniform`]: crate::distr::Uniform
     fn choose_iter<R>(&self, rng: &mut R) -> Option<impl Iterator<Item = &Self::Output>>
     where
         R: Rng + ?Sized,
@@ -90,6 +115,7 @@ pub trait IndexedRandom: Index<usize> {
     /// collection into a `Vec` and filling an existing buffer (see example).
     ///
     /// In case this API is not sufficiently flexible, use [`index::sample`].
+    /// For sampling with replacement, see [`IndexedRandom::choose_iter`].
     ///
     /// For slices, complexity is the same as [`index::sample`].
     ///
@@ -123,21 +149,104 @@ pub trait IndexedRandom: Index<usize> {
         }
     }
 
+    /// Uniformly sample `amount` distinct elements into an existing collection
+    ///
+    /// This is equivalent to `target.extend(self.sample(rng, amount))`: the
+    /// sampled elements are appended to `target` in random order, allowing
+    /// use of caller-managed storage such as a re-used `Vec` or an
+    /// `ArrayVec`. Returns the number of elements appended, which is
+    /// `amount` or `self.len()`, whichever is smaller.
+    ///
+    /// For slices, complexity is the same as [`index::sample`].
+    ///
+    /// # Example
+    /// ```
+    /// use rand::seq::IndexedRandom;
+    ///
+    /// let mut rng = &mut rand::rng();
+    /// let deck = ["A", "K", "Q", "J", "10", "9"];
+    ///
+    /// let mut hand: Vec<&str> = Vec::with_capacity(3);
+    /// for _ in 0..10 {
+    ///     hand.clear();
+    ///     assert_eq!(deck.sample_into(&mut rng, 3, &mut hand), 3);
+    ///     assert_eq!(hand.len(), 3);
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sample_into<'a, R, E>(&'a self, rng: &mut R, amount: usize, target: &mut E) -> usize
+    where
+        Self::Output: Sized,
+        R: Rng + ?Sized,
+        E: Extend<&'a Self::Output>,
+    {
+        let samples = self.sample(rng, amount);
+        let len = samples.len();
+        target.extend(samples);
+        len
+    }
+
+    /// Uniformly sample `amount` distinct elements, preserving their order
+    ///
+    /// Chooses `amount` elements from the slice at random, without repetition,
+    /// like [`IndexedRandom::sample`], but yields them in the order in which
+    /// they appear in the slice.
+    ///
+    /// For slices, complexity is the same as [`index::sample_sorted`].
+    ///
+    /// # Example
+    /// ```
+    /// use rand::seq::IndexedRandom;
+    ///
+    /// let mut rng = &mut rand::rng();
+    /// let timestamps = [10, 20, 30, 40, 50, 60];
+    ///
+    /// let v: Vec<u32> = timestamps.sample_ordered(&mut rng, 3).cloned().collect();
+    /// assert!(v.windows(2).all(|w| w[0] < w[1]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sample_ordered<R>(
+        &self,
+        rng: &mut R,
+        amount: usize,
+    ) -> IndexedSamples<'_, Self, Self::Output>
+    where
+        Self::Output: Sized,
+        R: Rng + ?Sized,
+    {
+        let amount = core::cmp::min(amount, self.len());
+        IndexedSamples {
+            slice: self,
+            _phantom: Default::default(),
+            indices: index::sample_sorted(rng, self.len(), amount).into_iter(),
+        }
+    }
+
     /// Uniformly sample a fixed-size array of distinct elements from self
     ///
     /// Chooses `N` elements from the slice at random, without repetition,
     /// and in random order.
     ///
+    /// Returns `None` if and only if `N > self.len()`. This method does not
+    /// allocate and is available without the `alloc` feature.
+    ///
     /// For slices, complexity is the same as [`index::sample_array`].
     ///
     /// # Example
     /// ```
-    /// use rand::seq::IndexedRandom;
+    /// use rand::seq::{index, IndexedRandom};
     ///
     /// let mut rng = &mut rand::rng();
     /// let sample = "Hello, audience!".as_bytes();
     ///
     /// let a: [u8; 3] = sample.sample_array(&mut rng).unwrap();
+    ///
+    /// // To sample references instead of clones, sample the indices:
+    /// let words = ["apple", "banana", "cherry", "date"];
+    /// let refs: [&&str; 2] = index::sample_array(&mut rng, words.len())
+    ///     .unwrap()
+    ///     .map(|i| &words[i]);
+    /// assert_ne!(refs[0], refs[1]);
     /// ```
     fn sample_array<R, const N: usize>(&self, rng: &mut R) -> Option<[Self::Output; N]>
     where
@@ -148,6 +257,46 @@ pub trait IndexedRandom: Index<usize> {
         Some(indices.map(|index| self[index].clone()))
     }
 
+    /// Uniformly sample distinct elements into a buffer
+    ///
+    /// Chooses `buf.len()` elements from the slice at random, without
+    /// repetition, and clones them into `buf` in random order. If the slice
+    /// contains fewer than `buf.len()` elements, all elements are used and
+    /// the rest of `buf` is left unchanged.
+    ///
+    /// Returns the number of elements written to `buf`.
+    ///
+    /// This method does not allocate and is available without the `alloc`
+    /// feature. It is based on [`index::sample_iter`] followed by a shuffle;
+    /// complexity is `O(n)` for slices of length `n`.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::seq::IndexedRandom;
+    ///
+    /// let mut rng = &mut rand::rng();
+    /// let sample = "Hello, audience!".as_bytes();
+    ///
+    /// let mut buf = [0u8; 5];
+    /// assert_eq!(sample.sample_fill(&mut rng, &mut buf), 5);
+    /// ```
+    fn sample_fill<R>(&self, rng: &mut R, buf: &mut [Self::Output]) -> usize
+    where
+        Self::Output: Clone + Sized,
+        R: Rng + ?Sized,
+    {
+        let amount = buf.len().min(self.len());
+        let buf = &mut buf[..amount];
+        for (slot, i) in buf
+            .iter_mut()
+            .zip(index::sample_iter(rng, self.len(), amount))
+        {
+            *slot = self[i].clone();
+        }
+        buf.shuffle(rng);
+        amount
+    }
+
     /// Biased sampling for one element
     ///
     /// Returns a reference to one element of the slice, sampled according
@@ -183,6 +332,42 @@ pub trait IndexedRandom: Index<usize> {
         rng: &mut R,
         weight: F,
     ) -> Result<&Self::Output, WeightError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Output) -> B,
+        B: SampleBorrow<X>,
+        X: SampleUniform + Weight + PartialOrd<X>,
+    {
+        self.choose_weighted_with_index(rng, weight)
+            .map(|(_, item)| item)
+    }
+
+    /// Biased sampling for one element, returning its index
+    ///
+    /// This is equivalent to [`choose_weighted`], but additionally returns the
+    /// index of the chosen element, for example to remove it afterwards.
+    ///
+    /// See also [`choose_weighted_with_index_mut`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::prelude::*;
+    ///
+    /// let mut choices = vec![('a', 2), ('b', 1), ('c', 1), ('d', 0)];
+    /// let mut rng = rand::rng();
+    /// let (index, item) = choices.choose_weighted_with_index(&mut rng, |item| item.1).unwrap();
+    /// assert_ne!(item.0, 'd');
+    /// choices.swap_remove(index);
+    /// ```
+    /// [`choose_weighted`]: IndexedRandom::choose_weighted
+    /// [`choose_weighted_with_index_mut`]: IndexedMutRandom::choose_weighted_with_index_mut
+    #[cfg(feature = "alloc")]
+    fn choose_weighted_with_index<R, F, B, X>(
+        &self,
+        rng: &mut R,
+        weight: F,
+    ) -> Result<(usize, &Self::Output), WeightError>
     where
         R: Rng + ?Sized,
         F: Fn(&Self::Output) -> B,
@@ -191,16 +376,24 @@ pub trait IndexedRandom: Index<usize> {
     {
         use crate::distr::weighted::WeightedIndex;
         let distr = WeightedIndex::new((0..self.len()).map(|idx| weight(&self[idx])))?;
-        Ok(&self[rng.sample(distr)])
+        let index = rng.sample(distr);
+        Ok((index, &self[index]))
     }
 
     /// Biased sampling with replacement
     ///
     /// Returns an iterator which samples elements from `self` according to the
-    /// given weights with replacement (i.e. elements may be repeated).
-    /// Returns `None` if and only if `self.is_empty()`.
+    /// given weights with replacement (i.e. elements may be repeated); use
+    /// [`Iterator::take`] to draw `amount` elements. The underlying
+    /// [`WeightedIndex`] distribution is constructed only once.
+    ///
+    /// Returns an error if [`WeightedIndex::new`] does, in particular
+    /// [`WeightError::InvalidInput`] if `self` is empty.
     ///
     /// See also doc for [`Self::choose_weighted`].
+    ///
+    /// [`WeightedIndex`]: crate::distr::weighted::WeightedIndex
+    /// [`WeightedIndex::new`]: crate::distr::weighted::WeightedIndex::new
     #[cfg(feature = "alloc")]
     fn choose_weighted_iter<R, F, B, X>(
         &self,
@@ -374,10 +567,200 @@ pub trait IndexedMutRandom: IndexedRandom + IndexMut<usize> {
         B: SampleBorrow<X>,
         X: SampleUniform + Weight + PartialOrd<X>,
     {
-        use crate::distr::{Distribution, weighted::WeightedIndex};
+        self.choose_weighted_with_index_mut(rng, weight)
+            .map(|(_, item)| item)
+    }
+
+    /// Biased sampling for one element (mut), returning its index
+    ///
+    /// This is equivalent to [`choose_weighted_mut`], but additionally returns
+    /// the index of the chosen element.
+    ///
+    /// See also [`choose_weighted_with_index`].
+    ///
+    /// [`choose_weighted_mut`]: IndexedMutRandom::choose_weighted_mut
+    /// [`choose_weighted_with_index`]: IndexedRandom::choose_weighted_with_index
+    #[cfg(feature = "alloc")]
+    fn choose_weighted_with_index_mut<R, F, B, X>(
+        &mut self,
+        rng: &mut R,
+        weight: F,
+    ) -> Result<(usize, &mut Self::Output), WeightError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Output) -> B,
+        B: SampleBorrow<X>,
+        X: SampleUniform + Weight + PartialOrd<X>,
+    {
+        use crate::distr::weighted::WeightedIndex;
         let distr = WeightedIndex::new((0..self.len()).map(|idx| weight(&self[idx])))?;
         let index = distr.sample(rng);
-        Ok(&mut self[index])
+        Ok((index, &mut self[index]))
+    }
+}
+
+/// Extension trait for shuffling containers which support swapping elements
+///
+/// This allows in-place shuffling of containers which are not slices, such as
+/// [`VecDeque`], struct-of-arrays collections or arenas: it suffices to
+/// implement [`SwapRandom::len`] and [`SwapRandom::swap`]. For slices, use
+/// [`SliceRandom`] instead.
+///
+/// # Example
+///
+/// ```
+/// use rand::seq::SwapRandom;
+///
+/// // A struct-of-arrays collection
+/// struct Particles {
+///     position: Vec<f32>,
+///     velocity: Vec<f32>,
+/// }
+///
+/// impl SwapRandom for Particles {
+///     fn len(&self) -> usize {
+///         self.position.len()
+///     }
+///
+///     fn swap(&mut self, a: usize, b: usize) {
+///         self.position.swap(a, b);
+///         self.velocity.swap(a, b);
+///     }
+/// }
+///
+/// let mut particles = Particles {
+///     position: vec![0.0, 1.0, 2.0],
+///     velocity: vec![0.0, -1.0, -2.0],
+/// };
+/// particles.shuffle(&mut rand::rng());
+/// for (p, v) in particles.position.iter().zip(&particles.velocity) {
+///     assert_eq!(*p, -*v);
+/// }
+/// ```
+pub trait SwapRandom {
+    /// The number of elements
+    fn len(&self) -> usize;
+
+    /// Swap the elements at indices `a` and `b`
+    ///
+    /// Implementations may panic if `a` or `b` is out of bounds.
+    fn swap(&mut self, a: usize, b: usize);
+
+    /// True when the length is zero
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Shuffle the container in place
+    ///
+    /// Given equal RNG state and length, the result equals that of
+    /// [`SliceRandom::shuffle`].
+    ///
+    /// For containers of length `n`, complexity is `O(n)` calls to
+    /// [`SwapRandom::swap`].
+    fn shuffle<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        let len = self.len();
+        if len <= 1 {
+            return;
+        }
+        // This matches the algorithm of `SliceRandom::partial_shuffle`
+        if len < (u32::MAX as usize) {
+            let mut chooser = IncreasingUniform::new(rng, 0);
+            for i in 0..len {
+                let index = chooser.next_index();
+                self.swap(i, index);
+            }
+        } else {
+            for i in 0..len {
+                let index = rng.random_range(..i + 1);
+                self.swap(i, index);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SwapRandom for VecDeque<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    #[inline]
+    fn swap(&mut self, a: usize, b: usize) {
+        VecDeque::swap(self, a, b)
+    }
+}
+
+/// Extension trait on arrays, providing sampling methods for non-empty arrays.
+///
+/// This trait is implemented for all arrays `[T; N]`, but its methods may only
+/// be used where `N > 0`; this is checked at compile time. Results equal those
+/// of the corresponding [`IndexedRandom`] and [`IndexedMutRandom`] methods.
+///
+/// ```
+/// use rand::seq::ArrayRandom;
+///
+/// let mut rng = rand::rng();
+/// let directions = ["north", "east", "south", "west"];
+/// let direction: &str = directions.choose_nonempty(&mut rng);
+/// println!("Heading {}", direction);
+/// ```
+///
+/// Use on an empty array fails to compile:
+///
+/// ```compile_fail
+/// use rand::seq::ArrayRandom;
+///
+/// let empty: [u8; 0] = [];
+/// empty.choose_nonempty(&mut rand::rng());
+/// ```
+pub trait ArrayRandom {
+    /// The element type
+    type Item;
+
+    /// Uniformly sample one element
+    ///
+    /// Like [`IndexedRandom::choose`], but returns a reference directly since
+    /// the array is known to be non-empty.
+    ///
+    /// Complexity is `O(1)`.
+    fn choose_nonempty<R>(&self, rng: &mut R) -> &Self::Item
+    where
+        R: Rng + ?Sized;
+
+    /// Uniformly sample one element (mut)
+    ///
+    /// Like [`IndexedMutRandom::choose_mut`], but returns a reference directly
+    /// since the array is known to be non-empty.
+    ///
+    /// Complexity is `O(1)`.
+    fn choose_nonempty_mut<R>(&mut self, rng: &mut R) -> &mut Self::Item
+    where
+        R: Rng + ?Sized;
+}
+
+impl<T, const N: usize> ArrayRandom for [T; N] {
+    type Item = T;
+
+    fn choose_nonempty<R>(&self, rng: &mut R) -> &T
+    where
+        R: Rng + ?Sized,
+    {
+        const { assert!(N > 0, "array must be non-empty") };
+        &self[rng.random_range(..N)]
+    }
+
+    fn choose_nonempty_mut<R>(&mut self, rng: &mut R) -> &mut T
+    where
+        R: Rng + ?Sized,
+    {
+        const { assert!(N > 0, "array must be non-empty") };
+        &mut self[rng.random_range(..N)]
     }
 }
 
@@ -420,6 +803,39 @@ pub trait SliceRandom: IndexedMutRandom {
     where
         R: Rng + ?Sized;
 
+    /// Shuffle a mutable slice in place, deterministically from a `seed`
+    ///
+    /// The result depends only on `seed` and the slice length; unlike
+    /// [`SliceRandom::shuffle`] with a seeded RNG, it is guaranteed not to
+    /// change in future versions of Rand and does not depend on the platform.
+    ///
+    /// The algorithm is fixed as follows: a [`Xoshiro256PlusPlus`] generator
+    /// is constructed via [`SeedableRng::seed_from_u64`], then for each `i`
+    /// from `len - 1` down to `1`, element `i` is swapped with element `j`,
+    /// where `j` is sampled from `0..=i` using widening multiplication of
+    /// [`RngCore::next_u64`] output by `i + 1`, rejecting the low `2^64 % (i + 1)`
+    /// products (Lemire's method).
+    ///
+    /// For slices of length `n`, complexity is `O(n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut level = [1, 2, 3, 4, 5];
+    /// level.shuffle_seeded(2025);
+    /// let mut again = [1, 2, 3, 4, 5];
+    /// again.shuffle_seeded(2025);
+    /// assert_eq!(level, again);
+    /// ```
+    ///
+    /// [`Xoshiro256PlusPlus`]: crate::rngs::Xoshiro256PlusPlus
+    /// [`SeedableRng::seed_from_u64`]: crate::SeedableRng::seed_from_u64
+    /// [`RngCore::next_u64`]: crate::RngCore::next_u64
+    #[cfg(feature = "small_rng")]
+    fn shuffle_seeded(&mut self, seed: u64);
+
     /// Shuffle a slice in place, but exit early.
     ///
     /// Returns two mutable slices from the source slice. The first contains
@@ -436,6 +852,10 @@ pub trait SliceRandom: IndexedMutRandom {
     /// If `amount` is greater than the number of elements in the slice, this
     /// will perform a full shuffle.
     ///
+    /// If `amount` is not known in advance, e.g. when drawing elements until
+    /// one satisfies a predicate, use [`SliceRandom::shuffled_iter`] which
+    /// performs the same steps lazily.
+    ///
     /// For slices, complexity is `O(m)` where `m = amount`.
     fn partial_shuffle<R>(
         &mut self,
@@ -445,37 +865,419 @@ pub trait SliceRandom: IndexedMutRandom {
     where
         Self::Output: Sized,
         R: Rng + ?Sized;
-}
-
-impl<T> IndexedRandom for [T] {
-    fn len(&self) -> usize {
-        self.len()
-    }
-}
-
-impl<IR: IndexedRandom + IndexMut<usize> + ?Sized> IndexedMutRandom for IR {}
-
-impl<T> SliceRandom for [T] {
-    fn shuffle<R>(&mut self, rng: &mut R)
-    where
-        R: Rng + ?Sized,
-    {
-        if self.len() <= 1 {
-            // There is no need to shuffle an empty or single element slice
-            return;
-        }
-        self.partial_shuffle(rng, self.len());
-    }
 
-    fn partial_shuffle<R>(&mut self, rng: &mut R, amount: usize) -> (&mut [T], &mut [T])
+    /// Shuffle a sub-range of a mutable slice in place.
+    ///
+    /// Elements outside of `range` are not modified. This is equivalent to
+    /// `self[range].shuffle(rng)`.
+    ///
+    /// For a range of length `n`, complexity is `O(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds for the slice (see
+    /// [slice indexing](https://doc.rust-lang.org/std/primitive.slice.html#method.get)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let mut deck: Vec<u32> = (0..52).collect();
+    /// // Two cards have been dealt; shuffle the remaining deck
+    /// deck.shuffle_range(&mut rng, 2..);
+    /// assert_eq!(deck[..2], [0, 1]);
+    /// ```
+    #[track_caller]
+    fn shuffle_range<R, B>(&mut self, rng: &mut R, range: B)
     where
+        Self::Output: Sized,
         R: Rng + ?Sized,
-    {
-        let m = self.len().saturating_sub(amount);
+        B: RangeBounds<usize>;
 
-        // The algorithm below is based on Durstenfeld's algorithm for the
-        // [Fisher–Yates shuffle](https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle#The_modern_algorithm)
-        // for an unbiased permutation.
+    /// Shuffle a mutable slice in place, biased by weight
+    ///
+    /// Produces a full permutation in which elements of higher weight tend to
+    /// appear earlier: the first element is chosen with probability
+    /// proportional to its weight, the second proportionally to its weight
+    /// among the remaining elements, and so on (successive sampling without
+    /// replacement). Elements of zero weight are placed last, in uniformly
+    /// random order.
+    ///
+    /// The specified function `weight` maps each item `x` to a relative
+    /// likelihood `weight(x)`.
+    ///
+    /// This implementation assigns the keys of the algorithm A-Res found in
+    /// [Efraimidis and Spirakis, 2005](https://doi.org/10.1016/j.ipl.2005.11.003)
+    /// and sorts by them. It uses `O(n)` memory and `O(n log n)` time for
+    /// slices of length `n`.
+    ///
+    /// Error cases:
+    /// -   [`WeightError::InvalidWeight`] when a weight is not-a-number or
+    ///     negative. The slice is not modified in this case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let mut candidates = [("a", 10.0), ("b", 1.0), ("c", 0.0), ("d", 5.0)];
+    /// candidates.shuffle_weighted(&mut rng, |x| x.1).unwrap();
+    /// // Zero-weighted elements are always last
+    /// assert_eq!(candidates[3].0, "c");
+    /// ```
+    // Note: this is feature-gated on std due to usage of f64::ln.
+    #[cfg(feature = "std")]
+    fn shuffle_weighted<R, F, X>(&mut self, rng: &mut R, weight: F) -> Result<(), WeightError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&Self::Output) -> X,
+        X: Into<f64>;
+
+    /// Riffle shuffle a mutable slice in place
+    ///
+    /// This simulates a single riffle shuffle as performed by hand, following
+    /// the Gilbert–Shannon–Reeds model: the deck is cut into two packets,
+    /// where the size of the top packet is `Binomial(n, 1/2)` distributed,
+    /// and the packets are then interleaved by dropping cards from the bottom
+    /// of either packet with probability proportional to that packet's size.
+    ///
+    /// The result is *not* a uniform permutation; about `1.5 log2(n)` riffle
+    /// shuffles are needed to approximately randomise a deck of `n` cards.
+    /// This method is intended for simulation; use [`SliceRandom::shuffle`]
+    /// for uniform shuffling.
+    ///
+    /// For slices of length `n`, complexity is `O(n)` time and memory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let mut deck: Vec<u8> = (0..52).collect();
+    /// for _ in 0..7 {
+    ///     deck.riffle(&mut rng);
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn riffle<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized;
+
+    /// Overhand shuffle a mutable slice in place
+    ///
+    /// This simulates a single overhand shuffle as performed by hand,
+    /// following the model of Pemantle (1989): the deck is cut into packets,
+    /// where a cut occurs between each pair of adjacent cards independently
+    /// with probability `p`, and the order of packets is then reversed while
+    /// the order of cards within each packet is preserved.
+    ///
+    /// The result is *not* a uniform permutation; many overhand shuffles are
+    /// needed to approximately randomise a deck. This method is intended for
+    /// simulation; use [`SliceRandom::shuffle`] for uniform shuffling.
+    ///
+    /// For slices of length `n`, complexity is `O(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p < 0` or `p > 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let mut deck: Vec<u8> = (0..52).collect();
+    /// deck.overhand(&mut rng, 0.2);
+    /// ```
+    #[track_caller]
+    fn overhand<R>(&mut self, rng: &mut R, p: f64)
+    where
+        R: Rng + ?Sized;
+
+    /// Rotate a mutable slice in place by a uniformly random offset
+    ///
+    /// The slice is rotated left by an offset sampled uniformly from
+    /// `0..self.len()`, such that the element at that offset becomes the
+    /// first element. Returns the offset (zero for an empty slice).
+    ///
+    /// For slices of length `n`, complexity is `O(n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let mut players = ["Alice", "Bob", "Carol", "Dave"];
+    /// let offset = players.rotate_random(&mut rng);
+    /// println!("Player {} starts", players[0]);
+    /// # assert_eq!(players[(4 - offset) % 4], "Alice");
+    /// ```
+    fn rotate_random<R>(&mut self, rng: &mut R) -> usize
+    where
+        R: Rng + ?Sized;
+
+    /// Uniformly sample two distinct elements (mut)
+    ///
+    /// Returns mutable references to two elements at distinct, uniformly
+    /// sampled positions, or `None` if the slice has fewer than two elements.
+    /// The order of the pair is also random.
+    ///
+    /// Complexity is `O(1)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let mut genes = [0u8, 1, 2, 3, 4, 5];
+    /// if let Some((a, b)) = genes.choose_two_mut(&mut rng) {
+    ///     core::mem::swap(a, b);
+    /// }
+    /// ```
+    fn choose_two_mut<R>(&mut self, rng: &mut R) -> Option<(&mut Self::Output, &mut Self::Output)>
+    where
+        Self::Output: Sized,
+        R: Rng + ?Sized;
+
+    /// Randomly partition a mutable slice into `k` groups
+    ///
+    /// The slice is shuffled in place and split into `k` contiguous groups of
+    /// near-equal size, which are returned as an iterator of mutable
+    /// sub-slices. Where `k` does not divide the slice length, the first
+    /// `self.len() % k` groups contain one element more than the others;
+    /// if `k > self.len()`, some groups are empty.
+    ///
+    /// This is suitable for k-fold cross-validation. For a train/test
+    /// split of a given size, see [`SliceRandom::partial_shuffle`].
+    ///
+    /// For slices of length `n`, complexity is `O(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let mut samples: Vec<u32> = (0..10).collect();
+    /// let folds: Vec<&mut [u32]> = samples.partition_random(&mut rng, 3).collect();
+    /// assert_eq!(folds.iter().map(|f| f.len()).collect::<Vec<_>>(), [4, 3, 3]);
+    /// ```
+    #[track_caller]
+    fn partition_random<R>(
+        &mut self,
+        rng: &mut R,
+        k: usize,
+    ) -> impl ExactSizeIterator<Item = &mut [Self::Output]>
+    where
+        Self::Output: Sized,
+        R: Rng + ?Sized;
+
+    /// Shuffle the chunks of a mutable slice in place.
+    ///
+    /// The slice is divided into chunks of `chunk_size` elements, as with
+    /// [`chunks_exact`](slice::chunks_exact). These chunks are permuted
+    /// uniformly at random while the order of elements within each chunk is
+    /// preserved. If `chunk_size` does not divide the slice length, the
+    /// remaining elements stay in place at the end of the slice.
+    ///
+    /// With `chunk_size == 1`, this is equivalent to [`SliceRandom::shuffle`].
+    ///
+    /// For slices of length `n`, complexity is `O(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// // Shuffle shards of two records each
+    /// let mut records = [0, 1, 2, 3, 4, 5, 6];
+    /// records.shuffle_chunks(&mut rng, 2);
+    /// for chunk in records.chunks_exact(2) {
+    ///     assert_eq!(chunk[0] % 2, 0);
+    ///     assert_eq!(chunk[1], chunk[0] + 1);
+    /// }
+    /// assert_eq!(records[6], 6);
+    /// ```
+    #[track_caller]
+    fn shuffle_chunks<R>(&mut self, rng: &mut R, chunk_size: usize)
+    where
+        R: Rng + ?Sized;
+
+    /// Shuffle a mutable slice in place into a single random cycle.
+    ///
+    /// This implements [Sattolo's algorithm], producing a permutation chosen
+    /// uniformly from the `(n - 1)!` permutations consisting of exactly one
+    /// cycle: following the mapping from each element's original position to
+    /// its new position visits every position before returning to the start.
+    /// Consequently, for slices of length 2 or more, no element remains in its
+    /// original position.
+    ///
+    /// Note that this is *not* a uniform shuffle; use [`SliceRandom::shuffle`]
+    /// for that.
+    ///
+    /// For slices of length `n`, complexity is `O(n)`.
+    ///
+    /// [Sattolo's algorithm]: https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle#Sattolo's_algorithm
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// // Build a random ring: node i links to node ring[i]
+    /// let mut ring = [0, 1, 2, 3, 4, 5];
+    /// ring.shuffle_cyclic(&mut rng);
+    /// let mut node = 0;
+    /// for _ in 0..ring.len() {
+    ///     node = ring[node];
+    /// }
+    /// assert_eq!(node, 0);
+    /// ```
+    fn shuffle_cyclic<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized;
+
+    /// Shuffle a mutable slice in place such that no element remains in its
+    /// original position.
+    ///
+    /// The resulting permutation (a *derangement*) is picked uniformly from the
+    /// set of all derangements. An empty slice is left unchanged.
+    ///
+    /// This is implemented via rejection sampling over a Fisher–Yates shuffle,
+    /// rejecting early as soon as an element is fixed in its original position.
+    /// Expected complexity is `O(n)` time for slices of length `n`, plus `O(n)`
+    /// memory to track original positions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.len() == 1`, since no derangement exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let people = ["Alice", "Bob", "Carol", "Dave"];
+    /// // Secret Santa: nobody may draw their own name
+    /// let mut recipients = people;
+    /// recipients.derange(&mut rng);
+    /// for (giver, recipient) in people.iter().zip(recipients.iter()) {
+    ///     assert_ne!(giver, recipient);
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[track_caller]
+    fn derange<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized;
+
+    /// Lazily shuffle a slice, yielding its elements in random order.
+    ///
+    /// Returns an iterator which yields mutable references to all elements of
+    /// the slice in uniformly random order. Each step of the iterator performs
+    /// a single step of the Fisher–Yates shuffle in place, hence consuming only
+    /// the first `k` items costs `O(k)` time and `k` samples from `rng`. This
+    /// is thus a lazy variant of [`Self::partial_shuffle`].
+    ///
+    /// When the iterator is dropped, the consumed elements occupy the front of
+    /// the slice in the order they were yielded; the remaining elements follow
+    /// in an unspecified order. Consuming the iterator fully thus shuffles the
+    /// slice (though not with the same result as [`Self::shuffle`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::seq::SliceRandom;
+    ///
+    /// let mut rng = rand::rng();
+    /// let mut deck: Vec<u32> = (1..=52).collect();
+    /// // Draw cards until a face card (11..=13) turns up
+    /// let drawn = deck
+    ///     .shuffled_iter(&mut rng)
+    ///     .position(|card| (11..=13).contains(&(*card % 13 + 1)));
+    /// assert!(drawn.is_some());
+    /// ```
+    fn shuffled_iter<'a, R>(&'a mut self, rng: &'a mut R) -> ShuffledIter<'a, Self::Output, R>
+    where
+        Self::Output: Sized,
+        R: Rng + ?Sized;
+}
+
+impl<T> IndexedRandom for [T] {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IndexedRandom for VecDeque<T> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<IR: IndexedRandom + IndexMut<usize> + ?Sized> IndexedMutRandom for IR {}
+
+impl<T> SliceRandom for [T] {
+    fn shuffle<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        if self.len() <= 1 {
+            // There is no need to shuffle an empty or single element slice
+            return;
+        }
+        self.partial_shuffle(rng, self.len());
+    }
+
+    #[cfg(feature = "small_rng")]
+    fn shuffle_seeded(&mut self, seed: u64) {
+        use crate::rngs::Xoshiro256PlusPlus;
+        use crate::{RngCore, SeedableRng};
+
+        // This implementation is covered by a value-stability guarantee and
+        // must not be changed.
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+        for i in (1..self.len()).rev() {
+            let range = i as u64 + 1;
+            let threshold = range.wrapping_neg() % range;
+            let j = loop {
+                let m = u128::from(rng.next_u64()) * u128::from(range);
+                if m as u64 >= threshold {
+                    break (m >> 64) as usize;
+                }
+            };
+            self.swap(i, j);
+        }
+    }
+
+    fn partial_shuffle<R>(&mut self, rng: &mut R, amount: usize) -> (&mut [T], &mut [T])
+    where
+        R: Rng + ?Sized,
+    {
+        let m = self.len().saturating_sub(amount);
+
+        // The algorithm below is based on Durstenfeld's algorithm for the
+        // [Fisher–Yates shuffle](https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle#The_modern_algorithm)
+        // for an unbiased permutation.
         // It ensures that the last `amount` elements of the slice
         // are randomly selected from the whole slice.
 
@@ -497,8 +1299,271 @@ impl<T> SliceRandom for [T] {
         let r = self.split_at_mut(m);
         (r.1, r.0)
     }
+
+    #[cfg(feature = "std")]
+    fn shuffle_weighted<R, F, X>(&mut self, rng: &mut R, weight: F) -> Result<(), WeightError>
+    where
+        R: Rng + ?Sized,
+        F: Fn(&T) -> X,
+        X: Into<f64>,
+    {
+        use crate::distr::Open01;
+
+        let mut keys = Vec::with_capacity(self.len());
+        for (index, item) in self.iter().enumerate() {
+            let weight = weight(item).into();
+            if !(weight >= 0.0) {
+                return Err(WeightError::InvalidWeight);
+            }
+            // We use the log of the key used in A-Res to improve precision
+            // for small weights. Zero weights are given key -∞.
+            let key = if weight > 0.0 {
+                rng.sample::<f64, _>(Open01).ln() / weight
+            } else {
+                f64::NEG_INFINITY
+            };
+            keys.push((key, index));
+        }
+
+        // Sort by descending key. Since keys are never NaN, unwrap won't panic.
+        keys.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+        let mut order: Vec<usize> = keys.iter().map(|(_, index)| *index).collect();
+        let zero_weighted = keys
+            .iter()
+            .rev()
+            .take_while(|(key, _)| *key == f64::NEG_INFINITY)
+            .count();
+        let n = order.len();
+        order[n - zero_weighted..].shuffle(rng);
+
+        permute(self, &mut order);
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn riffle<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        let n = self.len();
+        if n <= 1 {
+            return;
+        }
+
+        // Each position of the output independently receives the next card of
+        // the left packet with probability 1/2. This is equivalent to cutting
+        // at Binomial(n, 1/2) and choosing a uniformly random interleaving.
+        let mut from_left = Vec::with_capacity(n);
+        while from_left.len() < n {
+            let bits: u64 = rng.random();
+            let take = (n - from_left.len()).min(64);
+            from_left.extend((0..take).map(|b| (bits >> b) & 1 == 1));
+        }
+        let cut = from_left.iter().filter(|b| **b).count();
+
+        let (mut left, mut right) = (0, cut);
+        let mut order: Vec<usize> = from_left
+            .into_iter()
+            .map(|b| {
+                let next = if b { &mut left } else { &mut right };
+                *next += 1;
+                *next - 1
+            })
+            .collect();
+        permute(self, &mut order);
+    }
+
+    fn overhand<R>(&mut self, rng: &mut R, p: f64)
+    where
+        R: Rng + ?Sized,
+    {
+        let distr = Bernoulli::new(p).expect("probability must be in [0, 1]");
+        // Reversing the slice reverses the order of packets, but also the
+        // order within each packet, which we then restore.
+        self.reverse();
+        let mut start = 0;
+        for i in 1..self.len() {
+            if distr.sample(rng) {
+                self[start..i].reverse();
+                start = i;
+            }
+        }
+        self[start..].reverse();
+    }
+
+    fn rotate_random<R>(&mut self, rng: &mut R) -> usize
+    where
+        R: Rng + ?Sized,
+    {
+        if self.is_empty() {
+            return 0;
+        }
+        let offset = rng.random_range(..self.len());
+        self.rotate_left(offset);
+        offset
+    }
+
+    fn choose_two_mut<R>(&mut self, rng: &mut R) -> Option<(&mut T, &mut T)>
+    where
+        R: Rng + ?Sized,
+    {
+        let len = self.len();
+        if len < 2 {
+            return None;
+        }
+        let i = rng.random_range(..len);
+        let mut j = rng.random_range(..len - 1);
+        if j >= i {
+            j += 1;
+        }
+        let (head, tail) = self.split_at_mut(i.max(j));
+        let (first, second) = (&mut head[i.min(j)], &mut tail[0]);
+        Some(if i < j {
+            (first, second)
+        } else {
+            (second, first)
+        })
+    }
+
+    fn partition_random<R>(
+        &mut self,
+        rng: &mut R,
+        k: usize,
+    ) -> impl ExactSizeIterator<Item = &mut [T]>
+    where
+        R: Rng + ?Sized,
+    {
+        assert!(k != 0, "number of groups must be non-zero");
+        self.shuffle(rng);
+        let (size, larger) = (self.len() / k, self.len() % k);
+        let mut rest = self;
+        (0..k).map(move |i| {
+            let len = size + usize::from(i < larger);
+            let (group, tail) = core::mem::take(&mut rest).split_at_mut(len);
+            rest = tail;
+            group
+        })
+    }
+
+    fn shuffle_chunks<R>(&mut self, rng: &mut R, chunk_size: usize)
+    where
+        R: Rng + ?Sized,
+    {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let n = self.len() / chunk_size;
+        if n <= 1 {
+            return;
+        }
+
+        // Swap chunks `i` and `j`, where `j <= i`
+        let mut swap_chunks = |i: usize, j: usize| {
+            if j < i {
+                let (head, tail) = self.split_at_mut(i * chunk_size);
+                head[j * chunk_size..(j + 1) * chunk_size].swap_with_slice(&mut tail[..chunk_size]);
+            }
+        };
+
+        // As in `partial_shuffle`, for consistency with `shuffle`
+        if n < (u32::MAX as usize) {
+            let mut chooser = IncreasingUniform::new(rng, 0);
+            for i in 0..n {
+                swap_chunks(i, chooser.next_index());
+            }
+        } else {
+            for i in 0..n {
+                swap_chunks(i, rng.random_range(..i + 1));
+            }
+        }
+    }
+
+    fn shuffle_cyclic<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        // Sattolo's variant of the Fisher–Yates shuffle: the element placed at
+        // `i` is always drawn from strictly below `i`.
+        for i in (1..self.len()).rev() {
+            let j = rng.random_range(..i);
+            self.swap(i, j);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn derange<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        let len = self.len();
+        assert!(len != 1, "cannot derange a slice of length 1");
+
+        // `origin[i]` is the original position of the element now at `i`.
+        let mut origin: Vec<usize> = (0..len).collect();
+        'retry: loop {
+            // A full Fisher–Yates pass yields a uniform permutation regardless
+            // of the initial order, hence we may restart from the current state.
+            for i in (1..len).rev() {
+                let j = rng.random_range(..=i);
+                self.swap(i, j);
+                origin.swap(i, j);
+                if origin[i] == i {
+                    continue 'retry;
+                }
+            }
+            if len == 0 || origin[0] != 0 {
+                return;
+            }
+        }
+    }
+
+    fn shuffle_range<R, B>(&mut self, rng: &mut R, range: B)
+    where
+        R: Rng + ?Sized,
+        B: RangeBounds<usize>,
+    {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+        self[bounds].shuffle(rng);
+    }
+
+    fn shuffled_iter<'a, R>(&'a mut self, rng: &'a mut R) -> ShuffledIter<'a, T, R>
+    where
+        R: Rng + ?Sized,
+    {
+        ShuffledIter { slice: self, rng }
+    }
+}
+
+/// An iterator over the elements of a slice in random order.
+///
+/// This struct is created by [`SliceRandom::shuffled_iter`].
+#[derive(Debug)]
+pub struct ShuffledIter<'a, T, R: ?Sized> {
+    slice: &'a mut [T],
+    rng: &'a mut R,
+}
+
+impl<'a, T, R: Rng + ?Sized> Iterator for ShuffledIter<'a, T, R> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slice = core::mem::take(&mut self.slice);
+        if slice.len() > 1 {
+            let index = self.rng.random_range(..slice.len());
+            slice.swap(0, index);
+        }
+        let (first, rest) = slice.split_first_mut()?;
+        self.slice = rest;
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.slice.len(), Some(self.slice.len()))
+    }
 }
 
+impl<T, R: Rng + ?Sized> ExactSizeIterator for ShuffledIter<'_, T, R> {}
+
+impl<T, R: Rng + ?Sized> core::iter::FusedIterator for ShuffledIter<'_, T, R> {}
+
 /// An iterator over multiple slice elements.
 ///
 /// This struct is created by
@@ -542,8 +1607,6 @@ pub type SliceChooseIter<'a, S, T> = IndexedSamples<'a, S, T>;
 #[cfg(test)]
 mod test {
     use super::*;
-    #[cfg(feature = "alloc")]
-    use alloc::vec::Vec;
 
     #[test]
     fn test_slice_choose() {
@@ -611,6 +1674,32 @@ mod test {
         assert_eq!(res.1, &mut [0, 11, 2, 3, 4, 5, 10]);
     }
 
+    #[test]
+    #[cfg(all(feature = "small_rng", feature = "alloc"))]
+    fn test_shuffle_seeded() {
+        let mut empty: [u8; 0] = [];
+        empty.shuffle_seeded(0);
+        let mut one = [1];
+        one.shuffle_seeded(0);
+        assert_eq!(one, [1]);
+
+        // Value stability of these results is guaranteed
+        let mut nums = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        nums.shuffle_seeded(0);
+        assert_eq!(nums, [8, 2, 10, 7, 1, 5, 6, 9, 11, 0, 3, 12, 4]);
+        let mut nums = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        nums.shuffle_seeded(0x1234_5678_9abc_def0);
+        assert_eq!(nums, [9, 4, 1, 12, 0, 2, 6, 10, 8, 11, 5, 7, 3]);
+
+        let mut a: Vec<u32> = (0..100).collect();
+        let mut b = a.clone();
+        a.shuffle_seeded(7);
+        b.shuffle_seeded(7);
+        assert_eq!(a, b);
+        b.sort_unstable();
+        assert_eq!(b, (0..100).collect::<Vec<_>>());
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Miri is too slow
     fn test_shuffle() {
@@ -677,6 +1766,574 @@ mod test {
         assert!(res.1[0] == 1 || res.1[1] == 2 || res.1[2] == 3);
     }
 
+    #[test]
+    fn test_shuffle_range() {
+        let mut r = crate::test::rng(120);
+        let mut v = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut changed = false;
+        for _ in 0..10 {
+            v.shuffle_range(&mut r, 3..7);
+            assert_eq!(v[..3], [0, 1, 2]);
+            assert_eq!(v[7..], [7, 8, 9]);
+            let mut mid = [v[3], v[4], v[5], v[6]];
+            changed |= mid != [3, 4, 5, 6];
+            mid.sort_unstable();
+            assert_eq!(mid, [3, 4, 5, 6]);
+        }
+        assert!(changed);
+
+        v.shuffle_range(&mut r, ..0);
+        v.shuffle_range(&mut r, 10..);
+        v.shuffle_range(&mut r, 9..=9);
+        assert_eq!(v[9], 9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_shuffle_range_out_of_bounds() {
+        let mut r = crate::test::rng(121);
+        [0, 1, 2].shuffle_range(&mut r, 2..4);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_swap_random() {
+        let mut deque: VecDeque<u32> = (0..50).collect();
+        // Rotate the ring buffer such that it is not contiguous
+        deque.rotate_left(20);
+        let mut expected: Vec<u32> = deque.iter().copied().collect();
+        SwapRandom::shuffle(&mut deque, &mut crate::test::rng(417));
+        expected.shuffle(&mut crate::test::rng(417));
+        assert!(deque.iter().eq(expected.iter()));
+
+        let mut empty: VecDeque<u32> = VecDeque::new();
+        SwapRandom::shuffle(&mut empty, &mut crate::test::rng(418));
+        assert!(SwapRandom::is_empty(&empty));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_sample_into() {
+        let v = [1, 2, 3, 4, 5, 6];
+        let mut target = alloc::vec![&0];
+        assert_eq!(v.sample_into(&mut crate::test::rng(415), 4, &mut target), 4);
+        assert_eq!(target.len(), 5);
+        let expected: Vec<&i32> = v.sample(&mut crate::test::rng(415), 4).collect();
+        assert_eq!(target[1..], expected[..]);
+
+        let mut copies: Vec<i32> = Vec::new();
+        assert_eq!(
+            v.sample_into(&mut crate::test::rng(416), 10, &mut copies),
+            6
+        );
+        copies.sort_unstable();
+        assert_eq!(copies, v);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_sample_ordered() {
+        let mut r = crate::test::rng(135);
+        let v: Vec<u32> = (0..1000).map(|x| x * 3).collect();
+        for &amount in &[0, 1, 10, 500, 1000, 1200] {
+            let sample: Vec<u32> = v.sample_ordered(&mut r, amount).cloned().collect();
+            assert_eq!(sample.len(), amount.min(v.len()));
+            assert!(sample.windows(2).all(|w| w[0] < w[1]));
+            assert!(sample.iter().all(|x| x % 3 == 0));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_choose_weighted_with_index() {
+        let mut r = crate::test::rng(127);
+        let mut choices = [('a', 0), ('b', 3), ('c', 0), ('d', 1)];
+        for _ in 0..100 {
+            let (index, item) = choices
+                .choose_weighted_with_index(&mut r, |item| item.1)
+                .unwrap();
+            assert_eq!(&choices[index], item);
+            assert!(item.0 == 'b' || item.0 == 'd');
+        }
+
+        let (index, item) = choices
+            .choose_weighted_with_index_mut(&mut r, |item| (item.0 == 'c') as u8)
+            .unwrap();
+        assert_eq!(index, 2);
+        item.1 = 7;
+        assert_eq!(choices[2], ('c', 7));
+
+        let empty: [(char, u8); 0] = [];
+        assert_eq!(
+            empty.choose_weighted_with_index(&mut r, |item| item.1),
+            Err(WeightError::InvalidInput)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_vec_deque() {
+        let mut r = crate::test::rng(126);
+        let mut queue: VecDeque<u32> = VecDeque::with_capacity(4);
+        assert_eq!(queue.choose(&mut r), None);
+
+        // Force the contents to wrap around the ring buffer
+        queue.extend([0, 0, 1, 2]);
+        queue.pop_front();
+        queue.pop_front();
+        queue.extend([3, 4]);
+        assert!(!queue.as_slices().1.is_empty());
+
+        let mut counts = [0i32; 4];
+        for _ in 0..4000 {
+            counts[*queue.choose(&mut r).unwrap() as usize - 1] += 1;
+        }
+        for count in counts.iter() {
+            // Binomial(4000, 1/4) with average 1000
+            assert!(880 <= *count && *count <= 1120, "count: {}", count);
+        }
+
+        *queue.choose_mut(&mut r).unwrap() = 10;
+        assert_eq!(queue.iter().filter(|x| **x == 10).count(), 1);
+
+        let mut sample: Vec<u32> = queue.sample(&mut r, 4).cloned().collect();
+        sample.sort_unstable();
+        let mut all: Vec<u32> = queue.iter().cloned().collect();
+        all.sort_unstable();
+        assert_eq!(sample, all);
+
+        assert!(queue.sample_array::<_, 2>(&mut r).is_some());
+        assert_eq!(
+            queue.choose_weighted(&mut r, |x| (*x == 10) as u32),
+            Ok(&10)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_sample_fill() {
+        let mut r = crate::test::rng(136);
+        let v = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut buf = [0; 10];
+        assert_eq!(v.sample_fill(&mut r, &mut buf), 8);
+        assert_eq!(buf[8..], [0, 0]);
+        buf[..8].sort_unstable();
+        assert_eq!(buf[..8], v);
+
+        // Each element is chosen with probability 3/8, in random order
+        let mut counts = [[0i32; 8]; 3];
+        for _ in 0..8000 {
+            let mut buf = [0; 3];
+            assert_eq!(v.sample_fill(&mut r, &mut buf), 3);
+            assert!(buf[0] != buf[1] && buf[1] != buf[2] && buf[0] != buf[2]);
+            for (pos, x) in buf.iter().enumerate() {
+                counts[pos][*x - 1] += 1;
+            }
+        }
+        for count in counts.iter().flatten() {
+            // Binomial(8000, 1/8) with average 1000
+            assert!((880..=1120).contains(count), "counts: {:?}", counts);
+        }
+    }
+
+    #[test]
+    fn test_choose_nonempty() {
+        let mut a = [1, 2, 3, 4, 5, 6, 7];
+        for seed in 0..10 {
+            let x = *a.choose_nonempty(&mut crate::test::rng(seed));
+            assert_eq!(Some(&x), a.choose(&mut crate::test::rng(seed)));
+        }
+
+        let mut r = crate::test::rng(137);
+        *a.choose_nonempty_mut(&mut r) = 0;
+        assert_eq!(a.iter().filter(|x| **x == 0).count(), 1);
+
+        let one = ['a'];
+        assert_eq!(*one.choose_nonempty(&mut r), 'a');
+    }
+
+    #[test]
+    fn test_sample_array() {
+        let mut r = crate::test::rng(125);
+        let v = [1, 2, 3];
+        assert_eq!(v.sample_array::<_, 4>(&mut r), None);
+        assert_eq!(v.sample_array::<_, 0>(&mut r), Some([]));
+        let mut a: [i32; 3] = v.sample_array(&mut r).unwrap();
+        a.sort_unstable();
+        assert_eq!(a, v);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.sample_array::<_, 1>(&mut r), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_shuffle_weighted() {
+        let mut r = crate::test::rng(131);
+
+        let mut empty: [u32; 0] = [];
+        assert_eq!(empty.shuffle_weighted(&mut r, |_| 1.0), Ok(()));
+
+        let mut v = [1, 2, 3];
+        assert_eq!(
+            v.shuffle_weighted(&mut r, |x| if *x == 2 { -1.0 } else { 1.0 }),
+            Err(WeightError::InvalidWeight)
+        );
+        assert_eq!(v, [1, 2, 3]);
+        assert_eq!(
+            v.shuffle_weighted(&mut r, |_| f64::NAN),
+            Err(WeightError::InvalidWeight)
+        );
+
+        // Weights 3 : 1 : 0 : 0
+        let mut first = [0i32; 4];
+        let mut last_two = [0i32; 2];
+        for _ in 0..4000 {
+            let mut v = [0usize, 1, 2, 3];
+            v.shuffle_weighted(&mut r, |x| [3.0, 1.0, 0.0, 0.0][*x])
+                .unwrap();
+            let mut sorted = v;
+            sorted.sort_unstable();
+            assert_eq!(sorted, [0, 1, 2, 3]);
+            first[v[0]] += 1;
+            assert!(v[2] >= 2 && v[3] >= 2);
+            last_two[v[3] - 2] += 1;
+        }
+        // Expected: 3000 and 1000 for the first position
+        assert!(2880 <= first[0] && first[0] <= 3120, "{:?}", first);
+        assert_eq!(first[2] + first[3], 0);
+        // Zero-weighted elements are uniformly ordered
+        assert!(1850 <= last_two[0] && last_two[0] <= 2150, "{:?}", last_two);
+
+        // Larger slices are permuted correctly
+        let mut v: Vec<usize> = (0..100).collect();
+        v.shuffle_weighted(&mut r, |x| (*x % 7) as f64).unwrap();
+        let mut sorted = v.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..100).collect::<Vec<_>>());
+        assert!(v[100 - 15..].iter().all(|x| x % 7 == 0));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_riffle() {
+        let mut r = crate::test::rng(132);
+
+        let mut one = [0];
+        one.riffle(&mut r);
+        assert_eq!(one, [0]);
+
+        // A riffle of 3 cards yields the identity with probability 4/8, each
+        // of the other 4 interleavings with probability 1/8, and never the
+        // reversed order
+        let mut identity = 0;
+        for _ in 0..8000 {
+            let mut v = [0, 1, 2];
+            v.riffle(&mut r);
+            assert_ne!(v, [2, 1, 0]);
+            identity += (v == [0, 1, 2]) as i32;
+        }
+        assert!((3850..=4150).contains(&identity), "{}", identity);
+
+        // The result is an interleaving of a prefix and a suffix
+        let mut v: Vec<usize> = (0..200).collect();
+        v.riffle(&mut r);
+        let is_interleaving = (0..=200).any(|cut| {
+            let (left, right): (Vec<usize>, Vec<usize>) = v.iter().partition(|x| **x < cut);
+            left.windows(2).all(|w| w[0] < w[1]) && right.windows(2).all(|w| w[0] < w[1])
+        });
+        assert!(is_interleaving);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_overhand() {
+        let mut r = crate::test::rng(133);
+
+        let mut v = [0, 1, 2, 3, 4];
+        v.overhand(&mut r, 0.0);
+        assert_eq!(v, [0, 1, 2, 3, 4]);
+        v.overhand(&mut r, 1.0);
+        assert_eq!(v, [4, 3, 2, 1, 0]);
+
+        // The result consists of packets of consecutive values in reverse
+        // order of packets
+        let mut v: Vec<usize> = (0..100).collect();
+        v.overhand(&mut r, 0.1);
+        let mut packets: Vec<&[usize]> = v.chunk_by(|a, b| *a + 1 == *b).collect();
+        // Cuts are Binomial(99, 0.1) with average 9.9
+        assert!((3..=20).contains(&packets.len()), "{}", packets.len());
+        packets.reverse();
+        assert_eq!(packets.concat(), (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_overhand_invalid_p() {
+        let mut r = crate::test::rng(134);
+        [0, 1].overhand(&mut r, 1.5);
+    }
+
+    #[test]
+    fn test_rotate_random() {
+        let mut r = crate::test::rng(136);
+        let mut empty: [u8; 0] = [];
+        assert_eq!(empty.rotate_random(&mut r), 0);
+
+        let mut counts = [0i32; 5];
+        for _ in 0..5000 {
+            let mut v = [0usize, 1, 2, 3, 4];
+            let offset = v.rotate_random(&mut r);
+            assert_eq!(v[0], offset);
+            assert!(v.windows(2).all(|w| w[1] == (w[0] + 1) % 5));
+            counts[offset] += 1;
+        }
+        for count in counts.iter() {
+            // Binomial(5000, 0.2) with average 1000
+            assert!((880..=1120).contains(count), "counts: {:?}", counts);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_choose_two_mut() {
+        let mut r = crate::test::rng(137);
+        assert_eq!([0u8; 0].choose_two_mut(&mut r), None);
+        assert_eq!([0u8].choose_two_mut(&mut r), None);
+
+        let mut v = [0u8, 1];
+        let (a, b) = v.choose_two_mut(&mut r).unwrap();
+        core::mem::swap(a, b);
+        assert_eq!(v, [1, 0]);
+
+        // All 6 ordered pairs from 3 elements are seen equally often
+        let mut v = [0usize, 1, 2];
+        let mut counts = [[0i32; 3]; 3];
+        for _ in 0..6000 {
+            let (a, b) = v.choose_two_mut(&mut r).unwrap();
+            counts[*a][*b] += 1;
+        }
+        for (a, row) in counts.iter().enumerate() {
+            for (b, count) in row.iter().enumerate() {
+                if a == b {
+                    assert_eq!(*count, 0);
+                } else {
+                    // Binomial(6000, 1/6) with average 1000
+                    assert!((880..=1120).contains(count), "counts: {:?}", counts);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_partition_random() {
+        let mut r = crate::test::rng(138);
+        let mut v: Vec<u32> = (0..10).collect();
+
+        let groups: Vec<Vec<u32>> = v.partition_random(&mut r, 4).map(|g| g.to_vec()).collect();
+        let sizes: Vec<usize> = groups.iter().map(|g| g.len()).collect();
+        assert_eq!(sizes, [3, 3, 2, 2]);
+        let mut all = groups.concat();
+        assert_eq!(all, v);
+        all.sort_unstable();
+        assert_eq!(all, (0..10).collect::<Vec<_>>());
+
+        let sizes: Vec<usize> = v.partition_random(&mut r, 12).map(|g| g.len()).collect();
+        assert_eq!(sizes, [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0]);
+        assert_eq!(v.partition_random(&mut r, 1).len(), 1);
+
+        let mut empty: [u32; 0] = [];
+        assert_eq!(empty.partition_random(&mut r, 2).count(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_partition_random_zero() {
+        let mut r = crate::test::rng(139);
+        let _ = [0, 1].partition_random(&mut r, 0);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_shuffle_chunks() {
+        let mut r = crate::test::rng(128);
+
+        // Equivalent to `shuffle` for chunks of size 1
+        let mut a = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut b = a;
+        a.shuffle_chunks(&mut crate::test::rng(129), 1);
+        b.shuffle(&mut crate::test::rng(129));
+        assert_eq!(a, b);
+
+        let mut v = [0, 1, 2];
+        v.shuffle_chunks(&mut r, 2);
+        assert_eq!(v, [0, 1, 2]);
+        v.shuffle_chunks(&mut r, 5);
+        assert_eq!(v, [0, 1, 2]);
+
+        // All 6 permutations of 3 chunks are equally likely; element 9 stays
+        let mut counts = [0i32; 3 * 3];
+        for _ in 0..6000 {
+            let mut v = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+            v.shuffle_chunks(&mut r, 3);
+            for (pos, chunk) in v.chunks_exact(3).enumerate() {
+                assert_eq!(chunk[0] % 3, 0);
+                assert_eq!(chunk, [chunk[0], chunk[0] + 1, chunk[0] + 2]);
+                counts[pos * 3 + chunk[0] / 3] += 1;
+            }
+            assert_eq!(v[9], 9);
+        }
+        for count in counts.iter() {
+            // Binomial(6000, 1/3) with average 2000
+            assert!(1850 <= *count && *count <= 2150, "counts: {:?}", counts);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_shuffle_chunks_zero() {
+        let mut r = crate::test::rng(130);
+        [0, 1].shuffle_chunks(&mut r, 0);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_shuffle_cyclic() {
+        let mut r = crate::test::rng(124);
+        let mut empty: [u8; 0] = [];
+        empty.shuffle_cyclic(&mut r);
+        let mut one = [0];
+        one.shuffle_cyclic(&mut r);
+        assert_eq!(one, [0]);
+
+        // There are 3! = 6 cycles of length 4
+        let cycles = [
+            [1, 2, 3, 0],
+            [1, 3, 0, 2],
+            [2, 0, 3, 1],
+            [2, 3, 1, 0],
+            [3, 0, 1, 2],
+            [3, 2, 0, 1],
+        ];
+        let mut counts = [0i32; 6];
+        for _ in 0..6000 {
+            let mut v = [0, 1, 2, 3];
+            v.shuffle_cyclic(&mut r);
+            let k = cycles.iter().position(|c| *c == v).unwrap();
+            counts[k] += 1;
+        }
+        for count in counts.iter() {
+            // Binomial(6000, 1/6) with average 1000
+            assert!(880 <= *count && *count <= 1120, "count: {}", count);
+        }
+
+        let mut v = [0usize; 50];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = i;
+        }
+        v.shuffle_cyclic(&mut r);
+        let mut node = 0;
+        for step in 1..=v.len() {
+            node = v[node];
+            assert_eq!(node == 0, step == v.len());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_derange() {
+        let mut r = crate::test::rng(122);
+        let mut empty: [u8; 0] = [];
+        empty.derange(&mut r);
+
+        let mut two = [0, 1];
+        two.derange(&mut r);
+        assert_eq!(two, [1, 0]);
+
+        // There are 9 derangements of 4 elements
+        let derangements = [
+            [1, 0, 3, 2],
+            [1, 2, 3, 0],
+            [1, 3, 0, 2],
+            [2, 0, 3, 1],
+            [2, 3, 0, 1],
+            [2, 3, 1, 0],
+            [3, 0, 1, 2],
+            [3, 2, 0, 1],
+            [3, 2, 1, 0],
+        ];
+        let mut counts = [0i32; 9];
+        for _ in 0..9000 {
+            let mut v = [0, 1, 2, 3];
+            v.derange(&mut r);
+            let k = derangements.iter().position(|d| *d == v).unwrap();
+            counts[k] += 1;
+        }
+        for count in counts.iter() {
+            // Binomial(9000, 1/9) with average 1000
+            assert!(880 <= *count && *count <= 1120, "count: {}", count);
+        }
+
+        let mut v: Vec<usize> = (0..100).collect();
+        v.derange(&mut r);
+        assert!(v.iter().enumerate().all(|(i, x)| i != *x));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic]
+    fn test_derange_one() {
+        let mut r = crate::test::rng(123);
+        [0].derange(&mut r);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_shuffled_iter() {
+        let mut r = crate::test::rng(119);
+
+        let mut empty: [u32; 0] = [];
+        assert_eq!(empty.shuffled_iter(&mut r).next(), None);
+
+        let mut v = [0usize, 1, 2, 3, 4, 5, 6, 7];
+        let (first, second) = {
+            let mut iter = v.shuffled_iter(&mut r);
+            assert_eq!(iter.len(), 8);
+            let first = *iter.next().unwrap();
+            let second = *iter.next().unwrap();
+            assert_eq!(iter.len(), 6);
+            (first, second)
+        };
+        // Consumed elements are moved to the front
+        assert_eq!(v[..2], [first, second]);
+        let mut sorted = v;
+        sorted.sort_unstable();
+        assert_eq!(sorted, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+        // Yielded elements are mutable
+        for x in v.shuffled_iter(&mut r) {
+            *x *= 2;
+        }
+        assert_eq!(v.iter().sum::<usize>(), 56);
+
+        // The first element is uniformly distributed
+        let mut counts = [0i32; 8];
+        for _ in 0..8000 {
+            let mut v = [0usize, 1, 2, 3, 4, 5, 6, 7];
+            counts[*v.shuffled_iter(&mut r).next().unwrap()] += 1;
+        }
+        for count in counts.iter() {
+            // Binomial(8000, 1/8) with average 1000
+            assert!(880 <= *count && *count <= 1120, "count: {}", count);
+        }
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     #[cfg_attr(miri, ignore)] // Miri is too slow
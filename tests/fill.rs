@@ -8,6 +8,7 @@
 
 #![allow(unused)]
 
+use core::mem::MaybeUninit;
 use rand::{Fill, Rng};
 
 // Test that Fill may be implemented for externally-defined types
@@ -16,4 +17,11 @@ impl Fill for MyInt {
     fn fill_slice<R: Rng + ?Sized>(this: &mut [Self], rng: &mut R) {
         todo!()
     }
+
+    fn fill_uninit_slice<'a, R: Rng + ?Sized>(
+        this: &'a mut [MaybeUninit<Self>],
+        rng: &mut R,
+    ) -> &'a mut [Self] {
+        todo!()
+    }
 }
@@ -0,0 +1,113 @@
+#!/usr/bin/env python3
+#
+# Copyright 2025 Developers of the Rand project.
+#
+# Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+# https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+# <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+# option. This file may not be copied, modified, or distributed
+# except according to those terms.
+
+# This creates the tables of Unicode scalar values used by the
+# `UnicodeAssigned` and `UnicodePrintable` distributions in
+# src/distr/unicode_tables.rs from the general categories of the Unicode
+# Character Database (UCD). Run as
+#
+#   python3 utils/unicode_tables.py DerivedGeneralCategory.txt > src/distr/unicode_tables.rs
+#
+# where DerivedGeneralCategory.txt is the file of this name in the `extracted`
+# directory of the UCD, e.g. from
+# https://www.unicode.org/Public/UCD/latest/ucd/extracted/DerivedGeneralCategory.txt
+# The Unicode version is read from its header.
+
+import re
+import sys
+
+# Unassigned, surrogate and private-use code points
+NOT_ASSIGNED = {"Cn", "Cs", "Co"}
+# Categories of non-printable code points, except for U+0020 SPACE
+NOT_PRINTABLE = NOT_ASSIGNED | {"Cc", "Cf", "Zl", "Zp", "Zs"}
+
+
+def read_categories(path):
+    """Return the UCD version and the general category of each code point"""
+    version = None
+    # Code points not listed are unassigned
+    categories = ["Cn"] * 0x110000
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            if version is None:
+                m = re.match(r"# DerivedGeneralCategory-([0-9.]+)\.txt", line)
+                if m:
+                    version = m.group(1)
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+            points, category = (field.strip() for field in line.split(";"))
+            first, _, last = points.partition("..")
+            for c in range(int(first, 16), int(last or first, 16) + 1):
+                categories[c] = category
+    if version is None:
+        sys.exit("%s: missing DerivedGeneralCategory header" % path)
+    return version, categories
+
+
+def ranges(pred):
+    result = []
+    start = None
+    for c in range(0x110000):
+        ok = pred(c)
+        if ok and start is None:
+            start = c
+        elif not ok and start is not None:
+            result.append((start, c))
+            start = None
+    if start is not None:
+        result.append((start, 0x110000))
+    return result
+
+
+def render(name, doc, table):
+    print()
+    print("/// %s" % doc)
+    print("///")
+    print("/// Each entry is the start of a range and the number of values in")
+    print("/// preceding ranges.")
+    print("pub(crate) static %s: &[(u32, u32)] = &[" % name)
+    offset = 0
+    for start, end in table:
+        print("    (0x%04X, %d)," % (start, offset))
+        offset += end - start
+    print("];")
+    print()
+    print("/// The number of values in [`%s`]" % name)
+    print("pub(crate) const %s_LEN: u32 = %d;" % (name, offset))
+
+
+version, categories = read_categories(sys.argv[1])
+print(
+    """// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tables of Unicode scalar values, as of Unicode %s
+//!
+//! Generated by `utils/unicode_tables.py` from the Unicode Character
+//! Database file `DerivedGeneralCategory-%s.txt`; do not edit."""
+    % (version, version)
+)
+render(
+    "ASSIGNED",
+    "Assigned scalar values, excluding private-use characters",
+    ranges(lambda c: categories[c] not in NOT_ASSIGNED),
+)
+render(
+    "PRINTABLE",
+    "Printable scalar values: all but separators other than space, control,\n"
+    "/// format, surrogate, private-use and unassigned code points",
+    ranges(lambda c: c == 0x20 or categories[c] not in NOT_PRINTABLE),
+)
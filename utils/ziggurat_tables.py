@@ -0,0 +1,84 @@
+#!/usr/bin/env python3
+#
+# Copyright 2025 Developers of the Rand project.
+#
+# Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+# https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+# <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+# option. This file may not be copied, modified, or distributed
+# except according to those terms.
+
+# This creates the tables used by the ziggurat method in
+# src/distr/ziggurat_tables.rs. Run as
+#
+#   python3 utils/ziggurat_tables.py > src/distr/ziggurat_tables.rs
+#
+# The constants are taken from G. Marsaglia and W. W. Tsang, "The Ziggurat
+# Method for Generating Random Variables", 2000.
+
+from math import exp, log, sqrt
+
+TABLE_BITS = 8
+TABLE_LEN = 1 << TABLE_BITS
+
+# (name, cfg feature, tail start r, block area v, pdf f, inverse pdf f_inv)
+DISTRIBUTIONS = [
+    (
+        "NORM",
+        "distr_math",
+        3.6541528853610088,
+        0.00492867323399,
+        lambda x: exp(-x * x / 2.0),
+        lambda y: sqrt(-2.0 * log(y)),
+    ),
+    (
+        "EXP",
+        None,
+        7.69711747013104972,
+        0.0039496598225815571993,
+        lambda x: exp(-x),
+        lambda y: -log(y),
+    ),
+]
+
+
+def tables(r, v, f, f_inv):
+    xvec = [0.0] * (TABLE_LEN + 1)
+    xvec[0] = v / f(r)
+    xvec[1] = r
+    for i in range(2, TABLE_LEN):
+        last = xvec[i - 1]
+        xvec[i] = f_inv(v / last + f(last))
+    # the last element is zero by construction
+    yvec = [f(x) for x in xvec]
+    return xvec, yvec
+
+
+def render(name, cfg, table):
+    values = "".join("\n    %r," % x for x in table)
+    return "%spub(crate) static ZIG_%s: ZigTable = &[%s\n];\n" % (cfg, name, values)
+
+
+print(
+    """// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tables for distributions which are sampled using the ziggurat algorithm
+//!
+//! Generated by `utils/ziggurat_tables.py`; do not edit.
+
+pub(crate) type ZigTable = &'static [f64; %d];"""
+    % (TABLE_LEN + 1)
+)
+for name, feature, r, v, f, f_inv in DISTRIBUTIONS:
+    cfg = '#[cfg(feature = "%s")]\n' % feature if feature else ""
+    xvec, yvec = tables(r, v, f, f_inv)
+    print()
+    print("%spub(crate) const ZIG_%s_R: f64 = %r;" % (cfg, name, r))
+    print(render(name + "_X", cfg, xvec), end="")
+    print(render(name + "_F", cfg, yvec), end="")